@@ -0,0 +1,65 @@
+//! Times `f64` matrix multiplication, blocked and by Strassen's algorithm,
+//! against the unblocked triple loop.
+//!
+//! Run with `cargo bench --bench matmul`, adding `--features parallel` to time
+//! `par_mul` as well.
+
+use std::time::{Duration, Instant};
+
+use rand::distributions::Uniform;
+use rustices::Matrix;
+
+/// The textbook `i, j, k` loop, which strides down a column of `rhs` for every entry.
+fn naive_product(lhs: &Matrix<f64>, rhs: &Matrix<f64>) -> Matrix<f64> {
+    let mut output = Matrix::new(lhs.nrows(), rhs.ncols(), 0.0);
+    for i in 0..lhs.nrows() {
+        for j in 0..rhs.ncols() {
+            let mut total = 0.0;
+            for k in 0..lhs.ncols() {
+                total += lhs.get(i, k) * rhs.get(k, j);
+            }
+            output.set(i, j, total);
+        }
+    }
+    output
+}
+
+/// The fastest of `runs` timings, which is the least disturbed by other load.
+fn best_of(runs: usize, mut f: impl FnMut() -> Matrix<f64>) -> Duration {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            let product = f();
+            let elapsed = start.elapsed();
+            assert!(product.get(0, 0).is_finite());
+            elapsed
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    for &(size, runs) in &[(512, 5), (1024, 2)] {
+        let a = Matrix::random_seeded(size, size, 0, Uniform::new(-1.0, 1.0));
+        let b = Matrix::random_seeded(size, size, 1, Uniform::new(-1.0, 1.0));
+
+        let naive = best_of(runs, || naive_product(&a, &b));
+        let blocked = best_of(runs, || &a * &b);
+        println!(
+            "{0}x{0}: naive {1:>9.1?}  blocked {2:>9.1?}  speedup {3:.1}x",
+            size,
+            naive,
+            blocked,
+            naive.as_secs_f64() / blocked.as_secs_f64()
+        );
+
+        let strassen = best_of(runs, || a.mul_strassen(&b).unwrap());
+        println!("{0}x{0}: strassen {1:>9.1?}", size, strassen);
+
+        #[cfg(feature = "parallel")]
+        {
+            let threaded = best_of(runs, || a.par_mul(&b).unwrap());
+            println!("{0}x{0}: par_mul {1:>9.1?}", size, threaded);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::Matrix;
+use crate::binary::{self, ELEMENT_LEN, HEADER_LEN};
+
+/// Appends rows to a matrix file in the format of [`Matrix::write_binary`].
+///
+/// Each append writes the new rows and then updates the row count in the header,
+/// so the file is a valid matrix after every call and can be read with
+/// [`Matrix::read_binary`] at any time. If the process dies mid-append, the header
+/// still describes the rows written before it and reopening discards the rest.
+pub struct MatrixLog {
+    file: File,
+    rows: usize,
+    cols: usize
+}
+
+impl MatrixLog {
+    /// Creates a new, empty log of `cols` columns, replacing any file at `path`.
+    pub fn create<P: AsRef<Path>>(path: P, cols: usize) -> io::Result<MatrixLog> {
+        let mut file = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(path)?;
+        binary::write_header(&mut file, 0, cols)?;
+        file.flush()?;
+
+        Ok(MatrixLog {
+            file,
+            rows: 0,
+            cols
+        })
+    }
+
+    /// Reopens an existing log to append further rows.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<MatrixLog> {
+        let mut file = OpenOptions::new().read(true).write(true).open(path)?;
+        let (rows, cols) = binary::read_header(&mut file)?;
+        let len = HEADER_LEN + (rows * cols) as u64 * ELEMENT_LEN;
+        if file.metadata()?.len() < len {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "matrix log is truncated"));
+        }
+        // Drop anything written after the last completed append.
+        file.set_len(len)?;
+
+        Ok(MatrixLog {
+            file,
+            rows,
+            cols
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn append_row(&mut self, row: &[f64]) -> io::Result<()> {
+        assert_eq!(row.len(), self.cols, "row length must match the log's column count");
+        self.append(row, 1)
+    }
+
+    pub fn append_rows(&mut self, rows: &Matrix<f64>) -> io::Result<()> {
+        assert_eq!(rows.cols, self.cols, "matrix must have the log's column count");
+        self.append(&rows.data, rows.rows)
+    }
+
+    /// The length of the file up to the end of the last completed append.
+    fn committed_len(&self) -> u64 {
+        HEADER_LEN + (self.rows * self.cols) as u64 * ELEMENT_LEN
+    }
+
+    fn append(&mut self, elements: &[f64], rows: usize) -> io::Result<()> {
+        // Write over anything left by an earlier append that failed partway.
+        let end = self.committed_len();
+        self.file.set_len(end)?;
+        self.file.seek(SeekFrom::Start(end))?;
+        binary::write_elements(&mut self.file, elements)?;
+        self.file.sync_data()?;
+
+        self.rows += rows;
+        self.file.seek(SeekFrom::Start(0))?;
+        binary::write_header(&mut self.file, self.rows, self.cols)?;
+        self.file.sync_data()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::TempPath;
+
+    #[test]
+    fn append_and_reopen() {
+        let path = TempPath::new("append_and_reopen");
+
+        let mut log = MatrixLog::create(&path.0, 2).unwrap();
+        log.append_row(&[1.0, 2.0]).unwrap();
+        assert_eq!(Matrix::read_binary(&path.0).unwrap().data, vec![1.0, 2.0]);
+        drop(log);
+
+        let mut log = MatrixLog::open(&path.0).unwrap();
+        assert_eq!(log.rows(), 1);
+        log.append_rows(&Matrix::from(vec![vec![3.0, 4.0], vec![5.0, 6.0]])).unwrap();
+
+        let expected_matrix = Matrix::from(vec![
+            vec![1.0, 2.0],
+            vec![3.0, 4.0],
+            vec![5.0, 6.0]
+        ]);
+        let loaded = Matrix::read_binary(&path.0).unwrap();
+        assert_eq!(loaded, expected_matrix);
+        assert_eq!((loaded.rows, loaded.cols), (3, 2));
+    }
+
+    #[test]
+    fn reopen_discards_incomplete_append() {
+        let path = TempPath::new("reopen_discards_incomplete_append");
+        let mut log = MatrixLog::create(&path.0, 2).unwrap();
+        log.append_row(&[1.0, 2.0]).unwrap();
+        // Simulate a crash after writing half of a row but before the header update.
+        log.file.seek(SeekFrom::End(0)).unwrap();
+        binary::write_elements(&mut log.file, &[9.0]).unwrap();
+        drop(log);
+
+        let mut log = MatrixLog::open(&path.0).unwrap();
+        log.append_row(&[3.0, 4.0]).unwrap();
+
+        assert_eq!(Matrix::read_binary(&path.0).unwrap().data, vec![1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn append_overwrites_failed_append() {
+        let path = TempPath::new("append_overwrites_failed_append");
+        let mut log = MatrixLog::create(&path.0, 2).unwrap();
+        log.append_row(&[1.0, 2.0]).unwrap();
+        // Leave half a row behind, as a write that failed partway would.
+        log.file.seek(SeekFrom::End(0)).unwrap();
+        binary::write_elements(&mut log.file, &[9.0]).unwrap();
+
+        log.append_row(&[3.0, 4.0]).unwrap();
+
+        assert_eq!(Matrix::read_binary(&path.0).unwrap().data, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(std::fs::metadata(&path.0).unwrap().len(), HEADER_LEN + 4 * ELEMENT_LEN);
+    }
+}
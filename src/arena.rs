@@ -0,0 +1,93 @@
+use std::cell::RefCell;
+use std::mem;
+
+use crate::buffer::MatrixMut;
+
+/// A fixed pool of elements from which temporary matrices are bump-allocated.
+///
+/// Work proceeds in frames: [`Arena::frame`] starts a new frame, matrices taken
+/// from it borrow disjoint parts of the pool, and all of them are released
+/// together when the frame is dropped. The pool itself is allocated once, so a
+/// per-frame pipeline makes no heap allocations after start-up.
+#[derive(Debug)]
+pub struct Arena<T> {
+    pool: Vec<T>
+}
+
+/// The allocation state of one [`Arena`] frame.
+#[derive(Debug)]
+pub struct Frame<'a, T> {
+    remaining: RefCell<&'a mut [T]>
+}
+
+impl<T: Clone + Default> Arena<T> {
+    pub fn with_capacity(capacity: usize) -> Arena<T> {
+        Arena {
+            pool: vec![T::default(); capacity]
+        }
+    }
+}
+
+impl<T> Arena<T> {
+    pub fn capacity(&self) -> usize {
+        self.pool.len()
+    }
+
+    /// Starts a new frame with the whole pool available.
+    pub fn frame(&mut self) -> Frame<'_, T> {
+        Frame {
+            remaining: RefCell::new(&mut self.pool)
+        }
+    }
+}
+
+impl<'a, T> Frame<'a, T> {
+    /// Takes a `rows x cols` matrix with every element set to `value`, or `None`
+    /// if the frame has too little of the pool left.
+    pub fn matrix(&self, rows: usize, cols: usize, value: T) -> Option<MatrixMut<'a, T>>
+        where T: Clone
+    {
+        let mut remaining = self.remaining.borrow_mut();
+        if remaining.len() < rows * cols {
+            return None;
+        }
+
+        let (taken, rest) = mem::take(&mut *remaining).split_at_mut(rows * cols);
+        *remaining = rest;
+        let mut matrix = MatrixMut::from_buffer(rows, cols, taken);
+        matrix.fill(value);
+        Some(matrix)
+    }
+
+    /// How many elements this frame can still hand out.
+    pub fn remaining(&self) -> usize {
+        self.remaining.borrow().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Matrix;
+
+    #[test]
+    fn frames_reuse_the_pool() {
+        let mut arena = Arena::with_capacity(10);
+
+        for step in 0..3 {
+            let frame = arena.frame();
+            let mut a = frame.matrix(2, 2, 1).unwrap();
+            let b = frame.matrix(2, 2, step).unwrap();
+            assert_eq!(frame.remaining(), 2);
+            assert!(frame.matrix(2, 2, 0).is_none());
+
+            let mut product = frame.matrix(1, 2, 0).unwrap();
+            a.set(0, 1, 2);
+            product.mul_into(&Matrix::from(vec![vec![1, 1]]), &a);
+            assert_eq!(product.as_slice(), &[2, 3]);
+            assert_eq!(b.as_slice(), &[step; 4]);
+        }
+
+        assert_eq!(arena.capacity(), 10);
+    }
+}
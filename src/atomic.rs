@@ -0,0 +1,100 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::Matrix;
+
+/// A matrix of `f64` that many threads can accumulate into through a shared reference.
+///
+/// Each element is an independent atomic, so concurrent [`AtomicMatrix::fetch_add`]
+/// calls only contend when they hit the same element. Floating-point addition is
+/// not associative, so the low bits of a sum can depend on the order threads arrive in.
+#[derive(Debug)]
+pub struct AtomicMatrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<AtomicU64>
+}
+
+impl AtomicMatrix {
+    pub fn new(rows: usize, cols: usize) -> AtomicMatrix {
+        AtomicMatrix {
+            rows,
+            cols,
+            data: (0..rows * cols).map(|_| AtomicU64::new(0.0f64.to_bits())).collect()
+        }
+    }
+
+    fn element(&self, row: usize, col: usize) -> &AtomicU64 {
+        assert!(row < self.rows && col < self.cols, "index ({}, {}) out of bounds", row, col);
+        &self.data[row * self.cols + col]
+    }
+
+    /// Adds `value` to the element at `(row, col)`, returning its previous value.
+    pub fn fetch_add(&self, row: usize, col: usize, value: f64) -> f64 {
+        let element = self.element(row, col);
+        let mut current = element.load(Ordering::Relaxed);
+        loop {
+            let updated = (f64::from_bits(current) + value).to_bits();
+            match element.compare_exchange_weak(current, updated, Ordering::Relaxed, Ordering::Relaxed) {
+                Ok(previous) => return f64::from_bits(previous),
+                Err(actual) => current = actual
+            }
+        }
+    }
+
+    pub fn load(&self, row: usize, col: usize) -> f64 {
+        f64::from_bits(self.element(row, col).load(Ordering::Relaxed))
+    }
+
+    /// The accumulated values, once every thread has finished.
+    pub fn into_matrix(self) -> Matrix<f64> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.into_iter().map(|val| f64::from_bits(val.into_inner())).collect()
+        }
+    }
+}
+
+impl From<Matrix<f64>> for AtomicMatrix {
+    fn from(matrix: Matrix<f64>) -> AtomicMatrix {
+        AtomicMatrix {
+            rows: matrix.rows,
+            cols: matrix.cols,
+            data: matrix.data.into_iter().map(|val| AtomicU64::new(val.to_bits())).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn concurrent_scatter_accumulate() {
+        let histogram = AtomicMatrix::new(2, 2);
+
+        thread::scope(|scope| {
+            for t in 0..8 {
+                let histogram = &histogram;
+                scope.spawn(move || {
+                    for k in 0..1000 {
+                        histogram.fetch_add((t + k) % 2, k % 2, 0.5);
+                    }
+                });
+            }
+        });
+
+        assert_eq!(histogram.load(0, 0), 1000.0);
+        assert_eq!(histogram.into_matrix(), Matrix::new(2, 2, 1000.0));
+    }
+
+    #[test]
+    fn fetch_add_returns_previous_value() {
+        let matrix = AtomicMatrix::from(Matrix::from(vec![vec![1.0, 2.0]]));
+
+        assert_eq!(matrix.fetch_add(0, 1, 3.0), 2.0);
+        assert_eq!(matrix.load(0, 1), 5.0);
+    }
+}
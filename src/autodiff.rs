@@ -0,0 +1,278 @@
+use std::cell::RefCell;
+use std::ops::{Add, Mul, Sub};
+
+use crate::Matrix;
+
+enum Op {
+    Leaf,
+    MatMul(usize, usize),
+    Add(usize, usize),
+    Sub(usize, usize),
+    Hadamard(usize, usize),
+    Scale(usize, f64),
+    /// An element-wise function, storing its derivative evaluated at the input.
+    Map(usize, Matrix<f64>),
+    Sum(usize)
+}
+
+struct Node {
+    value: Matrix<f64>,
+    op: Op
+}
+
+/// Records matrix operations so that gradients can be computed by reverse-mode differentiation.
+///
+/// Create inputs with [`Tape::var`], combine them with the operators on [`Var`],
+/// and call [`Var::backward`] on a scalar (`1 x 1`) result.
+#[derive(Default)]
+pub struct Tape {
+    nodes: RefCell<Vec<Node>>
+}
+
+/// A matrix-valued variable recorded on a [`Tape`].
+///
+/// Combining variables from different tapes panics.
+#[derive(Clone, Copy)]
+pub struct Var<'t> {
+    tape: &'t Tape,
+    index: usize
+}
+
+/// The gradients produced by [`Var::backward`].
+pub struct Gradients {
+    grads: Vec<Matrix<f64>>
+}
+
+fn zip_with<F>(a: &Matrix<f64>, b: &Matrix<f64>, f: F) -> Matrix<f64>
+    where F: Fn(f64, f64) -> f64
+{
+    assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+
+    Matrix {
+        rows: a.rows,
+        cols: a.cols,
+        data: a.data.iter().zip(b.data.iter()).map(|(x, y)| f(*x, *y)).collect()
+    }
+}
+
+fn accumulate(grad: &mut Matrix<f64>, delta: &Matrix<f64>) {
+    *grad = zip_with(grad, delta, |a, b| a + b);
+}
+
+impl Tape {
+    pub fn new() -> Tape {
+        Tape::default()
+    }
+
+    /// Records an input variable.
+    pub fn var(&self, value: Matrix<f64>) -> Var<'_> {
+        self.push(value, Op::Leaf)
+    }
+
+    fn push(&self, value: Matrix<f64>, op: Op) -> Var<'_> {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(Node { value, op });
+        Var {
+            tape: self,
+            index: nodes.len() - 1
+        }
+    }
+}
+
+impl<'t> Var<'t> {
+    /// Panics unless `rhs` was recorded on the same tape, since its index would
+    /// otherwise refer to an unrelated node.
+    fn same_tape(&self, rhs: &Var<'t>) {
+        assert!(std::ptr::eq(self.tape, rhs.tape), "variables belong to different tapes");
+    }
+
+    pub fn value(&self) -> Matrix<f64> {
+        self.tape.nodes.borrow()[self.index].value.clone()
+    }
+
+    /// Element-wise product.
+    pub fn hadamard(self, rhs: Var<'t>) -> Var<'t> {
+        self.same_tape(&rhs);
+        let value = zip_with(&self.value(), &rhs.value(), |a, b| a * b);
+        self.tape.push(value, Op::Hadamard(self.index, rhs.index))
+    }
+
+    pub fn scale(self, factor: f64) -> Var<'t> {
+        self.tape.push(self.value() * factor, Op::Scale(self.index, factor))
+    }
+
+    /// Applies `f` element-wise, where `df` is its derivative.
+    pub fn map<F, D>(self, f: F, df: D) -> Var<'t>
+        where F: Fn(f64) -> f64,
+              D: Fn(f64) -> f64
+    {
+        let input = self.value();
+        let value = zip_with(&input, &input, |x, _| f(x));
+        let derivative = zip_with(&input, &input, |x, _| df(x));
+        self.tape.push(value, Op::Map(self.index, derivative))
+    }
+
+    /// The sum of all elements, as a `1 x 1` matrix.
+    pub fn sum(self) -> Var<'t> {
+        let total = self.value().data.iter().sum();
+        self.tape.push(Matrix::new(1, 1, total), Op::Sum(self.index))
+    }
+
+    /// Computes the gradient of this scalar variable with respect to every variable on the tape.
+    pub fn backward(&self) -> Gradients {
+        let nodes = self.tape.nodes.borrow();
+        let output = &nodes[self.index].value;
+        assert_eq!((output.rows, output.cols), (1, 1), "backward requires a scalar output");
+
+        let mut grads: Vec<Matrix<f64>> = nodes
+            .iter()
+            .map(|node| Matrix::new(node.value.rows, node.value.cols, 0.0))
+            .collect();
+        grads[self.index] = Matrix::new(1, 1, 1.0);
+
+        for index in (0..=self.index).rev() {
+            let grad = grads[index].clone();
+            match &nodes[index].op {
+                Op::Leaf => {}
+                Op::MatMul(a, b) => {
+                    let delta_a = &grad * nodes[*b].value.transpose();
+                    let delta_b = nodes[*a].value.transpose() * grad;
+                    accumulate(&mut grads[*a], &delta_a);
+                    accumulate(&mut grads[*b], &delta_b);
+                }
+                Op::Add(a, b) => {
+                    accumulate(&mut grads[*a], &grad);
+                    accumulate(&mut grads[*b], &grad);
+                }
+                Op::Sub(a, b) => {
+                    accumulate(&mut grads[*a], &grad);
+                    accumulate(&mut grads[*b], &(grad * -1.0));
+                }
+                Op::Hadamard(a, b) => {
+                    let delta_a = zip_with(&grad, &nodes[*b].value, |g, x| g * x);
+                    let delta_b = zip_with(&grad, &nodes[*a].value, |g, x| g * x);
+                    accumulate(&mut grads[*a], &delta_a);
+                    accumulate(&mut grads[*b], &delta_b);
+                }
+                Op::Scale(a, factor) => {
+                    accumulate(&mut grads[*a], &(grad * *factor));
+                }
+                Op::Map(a, derivative) => {
+                    accumulate(&mut grads[*a], &zip_with(&grad, derivative, |g, d| g * d));
+                }
+                Op::Sum(a) => {
+                    let input = &nodes[*a].value;
+                    accumulate(&mut grads[*a], &Matrix::new(input.rows, input.cols, grad.data[0]));
+                }
+            }
+        }
+
+        Gradients {
+            grads
+        }
+    }
+}
+
+impl<'t> Mul for Var<'t> {
+    type Output = Var<'t>;
+
+    /// Matrix product.
+    fn mul(self, rhs: Var<'t>) -> Var<'t> {
+        self.same_tape(&rhs);
+        let value = self.value() * rhs.value();
+        self.tape.push(value, Op::MatMul(self.index, rhs.index))
+    }
+}
+
+impl<'t> Add for Var<'t> {
+    type Output = Var<'t>;
+
+    fn add(self, rhs: Var<'t>) -> Var<'t> {
+        self.same_tape(&rhs);
+        let value = zip_with(&self.value(), &rhs.value(), |a, b| a + b);
+        self.tape.push(value, Op::Add(self.index, rhs.index))
+    }
+}
+
+impl<'t> Sub for Var<'t> {
+    type Output = Var<'t>;
+
+    fn sub(self, rhs: Var<'t>) -> Var<'t> {
+        self.same_tape(&rhs);
+        let value = zip_with(&self.value(), &rhs.value(), |a, b| a - b);
+        self.tape.push(value, Op::Sub(self.index, rhs.index))
+    }
+}
+
+impl Gradients {
+    /// The gradient of the output with respect to `var`, shaped like `var`.
+    pub fn wrt(&self, var: Var) -> &Matrix<f64> {
+        &self.grads[var.index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gradient_of_sum_of_product() {
+        let tape = Tape::new();
+        let a = tape.var(Matrix::from(vec![
+            vec![1.0, 2.0],
+            vec![3.0, 4.0]
+        ]));
+        let b = tape.var(Matrix::from(vec![
+            vec![5.0],
+            vec![6.0]
+        ]));
+
+        let loss = (a * b).sum();
+        assert_eq!(loss.value().data, vec![17.0 + 39.0]);
+
+        let grads = loss.backward();
+        // d/dA sum(A b) = 1 b^T, d/db = A^T 1
+        assert_eq!(grads.wrt(a), &Matrix::from(vec![vec![5.0, 6.0], vec![5.0, 6.0]]));
+        assert_eq!(grads.wrt(b), &Matrix::from(vec![vec![4.0], vec![6.0]]));
+    }
+
+    #[test]
+    fn gradient_of_squared_error() {
+        let tape = Tape::new();
+        let w = tape.var(Matrix::from(vec![vec![2.0, -1.0]]));
+        let x = tape.var(Matrix::from(vec![vec![1.0], vec![3.0]]));
+        let target = tape.var(Matrix::new(1, 1, 4.0));
+
+        // (w x - t)^2 = (-1 - 4)^2
+        let error = w * x - target;
+        let loss = error.hadamard(error).scale(0.5);
+        assert_eq!(loss.value().data, vec![12.5]);
+
+        let grads = loss.backward();
+        // d/dw = (w x - t) x^T
+        assert_eq!(grads.wrt(w), &Matrix::from(vec![vec![-5.0, -15.0]]));
+        assert_eq!(grads.wrt(target), &Matrix::new(1, 1, 5.0));
+    }
+
+    #[test]
+    fn gradient_through_map() {
+        let tape = Tape::new();
+        let x = tape.var(Matrix::from(vec![vec![0.0, 1.0, 2.0]]));
+
+        let loss = x.map(|v| v * v * v, |v| 3.0 * v * v).add(x).sum();
+        let grads = loss.backward();
+
+        assert_eq!(grads.wrt(x), &Matrix::from(vec![vec![1.0, 4.0, 13.0]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "variables belong to different tapes")]
+    fn mixing_tapes_panics() {
+        let first = Tape::new();
+        let second = Tape::new();
+        let a = first.var(Matrix::new(1, 1, 1.0));
+        let b = second.var(Matrix::new(1, 1, 2.0));
+
+        let _ = a + b;
+    }
+}
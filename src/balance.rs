@@ -0,0 +1,212 @@
+use num::Float;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+use crate::progress::SolverOptions;
+
+/// The result of [`Matrix::balance`]: `matrix = D^-1 * A * D` with `D = diag(scale)`.
+#[derive(Debug, PartialEq)]
+pub struct Balanced<T> {
+    pub matrix: Matrix<T>,
+    pub scale: Vec<T>
+}
+
+impl<T: Float> Matrix<T> {
+    /// Balances a square matrix by a diagonal similarity transform.
+    ///
+    /// Rows and columns are repeatedly rescaled by powers of two until their
+    /// off-diagonal norms are comparable (Parlett & Reinsch). The eigenvalues are
+    /// unchanged and, because the scaling is by powers of two, no rounding error
+    /// is introduced, but subsequent eigenvalue computations become more accurate
+    /// for badly scaled matrices. Rows whose norms aren't finite are left as they are.
+    pub fn balance(&self) -> Balanced<T> {
+        self.balance_with(&mut SolverOptions::default())
+            .expect("balancing without a progress callback cannot be cancelled")
+    }
+
+    /// [`Matrix::balance`] with progress reporting and an iteration limit.
+    ///
+    /// Each sweep over the rows is one iteration, and its residual is the number
+    /// of rows that were rescaled. Hitting `max_iterations` returns the partially
+    /// balanced matrix, which is still an exact similarity transform.
+    pub fn balance_with(&self, options: &mut SolverOptions) -> Result<Balanced<T>, MatrixError> {
+        assert_eq!(self.rows, self.cols);
+
+        let radix = T::from(2).unwrap();
+        let radix_squared = radix * radix;
+        let threshold = T::from(0.95).unwrap();
+
+        let n = self.rows;
+        let mut matrix = self.clone();
+        let mut scale = vec![T::one(); n];
+
+        for iteration in 0..options.max_iterations {
+            let mut rescaled = 0;
+            for i in 0..n {
+                let mut c = T::zero();
+                let mut r = T::zero();
+                for j in (0..n).filter(|&j| j != i) {
+                    c = c + matrix.get(j, i).abs();
+                    r = r + matrix.get(i, j).abs();
+                }
+                // Infinite norms can't be balanced by any finite power of two.
+                if c == T::zero() || r == T::zero() || !c.is_finite() || !r.is_finite() {
+                    continue;
+                }
+
+                let total = c + r;
+                let mut f = T::one();
+                while c < r / radix {
+                    f = f * radix;
+                    c = c * radix_squared;
+                }
+                while c > r * radix {
+                    f = f / radix;
+                    c = c / radix_squared;
+                }
+
+                if (c + r) / f < threshold * total {
+                    rescaled += 1;
+                    scale[i] = scale[i] * f;
+                    for j in 0..n {
+                        let val = *matrix.get(i, j);
+                        matrix.set(i, j, val / f);
+                        let val = *matrix.get(j, i);
+                        matrix.set(j, i, val * f);
+                    }
+                }
+            }
+
+            if options.report(iteration, rescaled as f64).is_break() {
+                return Err(MatrixError::Cancelled { iterations: iteration + 1 });
+            }
+            if rescaled == 0 {
+                break;
+            }
+        }
+
+        Ok(Balanced {
+            matrix,
+            scale
+        })
+    }
+}
+
+impl<T: Float> Balanced<T> {
+    /// Recovers the original matrix `A = D * B * D^-1`.
+    pub fn restore(&self) -> Matrix<T> {
+        let n = self.matrix.rows;
+        let mut data = vec![];
+        for i in 0..n {
+            for j in 0..n {
+                data.push(self.scale[i] * *self.matrix.get(i, j) / self.scale[j]);
+            }
+        }
+
+        Matrix {
+            rows: n,
+            cols: n,
+            data
+        }
+    }
+
+    /// Maps eigenvectors of the balanced matrix (stored as columns) back to
+    /// eigenvectors of the original matrix by computing `D * V`.
+    pub fn unbalance_vectors(&self, vectors: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(vectors.rows, self.scale.len());
+
+        let mut data = vec![];
+        for i in 0..vectors.rows {
+            for j in 0..vectors.cols {
+                data.push(self.scale[i] * *vectors.get(i, j));
+            }
+        }
+
+        Matrix {
+            rows: vectors.rows,
+            cols: vectors.cols,
+            data
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+
+    use super::*;
+
+    #[test]
+    fn balance_equalises_norms() {
+        let matrix = Matrix::from(vec![
+            vec![1.0, 1024.0],
+            vec![1.0 / 1024.0, 1.0]
+        ]);
+
+        let balanced = matrix.balance();
+
+        let b = &balanced.matrix;
+        assert_eq!(*b.get(0, 0), 1.0);
+        assert_eq!(*b.get(1, 1), 1.0);
+        assert_eq!(*b.get(0, 1), *b.get(1, 0));
+        assert_eq!(balanced.restore(), matrix);
+    }
+
+    #[test]
+    fn balance_skips_infinite_norms() {
+        let matrix = Matrix::from(vec![
+            vec![1.0, f64::INFINITY, 0.0],
+            vec![1.0, 1.0, 1024.0],
+            vec![0.0, 1.0 / 1024.0, 1.0]
+        ]);
+
+        let balanced = matrix.balance();
+        assert_eq!(balanced.scale[0], 1.0);
+        assert_eq!(*balanced.matrix.get(0, 1), f64::INFINITY);
+    }
+
+    #[test]
+    fn balance_can_be_cancelled() {
+        let matrix = Matrix::from(vec![
+            vec![1.0, 1e9],
+            vec![1e-9, 1.0]
+        ]);
+
+        let mut sweeps = 0;
+        let mut options = SolverOptions::default().progress(|_| {
+            sweeps += 1;
+            ControlFlow::Break(())
+        });
+
+        assert_eq!(matrix.balance_with(&mut options).unwrap_err(), MatrixError::Cancelled { iterations: 1 });
+        drop(options);
+        assert_eq!(sweeps, 1);
+    }
+
+    #[test]
+    fn balance_preserves_trace_and_vectors() {
+        let matrix = Matrix::from(vec![
+            vec![2.0, 100.0, 0.0],
+            vec![0.01, 3.0, 1000.0],
+            vec![0.0, 0.001, 4.0]
+        ]);
+
+        let balanced = matrix.balance();
+        for i in 0..3 {
+            assert_eq!(*balanced.matrix.get(i, i), *matrix.get(i, i));
+        }
+
+        // A * (D * y) == D * (B * y) for any y.
+        let y = Matrix::from(vec![vec![1.0], vec![2.0], vec![3.0]]);
+        let x = balanced.unbalance_vectors(&y);
+        for i in 0..3 {
+            let mut lhs = 0.0;
+            let mut by = 0.0;
+            for j in 0..3 {
+                lhs += matrix.get(i, j) * x.get(j, 0);
+                by += balanced.matrix.get(i, j) * y.get(j, 0);
+            }
+            assert!((lhs - balanced.scale[i] * by).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use std::convert::TryInto;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use crate::Matrix;
+
+/// Identifies a file written by this crate.
+const MAGIC: &[u8; 4] = b"RMAT";
+const VERSION: u32 = 1;
+/// Magic, version, rows and columns.
+pub(crate) const HEADER_LEN: u64 = 24;
+pub(crate) const ELEMENT_LEN: u64 = 8;
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+/// Writes the header for a `rows x cols` matrix of `f64`.
+///
+/// The header is followed by the elements in row-major order, each as a
+/// little-endian `f64`.
+pub(crate) fn write_header<W: Write>(writer: &mut W, rows: usize, cols: usize) -> io::Result<()> {
+    writer.write_all(MAGIC)?;
+    writer.write_all(&VERSION.to_le_bytes())?;
+    writer.write_all(&(rows as u64).to_le_bytes())?;
+    writer.write_all(&(cols as u64).to_le_bytes())
+}
+
+/// Reads a header written by [`write_header`], returning `(rows, cols)`.
+///
+/// The dimensions are untrusted, so they are rejected as invalid data unless
+/// `rows * cols` fits in a `usize` and the size of the whole file,
+/// `HEADER_LEN + rows * cols * ELEMENT_LEN`, fits in a `u64`. Callers can then
+/// compute either without overflow.
+pub(crate) fn read_header<R: Read>(reader: &mut R) -> io::Result<(usize, usize)> {
+    let mut header = [0; HEADER_LEN as usize];
+    reader.read_exact(&mut header)?;
+    if &header[0..4] != MAGIC {
+        return Err(invalid_data("not a matrix file"));
+    }
+    if u32::from_le_bytes(header[4..8].try_into().unwrap()) != VERSION {
+        return Err(invalid_data("unsupported matrix file version"));
+    }
+    let rows = u64::from_le_bytes(header[8..16].try_into().unwrap());
+    let cols = u64::from_le_bytes(header[16..24].try_into().unwrap());
+    let too_large = || invalid_data("matrix dimensions are too large");
+    let count = rows.checked_mul(cols).ok_or_else(too_large)?;
+    count.checked_mul(ELEMENT_LEN).and_then(|bytes| bytes.checked_add(HEADER_LEN)).ok_or_else(too_large)?;
+    let _: usize = count.try_into().map_err(|_| too_large())?;
+    Ok((rows as usize, cols as usize))
+}
+
+pub(crate) fn write_elements<W: Write>(writer: &mut W, elements: &[f64]) -> io::Result<()> {
+    for val in elements {
+        writer.write_all(&val.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+/// Reads `count` elements, where `count * ELEMENT_LEN` must not overflow, as
+/// [`read_header`] ensures for the dimensions it returns.
+///
+/// The buffer grows as data arrives rather than being allocated up front, so
+/// a corrupt header claiming a huge matrix fails with `UnexpectedEof` at the
+/// end of the file instead of aborting on allocation.
+pub(crate) fn read_elements<R: Read>(reader: &mut R, count: usize) -> io::Result<Vec<f64>> {
+    let len = count as u64 * ELEMENT_LEN;
+    let mut bytes = vec![];
+    reader.take(len).read_to_end(&mut bytes)?;
+    if (bytes.len() as u64) < len {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "matrix file is truncated"));
+    }
+    Ok(bytes.chunks_exact(ELEMENT_LEN as usize)
+        .map(|chunk| f64::from_le_bytes(chunk.try_into().unwrap()))
+        .collect())
+}
+
+impl Matrix<f64> {
+    /// Saves the matrix in the crate's binary format: a 24 byte header followed by
+    /// the elements as little-endian `f64`s in row-major order.
+    pub fn write_binary<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        write_header(&mut writer, self.rows, self.cols)?;
+        write_elements(&mut writer, &self.data)?;
+        writer.flush()
+    }
+
+    /// Loads a matrix saved by [`Matrix::write_binary`].
+    pub fn read_binary<P: AsRef<Path>>(path: P) -> io::Result<Matrix<f64>> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let (rows, cols) = read_header(&mut reader)?;
+        let data = read_elements(&mut reader, rows * cols)?;
+
+        Ok(Matrix {
+            rows,
+            cols,
+            data
+        })
+    }
+}
+
+/// A path in the system temporary directory that is removed when dropped.
+#[cfg(test)]
+pub(crate) struct TempPath(pub(crate) std::path::PathBuf);
+
+#[cfg(test)]
+impl TempPath {
+    pub(crate) fn new(name: &str) -> TempPath {
+        TempPath(std::env::temp_dir().join(format!("rustices-{}-{}", std::process::id(), name)))
+    }
+}
+
+#[cfg(test)]
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binary_round_trip() {
+        let path = TempPath::new("binary_round_trip");
+        let matrix = Matrix::from(vec![
+            vec![1.5, -2.0, 3.0],
+            vec![f64::MAX, 0.0, f64::MIN_POSITIVE]
+        ]);
+
+        matrix.write_binary(&path.0).unwrap();
+        let loaded = Matrix::read_binary(&path.0).unwrap();
+
+        assert_eq!(loaded, matrix);
+        assert_eq!((loaded.rows, loaded.cols), (2, 3));
+        assert_eq!(std::fs::metadata(&path.0).unwrap().len(), HEADER_LEN + 6 * ELEMENT_LEN);
+    }
+
+    #[test]
+    fn binary_rejects_other_files() {
+        let path = TempPath::new("binary_rejects_other_files");
+        std::fs::write(&path.0, b"not a matrix at all, just some text").unwrap();
+
+        let error = Matrix::read_binary(&path.0).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn binary_rejects_corrupt_dimensions() {
+        let path = TempPath::new("binary_rejects_corrupt_dimensions");
+        let write = |rows: usize, cols: usize| {
+            let mut file = File::create(&path.0).unwrap();
+            write_header(&mut file, rows, cols).unwrap();
+            write_elements(&mut file, &[1.0, 2.0]).unwrap();
+        };
+
+        // The element count overflows, and so does the byte count.
+        write(1 << 40, 1 << 40);
+        assert_eq!(Matrix::read_binary(&path.0).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        write(1 << 61, 1);
+        assert_eq!(Matrix::read_binary(&path.0).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        // Representable, but far larger than the file: no huge allocation.
+        write(1 << 40, 1);
+        assert_eq!(Matrix::read_binary(&path.0).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,247 @@
+use std::ops::{BitAnd, BitOr, BitXor, Mul, Not};
+
+use crate::Matrix;
+
+const WORD_BITS: usize = 64;
+
+/// A boolean matrix storing 64 elements per `u64` word.
+///
+/// Each row occupies a whole number of words, and any padding bits past the last
+/// column are kept at zero so that row-wise word operations never leak into them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitMatrix {
+    rows: usize,
+    cols: usize,
+    words_per_row: usize,
+    data: Vec<u64>
+}
+
+impl BitMatrix {
+    /// Creates a `rows x cols` matrix with every element `false`.
+    pub fn new(rows: usize, cols: usize) -> BitMatrix {
+        let words_per_row = cols.div_ceil(WORD_BITS);
+        BitMatrix {
+            rows,
+            cols,
+            words_per_row,
+            data: vec![0; rows * words_per_row]
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        assert!(row < self.rows && col < self.cols);
+        let word = self.data[row * self.words_per_row + col / WORD_BITS];
+        (word >> (col % WORD_BITS)) & 1 == 1
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: bool) {
+        assert!(row < self.rows && col < self.cols);
+        let word = &mut self.data[row * self.words_per_row + col / WORD_BITS];
+        let mask = 1 << (col % WORD_BITS);
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    fn row_words(&self, row: usize) -> &[u64] {
+        &self.data[row * self.words_per_row..(row + 1) * self.words_per_row]
+    }
+
+    /// Number of `true` elements.
+    pub fn count_ones(&self) -> usize {
+        self.data.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn to_matrix(&self) -> Matrix<bool> {
+        let mut data = vec![];
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                data.push(self.get(i, j));
+            }
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data
+        }
+    }
+
+    /// The reachability relation of a square adjacency matrix (Warshall's algorithm).
+    ///
+    /// Element `(i, j)` of the result is `true` when `j` can be reached from `i`
+    /// along one or more edges.
+    pub fn transitive_closure(&self) -> BitMatrix {
+        assert_eq!(self.rows, self.cols);
+
+        let mut closure = self.clone();
+        let words = self.words_per_row;
+        for k in 0..self.rows {
+            let row_k = closure.row_words(k).to_vec();
+            for i in 0..self.rows {
+                if closure.get(i, k) {
+                    for (word, k_word) in closure.data[i * words..(i + 1) * words].iter_mut().zip(row_k.iter()) {
+                        *word |= k_word;
+                    }
+                }
+            }
+        }
+        closure
+    }
+
+    fn zip_words<F>(&self, rhs: &BitMatrix, f: F) -> BitMatrix
+        where F: Fn(u64, u64) -> u64
+    {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols));
+
+        BitMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            words_per_row: self.words_per_row,
+            data: self.data.iter().zip(rhs.data.iter()).map(|(a, b)| f(*a, *b)).collect()
+        }
+    }
+}
+
+impl From<&Matrix<bool>> for BitMatrix {
+    fn from(matrix: &Matrix<bool>) -> BitMatrix {
+        let mut bits = BitMatrix::new(matrix.rows, matrix.cols);
+        for i in 0..matrix.rows {
+            for j in 0..matrix.cols {
+                if *matrix.get(i, j) {
+                    bits.set(i, j, true);
+                }
+            }
+        }
+        bits
+    }
+}
+
+impl BitAnd for &BitMatrix {
+    type Output = BitMatrix;
+
+    fn bitand(self, rhs: &BitMatrix) -> BitMatrix {
+        self.zip_words(rhs, |a, b| a & b)
+    }
+}
+
+impl BitOr for &BitMatrix {
+    type Output = BitMatrix;
+
+    fn bitor(self, rhs: &BitMatrix) -> BitMatrix {
+        self.zip_words(rhs, |a, b| a | b)
+    }
+}
+
+impl BitXor for &BitMatrix {
+    type Output = BitMatrix;
+
+    fn bitxor(self, rhs: &BitMatrix) -> BitMatrix {
+        self.zip_words(rhs, |a, b| a ^ b)
+    }
+}
+
+impl Not for &BitMatrix {
+    type Output = BitMatrix;
+
+    fn not(self) -> BitMatrix {
+        let mut output = BitMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            words_per_row: self.words_per_row,
+            data: self.data.iter().map(|word| !word).collect()
+        };
+
+        // Clear the padding bits flipped on in the last word of each row.
+        let used = self.cols % WORD_BITS;
+        if used != 0 {
+            let mask = (1 << used) - 1;
+            for i in 0..self.rows {
+                output.data[(i + 1) * self.words_per_row - 1] &= mask;
+            }
+        }
+        output
+    }
+}
+
+/// Boolean matrix product: element `(i, j)` is the OR over `k` of `a[i][k] AND b[k][j]`.
+impl Mul for &BitMatrix {
+    type Output = BitMatrix;
+
+    fn mul(self, rhs: &BitMatrix) -> BitMatrix {
+        assert_eq!(self.cols, rhs.rows);
+
+        let mut output = BitMatrix::new(self.rows, rhs.cols);
+        let words = output.words_per_row;
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                if self.get(i, k) {
+                    for (word, rhs_word) in output.data[i * words..(i + 1) * words].iter_mut().zip(rhs.row_words(k)) {
+                        *word |= rhs_word;
+                    }
+                }
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_matrix_get_set_across_words() {
+        let mut bits = BitMatrix::new(2, 130);
+        bits.set(0, 0, true);
+        bits.set(1, 64, true);
+        bits.set(1, 129, true);
+
+        assert!(bits.get(0, 0));
+        assert!(bits.get(1, 64));
+        assert!(bits.get(1, 129));
+        assert!(!bits.get(0, 129));
+        assert_eq!(bits.count_ones(), 3);
+
+        bits.set(1, 64, false);
+        assert!(!bits.get(1, 64));
+    }
+
+    #[test]
+    fn bit_matrix_logic() {
+        let a = BitMatrix::from(&Matrix::from(vec![
+            vec![true, true, false]
+        ]));
+        let b = BitMatrix::from(&Matrix::from(vec![
+            vec![false, true, true]
+        ]));
+
+        assert_eq!((&a & &b).to_matrix(), Matrix::from(vec![vec![false, true, false]]));
+        assert_eq!((&a | &b).to_matrix(), Matrix::from(vec![vec![true, true, true]]));
+        assert_eq!((&a ^ &b).to_matrix(), Matrix::from(vec![vec![true, false, true]]));
+        assert_eq!((!&a).to_matrix(), Matrix::from(vec![vec![false, false, true]]));
+        assert_eq!((!&a).count_ones(), 1);
+    }
+
+    #[test]
+    fn bit_matrix_product_and_closure() {
+        // 0 -> 1 -> 2, 3 isolated
+        let mut graph = BitMatrix::new(4, 4);
+        graph.set(0, 1, true);
+        graph.set(1, 2, true);
+
+        let two_steps = &graph * &graph;
+        assert!(two_steps.get(0, 2));
+        assert_eq!(two_steps.count_ones(), 1);
+
+        let closure = graph.transitive_closure();
+        assert!(closure.get(0, 1));
+        assert!(closure.get(0, 2));
+        assert!(closure.get(1, 2));
+        assert!(!closure.get(2, 0));
+        assert!(!closure.get(3, 3));
+        assert_eq!(closure.count_ones(), 3);
+    }
+}
@@ -0,0 +1,383 @@
+use std::thread;
+
+use num::{Float, Zero};
+
+use crate::Matrix;
+use crate::error::MatrixError;
+use crate::parallel::thread_count;
+use crate::shape::Shape;
+
+/// A square matrix stored as the independent square blocks along its diagonal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockDiagonal<T> {
+    blocks: Vec<Matrix<T>>
+}
+
+impl<T> BlockDiagonal<T> {
+    /// A block-diagonal matrix from blocks whose structure is already known,
+    /// or [`MatrixError::NotSquare`] for the first block that isn't square.
+    pub fn new(blocks: Vec<Matrix<T>>) -> Result<BlockDiagonal<T>, MatrixError> {
+        if let Some(block) = blocks.iter().find(|block| block.rows != block.cols) {
+            return Err(MatrixError::NotSquare { rows: block.rows, cols: block.cols });
+        }
+        Ok(BlockDiagonal {
+            blocks
+        })
+    }
+
+    pub fn blocks(&self) -> &[Matrix<T>] {
+        &self.blocks
+    }
+}
+
+impl<T: Clone + Zero> Matrix<T> {
+    /// Places `blocks` along the diagonal of an otherwise zero matrix.
+    ///
+    /// The blocks need not be square; the result has the sum of their rows and
+    /// the sum of their columns.
+    pub fn block_diag(blocks: &[&Matrix<T>]) -> Matrix<T> {
+        let rows = blocks.iter().map(|block| block.rows).sum();
+        let cols = blocks.iter().map(|block| block.cols).sum();
+
+        let mut matrix = Matrix::new(rows, cols, T::zero());
+        let (mut row_offset, mut col_offset) = (0, 0);
+        for block in blocks {
+            for i in 0..block.rows {
+                for j in 0..block.cols {
+                    matrix.set(row_offset + i, col_offset + j, block.get(i, j).clone());
+                }
+            }
+            row_offset += block.rows;
+            col_offset += block.cols;
+        }
+        matrix
+    }
+
+    /// Sizes of the finest square block-diagonal partition of a square matrix.
+    ///
+    /// A matrix with no block structure yields a single block of its full size.
+    pub fn block_sizes(&self) -> Vec<usize> {
+        assert_eq!(self.rows, self.cols);
+
+        let mut sizes = vec![];
+        let mut start = 0;
+        let mut end = 0;
+        for i in 0..self.rows {
+            // The block containing i must extend to every nonzero in row i and column i.
+            for k in i..self.cols {
+                if !self.get(i, k).is_zero() || !self.get(k, i).is_zero() {
+                    end = end.max(k);
+                }
+            }
+            if end == i {
+                sizes.push(i + 1 - start);
+                start = i + 1;
+                end = i + 1;
+            }
+        }
+        sizes
+    }
+
+    /// Splits a square matrix into its diagonal blocks, discarding the zero off-diagonal parts.
+    pub fn to_block_diagonal(&self) -> BlockDiagonal<T> {
+        let mut blocks = vec![];
+        let mut offset = 0;
+        for size in self.block_sizes() {
+            let mut data = vec![];
+            for i in offset..offset + size {
+                data.extend_from_slice(&self.data[i * self.cols + offset..i * self.cols + offset + size]);
+            }
+            blocks.push(Matrix {
+                rows: size,
+                cols: size,
+                data
+            });
+            offset += size;
+        }
+        BlockDiagonal {
+            blocks
+        }
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Joins matrices with the same number of rows side by side, e.g. the
+    /// augmented matrix `[a | b]` as `Matrix::hstack(&[&a, &b])`.
+    pub fn hstack(matrices: &[&Matrix<T>]) -> Result<Matrix<T>, MatrixError> {
+        let first = matrices.first().ok_or(MatrixError::EmptyInput)?;
+        if let Some(other) = matrices.iter().find(|matrix| matrix.rows != first.rows) {
+            return Err(MatrixError::DimensionMismatch { left: first.shape(), right: other.shape() });
+        }
+
+        let cols = matrices.iter().map(|matrix| matrix.cols).sum();
+        let mut data = Vec::with_capacity(first.rows * cols);
+        for i in 0..first.rows {
+            for matrix in matrices {
+                data.extend_from_slice(&matrix.data[i * matrix.cols..(i + 1) * matrix.cols]);
+            }
+        }
+        Ok(Matrix {
+            rows: first.rows,
+            cols,
+            data
+        })
+    }
+
+    /// Stacks matrices with the same number of columns on top of each other.
+    pub fn vstack(matrices: &[&Matrix<T>]) -> Result<Matrix<T>, MatrixError> {
+        let first = matrices.first().ok_or(MatrixError::EmptyInput)?;
+        if let Some(other) = matrices.iter().find(|matrix| matrix.cols != first.cols) {
+            return Err(MatrixError::DimensionMismatch { left: first.shape(), right: other.shape() });
+        }
+
+        Ok(Matrix {
+            rows: matrices.iter().map(|matrix| matrix.rows).sum(),
+            cols: first.cols,
+            data: matrices.iter().flat_map(|matrix| matrix.data.iter().cloned()).collect()
+        })
+    }
+
+    /// Assembles a matrix from a grid of blocks, given as rows of blocks, e.g.
+    /// `[[a, b], [c, d]]` for the matrix with `a` and `b` above `c` and `d`.
+    ///
+    /// The blocks in each block row must have equal heights, those in each
+    /// block column equal widths, and every block row the same number of
+    /// blocks ([`MatrixError::RaggedRows`] otherwise).
+    pub fn from_blocks(blocks: &[&[&Matrix<T>]]) -> Result<Matrix<T>, MatrixError> {
+        let first_row = blocks.first().ok_or(MatrixError::EmptyInput)?;
+        for (i, row) in blocks.iter().enumerate() {
+            if row.len() != first_row.len() {
+                return Err(MatrixError::RaggedRows { row: i, expected: first_row.len(), found: row.len() });
+            }
+            for (j, block) in row.iter().enumerate() {
+                if block.cols != first_row[j].cols || block.rows != row[0].rows {
+                    let expected = Shape::new(row[0].rows, first_row[j].cols);
+                    return Err(MatrixError::DimensionMismatch { left: expected, right: block.shape() });
+                }
+            }
+        }
+
+        let block_rows = blocks.iter()
+            .map(|row| Matrix::hstack(row))
+            .collect::<Result<Vec<Matrix<T>>, MatrixError>>()?;
+        Matrix::vstack(&block_rows.iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T: Float> BlockDiagonal<T> {
+    pub fn size(&self) -> usize {
+        self.blocks.iter().map(|block| block.rows).sum()
+    }
+
+    fn rhs_slices(&self, b: &Matrix<T>) -> Vec<Matrix<T>> {
+        let mut slices = vec![];
+        let mut offset = 0;
+        for block in self.blocks.iter() {
+            slices.push(Matrix {
+                rows: block.rows,
+                cols: b.cols,
+                data: b.data[offset * b.cols..(offset + block.rows) * b.cols].to_vec()
+            });
+            offset += block.rows;
+        }
+        slices
+    }
+
+    fn check_rhs(&self, b: &Matrix<T>) -> Result<(), MatrixError> {
+        if b.rows != self.size() {
+            return Err(MatrixError::DimensionMismatch {
+                left: Shape::new(self.size(), self.size()),
+                right: b.shape()
+            });
+        }
+        Ok(())
+    }
+
+    /// Solves `A x = b` one block at a time.
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.check_rhs(b)?;
+
+        let mut data = vec![];
+        for (block, rhs) in self.blocks.iter().zip(self.rhs_slices(b)) {
+            data.extend(block.solve(&rhs)?.data);
+        }
+        Ok(Matrix {
+            rows: b.rows,
+            cols: b.cols,
+            data
+        })
+    }
+
+    /// [`BlockDiagonal::solve`], with the blocks split into one contiguous
+    /// group per available core and each group solved on its own thread.
+    pub fn par_solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: Send + Sync
+    {
+        self.check_rhs(b)?;
+
+        let slices = self.rhs_slices(b);
+        let per_thread = self.blocks.len().div_ceil(thread_count()).max(1);
+        let groups: Vec<Vec<Result<Matrix<T>, MatrixError>>> = thread::scope(|scope| {
+            let handles: Vec<_> = self.blocks.chunks(per_thread)
+                .zip(slices.chunks(per_thread))
+                .map(|(blocks, rhs)| scope.spawn(move || {
+                    blocks.iter().zip(rhs.iter()).map(|(block, rhs)| block.solve(rhs)).collect()
+                }))
+                .collect();
+            handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+        });
+
+        let mut data = vec![];
+        for solution in groups.into_iter().flatten() {
+            data.extend(solution?.data);
+        }
+        Ok(Matrix {
+            rows: b.rows,
+            cols: b.cols,
+            data
+        })
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// Solves `self * x = b` by detecting the block-diagonal structure of `self`
+    /// and solving each block independently.
+    pub fn solve_block_diagonal(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        self.to_block_diagonal().solve(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Matrix<f64> {
+        let a = Matrix::from(vec![
+            vec![2.0, 1.0],
+            vec![1.0, 3.0]
+        ]);
+        let b = Matrix::from(vec![vec![4.0]]);
+        let c = Matrix::from(vec![
+            vec![1.0, 2.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 3.0, 5.0]
+        ]);
+        Matrix::block_diag(&[&a, &b, &c])
+    }
+
+    #[test]
+    fn block_diag_construction() {
+        let a = Matrix::from(vec![vec![1, 2]]);
+        let b = Matrix::from(vec![vec![3], vec![4]]);
+
+        let expected_matrix = Matrix::from(vec![
+            vec![1, 2, 0],
+            vec![0, 0, 3],
+            vec![0, 0, 4]
+        ]);
+        assert_eq!(Matrix::block_diag(&[&a, &b]), expected_matrix);
+    }
+
+    #[test]
+    fn stacking_and_blocks() {
+        let a = Matrix::from(vec![
+            vec![1, 2],
+            vec![3, 4]
+        ]);
+        let b = Matrix::from(vec![vec![5], vec![6]]);
+        let c = Matrix::from(vec![vec![7, 8]]);
+
+        let augmented = Matrix::hstack(&[&a, &b]).unwrap();
+        assert_eq!(augmented.shape(), Shape::new(2, 3));
+        assert_eq!(augmented, Matrix::from(vec![vec![1, 2, 5], vec![3, 4, 6]]));
+
+        let stacked = Matrix::vstack(&[&a, &c]).unwrap();
+        assert_eq!(stacked.shape(), Shape::new(3, 2));
+        assert_eq!(stacked, Matrix::from(vec![vec![1, 2], vec![3, 4], vec![7, 8]]));
+
+        let d = Matrix::from(vec![vec![9]]);
+        let blocks = Matrix::from_blocks(&[&[&a, &b], &[&c, &d]]).unwrap();
+        assert_eq!(blocks, Matrix::from(vec![
+            vec![1, 2, 5],
+            vec![3, 4, 6],
+            vec![7, 8, 9]
+        ]));
+
+        assert_eq!(Matrix::hstack(&[&a, &c]), Err(MatrixError::DimensionMismatch { left: a.shape(), right: c.shape() }));
+        assert_eq!(Matrix::vstack(&[&a, &b]), Err(MatrixError::DimensionMismatch { left: a.shape(), right: b.shape() }));
+        assert_eq!(Matrix::<i32>::hstack(&[]), Err(MatrixError::EmptyInput));
+        assert_eq!(
+            Matrix::from_blocks(&[&[&a, &b], &[&b, &a]]),
+            Err(MatrixError::DimensionMismatch { left: Shape::new(2, 2), right: b.shape() })
+        );
+        assert_eq!(
+            Matrix::from_blocks(&[&[&a, &b], &[&c]]),
+            Err(MatrixError::RaggedRows { row: 1, expected: 2, found: 1 })
+        );
+    }
+
+    #[test]
+    fn block_diagonal_from_known_blocks() {
+        let blocks: Vec<Matrix<f64>> = (1..=500).map(|k| Matrix::from(vec![
+            vec![k as f64, 1.0],
+            vec![0.0, 2.0]
+        ])).collect();
+        let matrix = BlockDiagonal::new(blocks).unwrap();
+        assert_eq!(matrix.size(), 1000);
+        assert_eq!(matrix.blocks().len(), 500);
+
+        let b = Matrix::new(1000, 1, 2.0);
+        assert_eq!(matrix.par_solve(&b).unwrap(), matrix.solve(&b).unwrap());
+
+        assert_eq!(
+            BlockDiagonal::new(vec![Matrix::new(2, 2, 1.0), Matrix::new(2, 3, 1.0)]),
+            Err(MatrixError::NotSquare { rows: 2, cols: 3 })
+        );
+    }
+
+    #[test]
+    fn detect_block_sizes() {
+        let matrix = example();
+        assert_eq!(matrix.block_sizes(), vec![2, 1, 3]);
+        assert_eq!(matrix.to_block_diagonal().blocks[1], Matrix::from(vec![vec![4.0]]));
+
+        let dense = Matrix::new(3, 3, 1.0);
+        assert_eq!(dense.block_sizes(), vec![3]);
+    }
+
+    #[test]
+    fn solve_block_diagonal_system() {
+        let matrix = example();
+        let x = Matrix::from(vec![
+            vec![1.0, -1.0],
+            vec![2.0, 0.0],
+            vec![3.0, 1.0],
+            vec![4.0, 2.0],
+            vec![5.0, 3.0],
+            vec![6.0, 4.0]
+        ]);
+        let b = matrix.clone() * x.clone();
+
+        let solution = matrix.solve_block_diagonal(&b).unwrap();
+        let parallel = matrix.to_block_diagonal().par_solve(&b).unwrap();
+        for i in 0..x.data.len() {
+            assert!((solution.data[i] - x.data[i]).abs() < 1e-12);
+            assert!((parallel.data[i] - x.data[i]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn solve_singular_block() {
+        let singular = Matrix::from(vec![
+            vec![1.0, 2.0],
+            vec![2.0, 4.0]
+        ]);
+        let matrix = Matrix::block_diag(&[&Matrix::from(vec![vec![1.0]]), &singular]);
+        let b = Matrix::new(3, 1, 1.0);
+
+        assert_eq!(matrix.solve_block_diagonal(&b), Err(MatrixError::Singular));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::Matrix;
+use crate::element::MatrixElement;
+use crate::error::MatrixError;
+
+impl<T> Matrix<T> {
+    /// Combines two matrices element-wise with NumPy-style broadcasting.
+    ///
+    /// Along each axis the sizes must match, or one of them must be 1, in which
+    /// case that matrix is repeated along the axis. This lets a `1 x n` row vector
+    /// or `m x 1` column vector be applied to every row or column of an `m x n`
+    /// matrix, and also lets a column and a row vector combine into an outer result.
+    pub fn broadcast_zip<U, F>(&self, other: &Matrix<T>, f: F) -> Result<Matrix<U>, MatrixError>
+        where F: Fn(&T, &T) -> U
+    {
+        let shape = self.shape().broadcast_with(other.shape())?;
+
+        let mut data = vec![];
+        for i in 0..shape.rows {
+            for j in 0..shape.cols {
+                let left = self.get(i % self.rows, j % self.cols);
+                let right = other.get(i % other.rows, j % other.cols);
+                data.push(f(left, right));
+            }
+        }
+
+        Ok(Matrix {
+            rows: shape.rows,
+            cols: shape.cols,
+            data
+        })
+    }
+
+    pub fn broadcast_add(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: MatrixElement
+    {
+        self.broadcast_zip(other, |a, b| a.clone() + b.clone())
+    }
+
+    pub fn broadcast_sub(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: MatrixElement
+    {
+        self.broadcast_zip(other, |a, b| a.clone() - b.clone())
+    }
+
+    pub fn broadcast_mul(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: MatrixElement
+    {
+        self.broadcast_zip(other, |a, b| a.clone() * b.clone())
+    }
+
+    pub fn broadcast_div(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: MatrixElement
+    {
+        self.broadcast_zip(other, |a, b| a.clone() / b.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Shape;
+
+    #[test]
+    fn broadcast_row_vector() {
+        let matrix = Matrix::from(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6]
+        ]);
+        let bias = Matrix::from(vec![vec![10, 20, 30]]);
+
+        let expected_matrix = Matrix::from(vec![
+            vec![11, 22, 33],
+            vec![14, 25, 36]
+        ]);
+        assert_eq!(matrix.broadcast_add(&bias).unwrap(), expected_matrix);
+    }
+
+    #[test]
+    fn broadcast_column_vector() {
+        let matrix = Matrix::from(vec![
+            vec![1.0, 3.0],
+            vec![10.0, 30.0]
+        ]);
+        let means = Matrix::from(vec![vec![2.0], vec![20.0]]);
+
+        let centred = matrix.broadcast_sub(&means).unwrap();
+        assert_eq!(centred, Matrix::from(vec![vec![-1.0, 1.0], vec![-10.0, 10.0]]));
+
+        let scaled = matrix.broadcast_div(&means).unwrap();
+        assert_eq!(scaled, Matrix::from(vec![vec![0.5, 1.5], vec![0.5, 1.5]]));
+    }
+
+    #[test]
+    fn broadcast_outer() {
+        let column = Matrix::from(vec![vec![1], vec![2]]);
+        let row = Matrix::from(vec![vec![3, 4, 5]]);
+
+        let product = column.broadcast_mul(&row).unwrap();
+        assert_eq!((product.rows, product.cols), (2, 3));
+        assert_eq!(product.data, vec![3, 4, 5, 6, 8, 10]);
+    }
+
+    #[test]
+    fn broadcast_incompatible_shapes() {
+        let matrix = Matrix::new(2, 3, 0);
+        let other = Matrix::new(1, 2, 0);
+
+        assert_eq!(
+            matrix.broadcast_add(&other),
+            Err(MatrixError::DimensionMismatch { left: Shape::new(2, 3), right: Shape::new(1, 2) })
+        );
+    }
+}
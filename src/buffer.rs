@@ -0,0 +1,140 @@
+use crate::Matrix;
+use crate::element::MatrixElement;
+use crate::shape::Shape;
+use crate::traits::MatrixOps;
+
+/// A `rows x cols` matrix stored in a caller-owned, row-major buffer.
+///
+/// Nothing here allocates, so it suits real-time code that must prepare its
+/// storage up front and only read and write it on the hot path.
+#[derive(Debug, PartialEq)]
+pub struct MatrixMut<'a, T> {
+    rows: usize,
+    cols: usize,
+    data: &'a mut [T]
+}
+
+impl<'a, T> MatrixMut<'a, T> {
+    /// Wraps the first `rows * cols` elements of `buffer`, which must be at least that long.
+    pub fn from_buffer(rows: usize, cols: usize, buffer: &'a mut [T]) -> MatrixMut<'a, T> {
+        assert!(buffer.len() >= rows * cols, "a {}x{} matrix needs {} elements but the buffer has {}",
+            rows, cols, rows * cols, buffer.len());
+
+        MatrixMut {
+            rows,
+            cols,
+            data: &mut buffer[..rows * cols]
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &T {
+        &self.data[row * self.cols + col]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        self.data[row * self.cols + col] = value;
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        self.data
+    }
+
+    pub fn fill(&mut self, value: T)
+        where T: Clone
+    {
+        for val in self.data.iter_mut() {
+            *val = value.clone();
+        }
+    }
+
+    /// Copies the elements of an equally shaped matrix into the buffer.
+    pub fn copy_from(&mut self, other: &Matrix<T>)
+        where T: Clone
+    {
+        assert_eq!((self.rows, self.cols), (other.rows, other.cols));
+        self.data.clone_from_slice(&other.data);
+    }
+}
+
+impl<T: MatrixElement> MatrixMut<'_, T> {
+    pub fn scale(&mut self, factor: T) {
+        for val in self.data.iter_mut() {
+            *val = val.clone() * factor.clone();
+        }
+    }
+
+    /// Overwrites `self` with the product `a * b`.
+    pub fn mul_into(&mut self, a: &impl MatrixOps<T>, b: &impl MatrixOps<T>) {
+        let shape = a.shape().matmul_with(b.shape()).unwrap_or_else(|error| panic!("{}", error));
+        assert_eq!(self.shape(), shape);
+        let inner = a.ncols();
+
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let mut total = T::zero();
+                for k in 0..inner {
+                    total = total + a.element(i, k) * b.element(k, j);
+                }
+                self.set(i, j, total);
+            }
+        }
+    }
+}
+
+impl<T: MatrixElement> MatrixOps<T> for MatrixMut<'_, T> {
+    fn shape(&self) -> Shape {
+        Shape::new(self.rows, self.cols)
+    }
+
+    fn element(&self, row: usize, col: usize) -> T {
+        self.get(row, col).clone()
+    }
+
+    fn row_entries(&self, row: usize) -> Box<dyn Iterator<Item = (usize, T)> + '_> {
+        Box::new(self.data[row * self.cols..(row + 1) * self.cols].iter().cloned().enumerate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matrix_over_external_buffer() {
+        let mut buffer = [0.0; 8];
+        let mut matrix = MatrixMut::from_buffer(2, 3, &mut buffer);
+
+        matrix.fill(1.0);
+        matrix.set(1, 2, 4.0);
+        matrix.scale(2.0);
+
+        assert_eq!(*matrix.get(1, 2), 8.0);
+        assert_eq!(matrix.as_slice(), &[2.0, 2.0, 2.0, 2.0, 2.0, 8.0]);
+        // Elements beyond rows * cols are left alone.
+        assert_eq!(buffer, [2.0, 2.0, 2.0, 2.0, 2.0, 8.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn multiply_into_buffer() {
+        let a = Matrix::from(vec![
+            vec![1, 2],
+            vec![3, 4]
+        ]);
+        let mut b_buffer = [0; 4];
+        let mut b = MatrixMut::from_buffer(2, 2, &mut b_buffer);
+        b.copy_from(&Matrix::from(vec![vec![0, 1], vec![1, 0]]));
+
+        let mut out_buffer = [0; 4];
+        let mut out = MatrixMut::from_buffer(2, 2, &mut out_buffer);
+        out.mul_into(&a, &b);
+
+        assert_eq!(out.as_slice(), &[2, 1, 4, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn buffer_too_small() {
+        let mut buffer = [0; 3];
+        MatrixMut::from_buffer(2, 2, &mut buffer);
+    }
+}
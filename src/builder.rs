@@ -0,0 +1,118 @@
+use num::Zero;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+
+/// Staged construction of a [`Matrix`], validated when [`MatrixBuilder::build`] is called.
+///
+/// Settings are applied in a fixed order regardless of call order: the fill value
+/// first, then the diagonal, then individual entries.
+#[derive(Debug, Clone)]
+pub struct MatrixBuilder<T> {
+    rows: Option<usize>,
+    cols: Option<usize>,
+    fill: Option<T>,
+    diagonal: Option<T>,
+    entries: Vec<(usize, usize, T)>
+}
+
+impl<T> Matrix<T> {
+    pub fn builder() -> MatrixBuilder<T> {
+        MatrixBuilder {
+            rows: None,
+            cols: None,
+            fill: None,
+            diagonal: None,
+            entries: vec![]
+        }
+    }
+}
+
+impl<T: Clone + Zero> MatrixBuilder<T> {
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+
+    pub fn cols(mut self, cols: usize) -> Self {
+        self.cols = Some(cols);
+        self
+    }
+
+    /// The value of every element not otherwise set. Defaults to zero.
+    pub fn fill(mut self, value: T) -> Self {
+        self.fill = Some(value);
+        self
+    }
+
+    /// The value of every element on the main diagonal.
+    pub fn diagonal(mut self, value: T) -> Self {
+        self.diagonal = Some(value);
+        self
+    }
+
+    /// Sets a single element, overriding the fill and diagonal.
+    pub fn entry(mut self, row: usize, col: usize, value: T) -> Self {
+        self.entries.push((row, col, value));
+        self
+    }
+
+    pub fn build(self) -> Result<Matrix<T>, MatrixError> {
+        let rows = self.rows.ok_or(MatrixError::IncompleteBuilder { missing: "rows" })?;
+        let cols = self.cols.ok_or(MatrixError::IncompleteBuilder { missing: "cols" })?;
+
+        let mut matrix = Matrix::new(rows, cols, self.fill.unwrap_or_else(T::zero));
+        if let Some(value) = self.diagonal {
+            for i in 0..rows.min(cols) {
+                matrix.set(i, i, value.clone());
+            }
+        }
+        for (row, col, value) in self.entries {
+            if row >= rows || col >= cols {
+                return Err(MatrixError::IndexOutOfBounds { row, col, rows, cols });
+            }
+            matrix.set(row, col, value);
+        }
+
+        Ok(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_identity_like_matrix() {
+        let matrix = Matrix::builder()
+            .rows(3)
+            .cols(3)
+            .fill(0.5)
+            .diagonal(1.0)
+            .entry(0, 2, 7.0)
+            .build()
+            .unwrap();
+
+        let expected_matrix = Matrix::from(vec![
+            vec![1.0, 0.5, 7.0],
+            vec![0.5, 1.0, 0.5],
+            vec![0.5, 0.5, 1.0]
+        ]);
+        assert_eq!(matrix, expected_matrix);
+    }
+
+    #[test]
+    fn build_defaults_to_zero_fill() {
+        let matrix: Matrix<i32> = Matrix::builder().rows(2).cols(1).build().unwrap();
+        assert_eq!(matrix.data, vec![0, 0]);
+    }
+
+    #[test]
+    fn build_validates() {
+        let missing = Matrix::<i32>::builder().rows(2).build();
+        assert_eq!(missing, Err(MatrixError::IncompleteBuilder { missing: "cols" }));
+
+        let out_of_bounds = Matrix::builder().rows(2).cols(2).entry(2, 0, 1).build();
+        assert_eq!(out_of_bounds, Err(MatrixError::IndexOutOfBounds { row: 2, col: 0, rows: 2, cols: 2 }));
+    }
+}
@@ -0,0 +1,172 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use crate::Matrix;
+use crate::binary::{self, ELEMENT_LEN, HEADER_LEN};
+
+/// A matrix of `f64` kept in a binary file and processed a block of rows at a time.
+///
+/// Only `chunk_rows` rows are held in memory at once, so matrices much larger than
+/// RAM can be reduced, transformed and multiplied by vectors. The file uses the
+/// format of [`Matrix::write_binary`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkedMatrix {
+    path: PathBuf,
+    rows: usize,
+    cols: usize,
+    chunk_rows: usize
+}
+
+impl ChunkedMatrix {
+    /// Opens a matrix file, streaming it in blocks of `chunk_rows` rows.
+    pub fn open<P: AsRef<Path>>(path: P, chunk_rows: usize) -> io::Result<ChunkedMatrix> {
+        assert!(chunk_rows > 0, "chunks must contain at least one row");
+
+        let mut file = File::open(path.as_ref())?;
+        // `read_header` has checked this size can't overflow.
+        let (rows, cols) = binary::read_header(&mut file)?;
+        let expected_len = HEADER_LEN + (rows * cols) as u64 * ELEMENT_LEN;
+        if file.metadata()?.len() < expected_len {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "matrix file is truncated"));
+        }
+
+        Ok(ChunkedMatrix {
+            path: path.as_ref().to_path_buf(),
+            rows,
+            cols,
+            chunk_rows
+        })
+    }
+
+    /// Calls `f` with the index of the first row of each block and the block itself.
+    pub fn for_each_chunk<F>(&self, mut f: F) -> io::Result<()>
+        where F: FnMut(usize, &Matrix<f64>)
+    {
+        let mut reader = BufReader::new(File::open(&self.path)?);
+        reader.seek(SeekFrom::Start(HEADER_LEN))?;
+
+        let mut start = 0;
+        while start < self.rows {
+            let rows = self.chunk_rows.min(self.rows - start);
+            let chunk = Matrix {
+                rows,
+                cols: self.cols,
+                data: binary::read_elements(&mut reader, rows * self.cols)?
+            };
+            f(start, &chunk);
+            start += rows;
+        }
+        Ok(())
+    }
+
+    pub fn sum(&self) -> io::Result<f64> {
+        let mut total = 0.0;
+        self.for_each_chunk(|_, chunk| total += chunk.data.iter().sum::<f64>())?;
+        Ok(total)
+    }
+
+    pub fn column_sums(&self) -> io::Result<Vec<f64>> {
+        let mut sums = vec![0.0; self.cols];
+        self.for_each_chunk(|_, chunk| {
+            for (index, val) in chunk.data.iter().enumerate() {
+                sums[index % chunk.cols] += val;
+            }
+        })?;
+        Ok(sums)
+    }
+
+    /// Computes `self * x`, holding only one block of rows and the result in memory.
+    pub fn matvec(&self, x: &[f64]) -> io::Result<Vec<f64>> {
+        assert_eq!(x.len(), self.cols);
+
+        let mut output = Vec::with_capacity(self.rows);
+        self.for_each_chunk(|_, chunk| {
+            for i in 0..chunk.rows {
+                output.push((0..chunk.cols).map(|j| chunk.get(i, j) * x[j]).sum());
+            }
+        })?;
+        Ok(output)
+    }
+
+    /// Applies `f` to every element, writing the result to a new file at `path`.
+    pub fn map_to<P, F>(&self, path: P, f: F) -> io::Result<ChunkedMatrix>
+        where P: AsRef<Path>, F: Fn(f64) -> f64
+    {
+        let mut writer = BufWriter::new(File::create(path.as_ref())?);
+        binary::write_header(&mut writer, self.rows, self.cols)?;
+
+        let mut result = Ok(());
+        self.for_each_chunk(|_, chunk| {
+            if result.is_ok() {
+                let mapped: Vec<f64> = chunk.data.iter().map(|&val| f(val)).collect();
+                result = binary::write_elements(&mut writer, &mapped);
+            }
+        })?;
+        result?;
+        writer.flush()?;
+
+        ChunkedMatrix::open(path, self.chunk_rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::TempPath;
+
+    fn example() -> Matrix<f64> {
+        let mut data = vec![];
+        for i in 0..7 {
+            data.push(vec![i as f64, 1.0, -(i as f64)]);
+        }
+        Matrix::from(data)
+    }
+
+    #[test]
+    fn chunked_reductions_and_matvec() {
+        let path = TempPath::new("chunked_reductions");
+        example().write_binary(&path.0).unwrap();
+
+        let chunked = ChunkedMatrix::open(&path.0, 3).unwrap();
+        let mut chunk_starts = vec![];
+        chunked.for_each_chunk(|start, chunk| chunk_starts.push((start, chunk.rows))).unwrap();
+
+        assert_eq!(chunk_starts, vec![(0, 3), (3, 3), (6, 1)]);
+        assert_eq!(chunked.sum().unwrap(), 7.0);
+        assert_eq!(chunked.column_sums().unwrap(), vec![21.0, 7.0, -21.0]);
+        assert_eq!(chunked.matvec(&[1.0, 2.0, 3.0]).unwrap(), vec![2.0, 0.0, -2.0, -4.0, -6.0, -8.0, -10.0]);
+    }
+
+    #[test]
+    fn chunked_elementwise_map() {
+        let input = TempPath::new("chunked_map_input");
+        let output = TempPath::new("chunked_map_output");
+        example().write_binary(&input.0).unwrap();
+
+        let doubled = ChunkedMatrix::open(&input.0, 2).unwrap().map_to(&output.0, |x| 2.0 * x).unwrap();
+
+        assert_eq!(Matrix::read_binary(&doubled.path).unwrap(), example() * 2.0);
+    }
+
+    #[test]
+    fn chunked_rejects_truncated_file() {
+        let path = TempPath::new("chunked_truncated");
+        example().write_binary(&path.0).unwrap();
+        let file = std::fs::OpenOptions::new().write(true).open(&path.0).unwrap();
+        file.set_len(HEADER_LEN + 4 * ELEMENT_LEN).unwrap();
+
+        assert!(ChunkedMatrix::open(&path.0, 2).is_err());
+    }
+
+    #[test]
+    fn chunked_rejects_overflowing_header() {
+        let path = TempPath::new("chunked_overflow");
+        let mut file = File::create(&path.0).unwrap();
+        binary::write_header(&mut file, 1 << 61, 1).unwrap();
+        drop(file);
+
+        let error = ChunkedMatrix::open(&path.0, 2).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,82 @@
+use num::{One, Zero};
+
+use crate::Matrix;
+
+impl<T> Matrix<T> {
+    /// A `rows x cols` matrix with element `(i, j)` set to `f(i, j)`, called in
+    /// row-major order.
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Matrix<T>
+        where F: FnMut(usize, usize) -> T
+    {
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                data.push(f(i, j));
+            }
+        }
+        Matrix {
+            rows,
+            cols,
+            data
+        }
+    }
+}
+
+impl<T: Clone + Zero> Matrix<T> {
+    pub fn zeros(rows: usize, cols: usize) -> Matrix<T> {
+        Matrix::new(rows, cols, T::zero())
+    }
+
+    /// A square matrix with `diagonal` on its main diagonal and zeros elsewhere.
+    pub fn from_diagonal(diagonal: &[T]) -> Matrix<T> {
+        let n = diagonal.len();
+        let mut matrix = Matrix::zeros(n, n);
+        for (i, val) in diagonal.iter().enumerate() {
+            matrix.set(i, i, val.clone());
+        }
+        matrix
+    }
+}
+
+impl<T: Clone + Zero + One> Matrix<T> {
+    pub fn ones(rows: usize, cols: usize) -> Matrix<T> {
+        Matrix::new(rows, cols, T::one())
+    }
+
+    /// The `n x n` identity matrix.
+    pub fn identity(n: usize) -> Matrix<T> {
+        Matrix::from_diagonal(&vec![T::one(); n])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::rational::Ratio;
+
+    use super::*;
+
+    #[test]
+    fn standard_constructors() {
+        assert_eq!(Matrix::<i32>::zeros(2, 3), Matrix::new(2, 3, 0));
+        assert_eq!(Matrix::<f64>::ones(3, 1), Matrix::from(vec![vec![1.0], vec![1.0], vec![1.0]]));
+        assert_eq!(Matrix::<i64>::identity(3), Matrix::from(vec![
+            vec![1, 0, 0],
+            vec![0, 1, 0],
+            vec![0, 0, 1]
+        ]));
+        assert_eq!(Matrix::from_diagonal(&[Ratio::new(1, 2), Ratio::new(3, 1)]).get_row(1), vec![Ratio::new(0, 1), Ratio::new(3, 1)]);
+        assert_eq!(Matrix::<f32>::identity(0).shape(), (0, 0).into());
+    }
+
+    #[test]
+    fn from_fn_builds_hilbert_matrix() {
+        let hilbert = Matrix::from_fn(3, 4, |i, j| Ratio::new(1, (i + j + 1) as i64));
+
+        assert_eq!(hilbert.shape(), (3, 4).into());
+        assert_eq!(*hilbert.get(2, 3), Ratio::new(1, 6));
+
+        let mut calls = vec![];
+        Matrix::from_fn(2, 2, |i, j| calls.push((i, j)));
+        assert_eq!(calls, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt::{Display, Formatter, Result};
+
+use num::Float;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+
+/// How many differing positions a [`MatrixDiff`] records.
+const REPORTED_POSITIONS: usize = 5;
+
+/// One element that differs between two matrices.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Difference<T> {
+    pub row: usize,
+    pub col: usize,
+    pub left: T,
+    pub right: T
+}
+
+/// A summary of how two equally shaped matrices differ.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatrixDiff<T> {
+    pub rows: usize,
+    pub cols: usize,
+    pub tolerance: T,
+    /// How many elements differ by more than the tolerance.
+    pub mismatches: usize,
+    pub max_abs_error: T,
+    /// The largest `|left - right| / max(|left|, |right|)`; zero when both are zero.
+    pub max_rel_error: T,
+    /// The first few differing elements in row-major order.
+    pub first: Vec<Difference<T>>
+}
+
+impl<T: Float> MatrixDiff<T> {
+    pub fn is_match(&self) -> bool {
+        self.mismatches == 0
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// Compares two matrices element by element, treating elements as equal when
+    /// they differ by at most `tolerance`.
+    ///
+    /// Two NaNs compare equal; a NaN against a number is a mismatch with an
+    /// infinite error.
+    pub fn diff(&self, other: &Matrix<T>, tolerance: T) -> std::result::Result<MatrixDiff<T>, MatrixError> {
+        self.shape().elementwise_with(other.shape())?;
+
+        let mut report = MatrixDiff {
+            rows: self.rows,
+            cols: self.cols,
+            tolerance,
+            mismatches: 0,
+            max_abs_error: T::zero(),
+            max_rel_error: T::zero(),
+            first: vec![]
+        };
+        for (index, (&left, &right)) in self.data.iter().zip(other.data.iter()).enumerate() {
+            let (abs_error, rel_error) = if left.is_nan() && right.is_nan() {
+                (T::zero(), T::zero())
+            } else if left.is_nan() || right.is_nan() {
+                (T::infinity(), T::infinity())
+            } else {
+                let abs_error = (left - right).abs();
+                let scale = left.abs().max(right.abs());
+                (abs_error, if scale == T::zero() { T::zero() } else { abs_error / scale })
+            };
+            report.max_abs_error = report.max_abs_error.max(abs_error);
+            report.max_rel_error = report.max_rel_error.max(rel_error);
+
+            if abs_error > tolerance {
+                report.mismatches += 1;
+                if report.first.len() < REPORTED_POSITIONS {
+                    report.first.push(Difference {
+                        row: index / self.cols,
+                        col: index % self.cols,
+                        left,
+                        right
+                    });
+                }
+            }
+        }
+        Ok(report)
+    }
+}
+
+impl<T: Float + Display> Display for MatrixDiff<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.is_match() {
+            return write!(f, "{}x{} matrices match within {}", self.rows, self.cols, self.tolerance);
+        }
+
+        writeln!(
+            f,
+            "{} of {} elements differ by more than {}",
+            self.mismatches,
+            self.rows * self.cols,
+            self.tolerance
+        )?;
+        writeln!(f, "max absolute error: {}", self.max_abs_error)?;
+        write!(f, "max relative error: {}", self.max_rel_error)?;
+        for difference in self.first.iter() {
+            write!(f, "\n  ({}, {}): {} != {}", difference.row, difference.col, difference.left, difference.right)?;
+        }
+        if self.mismatches > self.first.len() {
+            write!(f, "\n  ... and {} more", self.mismatches - self.first.len())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_reports_mismatches() {
+        let expected = Matrix::from(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, f64::NAN]
+        ]);
+        let actual = Matrix::from(vec![
+            vec![1.0, 2.5, 3.0],
+            vec![4.0, 4.0, f64::NAN]
+        ]);
+
+        let report = expected.diff(&actual, 1e-9).unwrap();
+
+        assert!(!report.is_match());
+        assert_eq!(report.mismatches, 2);
+        assert_eq!(report.max_abs_error, 1.0);
+        assert_eq!(report.max_rel_error, 0.2);
+        assert_eq!(report.first[0], Difference { row: 0, col: 1, left: 2.0, right: 2.5 });
+        assert_eq!(report.to_string(), "2 of 6 elements differ by more than 0.000000001\n\
+            max absolute error: 1\n\
+            max relative error: 0.2\n  \
+            (0, 1): 2 != 2.5\n  \
+            (1, 1): 5 != 4");
+
+        assert!(expected.diff(&actual, 1.0).unwrap().is_match());
+    }
+
+    #[test]
+    fn diff_truncates_positions() {
+        let report = Matrix::new(3, 3, 0.0).diff(&Matrix::new(3, 3, 1.0), 0.5).unwrap();
+
+        assert_eq!(report.mismatches, 9);
+        assert_eq!(report.first.len(), REPORTED_POSITIONS);
+        assert!(report.to_string().ends_with("... and 4 more"));
+
+        let wrong_shape = Matrix::new(3, 1, 0.0).diff(&Matrix::new(1, 3, 0.0), 0.5);
+        assert!(wrong_shape.is_err());
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::ops::Range;
+
+use crate::Matrix;
+
+/// The rows and columns `{}` and `{:?}` show before eliding the middle ones.
+const DISPLAY_ROWS: usize = 20;
+const DISPLAY_COLS: usize = 20;
+
+/// The indices shown when at most `max` of `0..len` fit: all of them, or the
+/// first and last halves of `max` around a gap.
+fn visible(len: usize, max: usize) -> (Range<usize>, Option<Range<usize>>) {
+    if len <= max {
+        (0..len, None)
+    } else {
+        (0..max.div_ceil(2), Some(len - max / 2..len))
+    }
+}
+
+/// A [`Matrix`] formatted with a limit on the rows and columns shown, from
+/// [`Matrix::display`].
+pub struct MatrixDisplay<'a, T> {
+    matrix: &'a Matrix<T>,
+    max_rows: usize,
+    max_cols: usize
+}
+
+impl<T> Matrix<T> {
+    /// Formats at most `max_rows` rows and `max_cols` columns, replacing the
+    /// middle ones with `...`, followed by the full shape.
+    ///
+    /// `{}` and `{:?}` on a matrix use a limit of 20 rows and 20 columns, so
+    /// printing a huge matrix by accident stays cheap; `{:#}` shows every
+    /// element. The result can be formatted with either, e.g.
+    /// `{:?}` on `matrix.display(rows, cols)` to debug print it in full.
+    pub fn display(&self, max_rows: usize, max_cols: usize) -> MatrixDisplay<'_, T> {
+        MatrixDisplay {
+            matrix: self,
+            max_rows,
+            max_cols
+        }
+    }
+}
+
+impl<T: Display> MatrixDisplay<'_, T> {
+    fn write_row(&self, f: &mut Formatter<'_>, i: usize) -> Result {
+        let (head, tail) = visible(self.matrix.cols, self.max_cols);
+        for j in head {
+            write!(f, "{}, ", self.matrix.get(i, j))?;
+        }
+        if let Some(tail) = tail {
+            write!(f, "..., ")?;
+            for j in tail {
+                write!(f, "{}, ", self.matrix.get(i, j))?;
+            }
+        }
+        writeln!(f)
+    }
+}
+
+impl<T: Display> Display for MatrixDisplay<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let (head, tail) = visible(self.matrix.rows, self.max_rows);
+        for i in head {
+            self.write_row(f, i)?;
+        }
+        if let Some(tail) = tail {
+            writeln!(f, "...")?;
+            for i in tail {
+                self.write_row(f, i)?;
+            }
+        }
+        write!(f, "Rows: {}, ", self.matrix.rows)?;
+        write!(f, "Columns: {}", self.matrix.cols)?;
+
+        Ok(())
+    }
+}
+
+impl<T> Display for Matrix<T> 
+    where T: Display
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result { 
+        if f.alternate() {
+            self.display(self.rows, self.cols).fmt(f)
+        } else {
+            self.display(DISPLAY_ROWS, DISPLAY_COLS).fmt(f)
+        }
+    }
+}
+
+/// The visible rows of a [`MatrixDisplay`], each as a list of its visible
+/// elements.
+struct DebugRows<'a, 'b, T>(&'b MatrixDisplay<'a, T>);
+
+/// The visible elements of one row of a [`MatrixDisplay`].
+struct DebugRow<'a, 'b, T>(&'b MatrixDisplay<'a, T>, usize);
+
+impl<T: Debug> Debug for DebugRow<'_, '_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let DebugRow(display, i) = *self;
+        let (head, tail) = visible(display.matrix.cols, display.max_cols);
+        let mut list = f.debug_list();
+        list.entries(head.map(|j| display.matrix.get(i, j)));
+        if let Some(tail) = tail {
+            list.entry(&format_args!("..."));
+            list.entries(tail.map(|j| display.matrix.get(i, j)));
+        }
+        list.finish()
+    }
+}
+
+impl<T: Debug> Debug for DebugRows<'_, '_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let (head, tail) = visible(self.0.matrix.rows, self.0.max_rows);
+        let mut list = f.debug_list();
+        list.entries(head.map(|i| DebugRow(self.0, i)));
+        if let Some(tail) = tail {
+            list.entry(&format_args!("..."));
+            list.entries(tail.map(|i| DebugRow(self.0, i)));
+        }
+        list.finish()
+    }
+}
+
+/// `Matrix { rows, cols, data }`, with `data` as a list of rows and the same
+/// elision as [`Display`].
+impl<T: Debug> Debug for MatrixDisplay<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("Matrix")
+            .field("rows", &self.matrix.rows)
+            .field("cols", &self.matrix.cols)
+            .field("data", &DebugRows(self))
+            .finish()
+    }
+}
+
+impl<T: Debug> Debug for Matrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.display(DISPLAY_ROWS, DISPLAY_COLS).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_small_matrix_in_full() {
+        let matrix = Matrix::from(vec![
+            vec![1, 2],
+            vec![3, 4]
+        ]);
+
+        assert_eq!(format!("{}", matrix), "1, 2, \n3, 4, \nRows: 2, Columns: 2");
+        assert_eq!(format!("{:?}", matrix), "Matrix { rows: 2, cols: 2, data: [[1, 2], [3, 4]] }");
+    }
+
+    #[test]
+    fn display_elides_large_matrix() {
+        let matrix = Matrix::from_fn(5, 6, |i, j| i * 10 + j);
+
+        let shown = format!("{}", matrix.display(3, 4));
+        assert_eq!(shown, "0, 1, ..., 4, 5, \n10, 11, ..., 14, 15, \n...\n40, 41, ..., 44, 45, \nRows: 5, Columns: 6");
+        assert_eq!(format!("{}", matrix.display(5, 6)), format!("{}", matrix));
+
+        let huge = Matrix::new(10_000, 10_000, 0u8);
+        let shown = format!("{}", huge);
+        assert_eq!(shown.lines().count(), 22);
+        assert!(shown.ends_with("Rows: 10000, Columns: 10000"));
+
+        let debug = format!("{:?}", matrix.display(3, 4));
+        assert_eq!(debug, "Matrix { rows: 5, cols: 6, data: [[0, 1, ..., 4, 5], [10, 11, ..., 14, 15], ..., [40, 41, ..., 44, 45]] }");
+        assert_eq!(format!("{:?}", matrix.display(5, 6)), format!("{:?}", matrix));
+
+        let debug = format!("{:?}", huge);
+        assert_eq!(debug.matches('[').count(), 21);
+        assert!(debug.starts_with("Matrix { rows: 10000, cols: 10000, data: [[0, 0,"));
+    }
+}
@@ -0,0 +1,357 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use num::{Float, Num, One, Zero};
+
+use crate::Matrix;
+
+/// A dual number `re + eps * ε` with `ε² = 0`, for forward-mode automatic differentiation.
+///
+/// Seeding an input with [`Dual::variable`] and running any computation over
+/// `Dual` elements yields the exact derivative of the result in `eps`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Dual<T> {
+    pub re: T,
+    pub eps: T
+}
+
+impl<T: Float> Dual<T> {
+    pub fn new(re: T, eps: T) -> Dual<T> {
+        Dual { re, eps }
+    }
+
+    /// A value that does not depend on the differentiation variable.
+    pub fn constant(re: T) -> Dual<T> {
+        Dual { re, eps: T::zero() }
+    }
+
+    /// The differentiation variable itself, with derivative one.
+    pub fn variable(re: T) -> Dual<T> {
+        Dual { re, eps: T::one() }
+    }
+
+    pub fn sqrt(self) -> Dual<T> {
+        let root = self.re.sqrt();
+        Dual::new(root, self.eps / (root + root))
+    }
+
+    pub fn exp(self) -> Dual<T> {
+        let exp = self.re.exp();
+        Dual::new(exp, self.eps * exp)
+    }
+
+    pub fn ln(self) -> Dual<T> {
+        Dual::new(self.re.ln(), self.eps / self.re)
+    }
+
+    pub fn sin(self) -> Dual<T> {
+        Dual::new(self.re.sin(), self.eps * self.re.cos())
+    }
+
+    pub fn cos(self) -> Dual<T> {
+        Dual::new(self.re.cos(), -self.eps * self.re.sin())
+    }
+
+    pub fn powi(self, n: i32) -> Dual<T> {
+        Dual::new(self.re.powi(n), self.eps * T::from(n).unwrap() * self.re.powi(n - 1))
+    }
+}
+
+impl<T: Display> Display for Dual<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{} + {}ε", self.re, self.eps)
+    }
+}
+
+impl<T: Float> Add for Dual<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Dual::new(self.re + rhs.re, self.eps + rhs.eps)
+    }
+}
+
+impl<T: Float> Sub for Dual<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Dual::new(self.re - rhs.re, self.eps - rhs.eps)
+    }
+}
+
+impl<T: Float> Mul for Dual<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Dual::new(self.re * rhs.re, self.re * rhs.eps + self.eps * rhs.re)
+    }
+}
+
+impl<T: Float> Div for Dual<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Dual::new(
+            self.re / rhs.re,
+            (self.eps * rhs.re - self.re * rhs.eps) / (rhs.re * rhs.re)
+        )
+    }
+}
+
+impl<T: Float> Rem for Dual<T> {
+    type Output = Self;
+
+    // a % b = a - b * trunc(a / b), and trunc is locally constant.
+    fn rem(self, rhs: Self) -> Self {
+        let quotient = (self.re / rhs.re).trunc();
+        Dual::new(self.re % rhs.re, self.eps - rhs.eps * quotient)
+    }
+}
+
+impl<T: Float> Neg for Dual<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Dual::new(-self.re, -self.eps)
+    }
+}
+
+impl<T: Float> Zero for Dual<T> {
+    fn zero() -> Self {
+        Dual::constant(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.eps.is_zero()
+    }
+}
+
+impl<T: Float> One for Dual<T> {
+    fn one() -> Self {
+        Dual::constant(T::one())
+    }
+}
+
+impl<T: Float> Num for Dual<T> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+
+    fn from_str_radix(s: &str, radix: u32) -> std::result::Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(s, radix).map(Dual::constant)
+    }
+}
+
+impl<T: Float> Matrix<Dual<T>> {
+    /// Gaussian elimination with partial pivoting on the real parts.
+    ///
+    /// Reduces `self` to upper triangular form, applying the same row operations to
+    /// `rhs`, and returns the determinant, whose real part is zero when `self` is
+    /// singular. NaN entries are never preferred as pivots and make the determinant NaN.
+    ///
+    /// A dual number with a zero real part can't be divided by, so when a column
+    /// has no usable pivot the elimination stops there. Its derivative still
+    /// needn't be zero, and is read off the remaining block instead: with the
+    /// real part of its first column zero, only that column's `eps` part
+    /// contributes to the derivative of its determinant.
+    fn eliminate(&mut self, rhs: &mut Matrix<Dual<T>>) -> Dual<T> {
+        assert_eq!(self.rows, self.cols);
+        assert_eq!(self.rows, rhs.rows);
+
+        let n = self.rows;
+        let mut determinant = Dual::one();
+        for k in 0..n {
+            // Ranking NaN below every magnitude keeps the comparison total.
+            let magnitude = |i: usize| {
+                let val = self.get(i, k).re.abs();
+                if val.is_nan() { -T::one() } else { val }
+            };
+            let pivot_row = (k..n)
+                .max_by(|&a, &b| magnitude(a).partial_cmp(&magnitude(b)).unwrap_or(Ordering::Equal))
+                .unwrap();
+            if self.get(pivot_row, k).re == T::zero() {
+                return determinant * self.singular_block_determinant(k);
+            }
+            if pivot_row != k {
+                for j in 0..n {
+                    self.data.swap(k * n + j, pivot_row * n + j);
+                }
+                for j in 0..rhs.cols {
+                    rhs.data.swap(k * rhs.cols + j, pivot_row * rhs.cols + j);
+                }
+                determinant = -determinant;
+            }
+
+            let pivot = *self.get(k, k);
+            determinant = determinant * pivot;
+            for i in k + 1..n {
+                let factor = *self.get(i, k) / pivot;
+                for j in k..n {
+                    let val = *self.get(i, j) - factor * *self.get(k, j);
+                    self.set(i, j, val);
+                }
+                for j in 0..rhs.cols {
+                    let val = *rhs.get(i, j) - factor * *rhs.get(k, j);
+                    rhs.set(i, j, val);
+                }
+            }
+        }
+        determinant
+    }
+
+    /// The determinant of the block from `k` onwards when the real part of its
+    /// first column is zero, which is `ε` times the determinant of the real
+    /// parts with that column swapped for its `eps` parts.
+    fn singular_block_determinant(&self, k: usize) -> Dual<T> {
+        let n = self.rows;
+        if (k..n).all(|i| self.get(i, k).eps == T::zero()) {
+            return Dual::zero();
+        }
+        let mut block = Matrix::from_fn(n - k, n - k, |i, j| {
+            let val = self.get(k + i, k + j);
+            Dual::constant(if j == 0 { val.eps } else { val.re })
+        });
+        let mut rhs = Matrix::new(n - k, 0, Dual::zero());
+        Dual::new(T::zero(), block.eliminate(&mut rhs).re)
+    }
+
+    /// The determinant, whose `eps` part is its derivative.
+    pub fn determinant(&self) -> Dual<T> {
+        let mut matrix = self.clone();
+        let mut rhs = Matrix::new(self.rows, 0, Dual::zero());
+        matrix.eliminate(&mut rhs)
+    }
+
+    /// Solves `self * x = b`, or returns `None` if `self` is singular or the
+    /// elimination meets a NaN.
+    ///
+    /// The `eps` parts of the solution are the derivatives of `x`.
+    pub fn solve(&self, b: &Matrix<Dual<T>>) -> Option<Matrix<Dual<T>>> {
+        let mut matrix = self.clone();
+        let mut x = b.clone();
+        let determinant = matrix.eliminate(&mut x).re;
+        if determinant == T::zero() || determinant.is_nan() {
+            return None;
+        }
+
+        let n = self.rows;
+        for i in (0..n).rev() {
+            for j in 0..x.cols {
+                let mut val = *x.get(i, j);
+                for k in i + 1..n {
+                    val = val - *matrix.get(i, k) * *x.get(k, j);
+                }
+                x.set(i, j, val / *matrix.get(i, i));
+            }
+        }
+        Some(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-10, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn dual_arithmetic_derivatives() {
+        let x = Dual::variable(3.0);
+
+        // d/dx (x^2 / (x + 1)) = (x^2 + 2x) / (x + 1)^2
+        let y = x * x / (x + Dual::one());
+        assert_close(y.re, 9.0 / 4.0);
+        assert_close(y.eps, 15.0 / 16.0);
+
+        let z = x.sin() * x.exp();
+        assert_close(z.eps, 3.0f64.exp() * (3.0f64.sin() + 3.0f64.cos()));
+        assert_close(x.powi(3).eps, 27.0);
+        assert_close(x.sqrt().eps, 0.5 / 3.0f64.sqrt());
+    }
+
+    #[test]
+    fn derivative_through_matrix_product() {
+        let x = Dual::variable(2.0);
+        let c = Dual::constant;
+        let a = Matrix::from(vec![
+            vec![x, c(1.0)],
+            vec![c(0.0), x * x]
+        ]);
+
+        let product = a.clone() * a;
+        // (0, 0) = x^2, (1, 1) = x^4, (0, 1) = x + x^2
+        assert_close(product.get(0, 0).eps, 4.0);
+        assert_close(product.get(1, 1).eps, 32.0);
+        assert_close(product.get(0, 1).eps, 5.0);
+    }
+
+    #[test]
+    fn derivative_of_determinant_and_solve() {
+        let x = Dual::variable(2.0);
+        let c = Dual::constant;
+        let a = Matrix::from(vec![
+            vec![c(3.0), x],
+            vec![c(2.0), c(4.0)]
+        ]);
+
+        // det = 12 - 2x
+        let determinant = a.determinant();
+        assert_close(determinant.re, 8.0);
+        assert_close(determinant.eps, -2.0);
+
+        // y = A^-1 b with b = [1, 0]: y = [4, -2] / det
+        let b = Matrix::from(vec![vec![c(1.0)], vec![c(0.0)]]);
+        let y = a.solve(&b).unwrap();
+        assert_close(y.get(0, 0).re, 0.5);
+        assert_close(y.get(1, 0).re, -0.25);
+        // d/dx 4 / (12 - 2x) = 8 / (12 - 2x)^2
+        assert_close(y.get(0, 0).eps, 8.0 / 64.0);
+        assert_close(y.get(1, 0).eps, -4.0 / 64.0);
+    }
+
+    #[test]
+    fn derivative_of_determinant_at_singular_points() {
+        let x = Dual::variable(0.0);
+        let c = Dual::constant;
+
+        let scalar = Matrix::from(vec![vec![x]]);
+        assert_eq!(scalar.determinant(), Dual::new(0.0, 1.0));
+
+        // det = 3x, singular from the first column.
+        let a = Matrix::from(vec![
+            vec![x, c(2.0)],
+            vec![c(0.0), c(3.0)]
+        ]);
+        assert_eq!(a.determinant(), Dual::new(0.0, 3.0));
+
+        // det = x, singular only once the first column is eliminated.
+        let b = Matrix::from(vec![
+            vec![c(1.0), c(2.0)],
+            vec![c(2.0), c(4.0) + x]
+        ]);
+        let determinant = b.determinant();
+        assert_close(determinant.re, 0.0);
+        assert_close(determinant.eps, 1.0);
+        assert_eq!(b.solve(&Matrix::new(2, 1, c(1.0))), None);
+
+        // det = x^2, whose derivative does vanish at zero.
+        let double = Matrix::from(vec![
+            vec![x, c(1.0)],
+            vec![c(0.0), x]
+        ]);
+        assert_eq!(double.determinant(), Dual::zero());
+    }
+
+    #[test]
+    fn nan_entries_do_not_panic() {
+        let c = Dual::constant;
+        let a = Matrix::from(vec![
+            vec![c(f64::NAN), c(1.0)],
+            vec![c(2.0), c(f64::NAN)]
+        ]);
+
+        assert!(a.determinant().re.is_nan());
+        assert_eq!(a.solve(&Matrix::new(2, 1, c(1.0))), None);
+    }
+}
@@ -0,0 +1,308 @@
+use std::fmt::Debug;
+
+use num::Float;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+use crate::progress::{Solution, SolverOptions};
+use crate::reflectors::Givens;
+use crate::sort::nan_last_cmp;
+
+/// The sweep limit of [`Matrix::eig_symmetric`]; Jacobi converges quadratically,
+/// so a well-scaled matrix rarely needs more than ten.
+const MAX_SWEEPS: usize = 100;
+
+/// Eigenvalues in increasing order, with the matching eigenvectors as the
+/// columns of `vectors`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetricEigen<T> {
+    pub values: Vec<T>,
+    pub vectors: Matrix<T>
+}
+
+/// Whether `matrix` is symmetric up to rounding relative to its largest entry.
+fn check_symmetric<T: Float>(matrix: &Matrix<T>) -> Result<(), MatrixError> {
+    if matrix.rows != matrix.cols {
+        return Err(MatrixError::NotSquare { rows: matrix.rows, cols: matrix.cols });
+    }
+    let scale = matrix.data.iter().fold(T::zero(), |max, val| max.max(val.abs()));
+    let tolerance = T::from(100.0).unwrap() * T::epsilon() * scale;
+    for i in 0..matrix.rows {
+        for j in 0..i {
+            if (*matrix.get(i, j) - *matrix.get(j, i)).abs() > tolerance {
+                return Err(MatrixError::NotSymmetric);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Options for the Jacobi methods that reproduce their standalone behaviour:
+/// `max_sweeps` sweeps, converging once the residual reaches working precision.
+pub(crate) fn jacobi_options<'a, T: Float>(max_sweeps: usize) -> SolverOptions<'a> {
+    SolverOptions::default()
+        .max_iterations(max_sweeps)
+        .tolerance(T::epsilon().to_f64().unwrap())
+}
+
+/// The cyclic Jacobi eigenvalue algorithm for a symmetric matrix.
+///
+/// Each rotation zeroes one off-diagonal pair; sweeping over all pairs
+/// repeatedly drives the matrix to diagonal form, with the accumulated
+/// rotations as the eigenvectors. Slower than tridiagonal QR but simple and
+/// accurate to working precision.
+///
+/// One sweep is one iteration, and the residual is the Frobenius norm of the
+/// off-diagonal part relative to that of the whole matrix.
+pub(crate) fn jacobi_eigen<T: Float>(matrix: &Matrix<T>, options: &mut SolverOptions) -> Result<Solution<SymmetricEigen<T>>, MatrixError> {
+    let n = matrix.rows;
+    let mut a = matrix.clone();
+    let mut vectors = Matrix::identity(n);
+
+    let scale = a.data.iter().fold(T::zero(), |total, &val| total + val * val);
+    let relative_off_diagonal = |a: &Matrix<T>| {
+        let off_diagonal = (0..n)
+            .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+            .fold(T::zero(), |total, (i, j)| total + *a.get(i, j) * *a.get(i, j));
+        if scale.is_zero() { 0.0 } else { (off_diagonal / scale).sqrt().to_f64().unwrap() }
+    };
+
+    let mut residual = relative_off_diagonal(&a);
+    let mut sweeps = 0;
+    while residual > options.tolerance && sweeps < options.max_iterations {
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = *a.get(p, q);
+                if apq.is_zero() {
+                    continue;
+                }
+                let theta = (*a.get(q, q) - *a.get(p, p)) / (apq + apq);
+                let t = if theta >= T::zero() { T::one() } else { -T::one() } / (theta.abs() + (theta * theta + T::one()).sqrt());
+                let c = T::one() / (t * t + T::one()).sqrt();
+                // The rotation J with columns (c, -s) and (s, c) in the (p, q) plane: a = Jᵀ a J.
+                let rotation = Givens { c, s: -(t * c), r: T::zero() };
+                rotation.apply_left(&mut a, p, q);
+                rotation.apply_right(&mut a, p, q);
+                rotation.apply_right(&mut vectors, p, q);
+            }
+        }
+
+        residual = relative_off_diagonal(&a);
+        if options.report(sweeps, residual).is_break() {
+            return Err(MatrixError::Cancelled { iterations: sweeps + 1 });
+        }
+        sweeps += 1;
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| nan_last_cmp(a.get(i, i), a.get(j, j)));
+    let mut sorted = Matrix::new(n, n, T::zero());
+    for (new, &old) in order.iter().enumerate() {
+        for i in 0..n {
+            sorted.set(i, new, *vectors.get(i, old));
+        }
+    }
+
+    Ok(Solution {
+        value: SymmetricEigen {
+            values: order.iter().map(|&i| *a.get(i, i)).collect(),
+            vectors: sorted
+        },
+        converged: residual <= options.tolerance,
+        iterations: sweeps,
+        residual
+    })
+}
+
+impl<T: PartialEq> Matrix<T> {
+    /// Whether the matrix is square and equal to its transpose, comparing exactly.
+    pub fn is_symmetric(&self) -> bool {
+        self.rows == self.cols && (0..self.rows).all(|i| (0..i).all(|j| self.get(i, j) == self.get(j, i)))
+    }
+}
+
+impl<T: Float + Debug> Matrix<T> {
+    /// The eigenvalues and orthonormal eigenvectors of a real symmetric matrix,
+    /// by the cyclic Jacobi method.
+    ///
+    /// Asymmetry up to rounding (a hundred ulps of the largest entry) is
+    /// tolerated, so products like `aᵀ * a` are accepted. Eigenvalues are in
+    /// ascending order, with the matching eigenvectors as columns.
+    pub fn eig_symmetric(&self) -> Result<SymmetricEigen<T>, MatrixError> {
+        self.eig_symmetric_with(&mut jacobi_options::<T>(MAX_SWEEPS))?.into_result()
+    }
+
+    /// [`Matrix::eig_symmetric`] with a custom tolerance, sweep limit and
+    /// progress reporting.
+    ///
+    /// Each Jacobi sweep is one iteration, and the residual is the Frobenius
+    /// norm of the remaining off-diagonal part relative to the whole matrix,
+    /// which bounds the relative error of the eigenvalues. Running out of
+    /// sweeps is not an error: the returned [`Solution`] says whether the
+    /// tolerance was met and carries the best effort either way.
+    pub fn eig_symmetric_with(&self, options: &mut SolverOptions) -> Result<Solution<SymmetricEigen<T>>, MatrixError> {
+        check_symmetric(self)?;
+        jacobi_eigen(self, options)
+    }
+
+    /// Solves the symmetric-definite generalized eigenproblem `a * x = λ * b * x`.
+    ///
+    /// `a` must be symmetric and `b` symmetric positive definite. With the
+    /// Cholesky factorization `b = l * lᵀ` the problem becomes the standard
+    /// symmetric one `c * y = λ * y` for `c = l⁻¹ * a * l⁻ᵀ` and `x = l⁻ᵀ * y`,
+    /// so the eigenvalues are real and the eigenvectors satisfy `xᵀ * b * x = I`.
+    pub fn generalized_eigen(a: &Matrix<T>, b: &Matrix<T>) -> Result<SymmetricEigen<T>, MatrixError> {
+        check_symmetric(a)?;
+        check_symmetric(b)?;
+        a.shape().elementwise_with(b.shape())?;
+
+        let l = b.cholesky()?.into_l();
+        let half = l.solve_lower_triangular(a)?;
+        let mut c = l.solve_lower_triangular(&half.transpose())?;
+        // Restore exact symmetry lost to rounding before the symmetric solver.
+        for i in 0..c.rows {
+            for j in 0..i {
+                let mean = (*c.get(i, j) + *c.get(j, i)) / T::from(2.0).unwrap();
+                c.set(i, j, mean);
+                c.set(j, i, mean);
+            }
+        }
+
+        let standard = jacobi_eigen(&c, &mut jacobi_options::<T>(MAX_SWEEPS))?.into_result()?;
+        Ok(SymmetricEigen {
+            values: standard.values,
+            vectors: l.transpose().solve_upper_triangular(&standard.vectors)?
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jacobi_eigen_of_symmetric_matrix() {
+        let matrix = Matrix::from(vec![
+            vec![2.0, 1.0, 0.0],
+            vec![1.0, 2.0, 1.0],
+            vec![0.0, 1.0, 2.0]
+        ]);
+
+        let eigen = jacobi_eigen(&matrix, &mut jacobi_options::<f64>(MAX_SWEEPS)).unwrap().value;
+
+        let root2 = 2.0f64.sqrt();
+        for (value, expected) in eigen.values.iter().zip([2.0 - root2, 2.0, 2.0 + root2].iter()) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        let residual = &(&matrix * &eigen.vectors) - &(&eigen.vectors * &Matrix::from(vec![
+            vec![eigen.values[0], 0.0, 0.0],
+            vec![0.0, eigen.values[1], 0.0],
+            vec![0.0, 0.0, eigen.values[2]]
+        ]));
+        assert!(residual.iter().all(|val| val.abs() < 1e-12));
+    }
+
+    #[test]
+    fn eig_symmetric_for_pca() {
+        // Points spread along the direction (1, 1) with a little noise across it.
+        let data = Matrix::from(vec![
+            vec![-2.0, -2.1],
+            vec![-1.0, -0.9],
+            vec![0.0, 0.1],
+            vec![1.0, 0.9],
+            vec![2.0, 2.0]
+        ]);
+        let covariance = &data.transpose() * &data;
+
+        let eigen = covariance.eig_symmetric().unwrap();
+
+        assert!(eigen.values[0] < eigen.values[1]);
+        let (x, y) = (eigen.vectors.get(0, 1), eigen.vectors.get(1, 1));
+        assert!((x.abs() - y.abs()).abs() < 0.05 && x * y > 0.0);
+        let gram = eigen.vectors.transpose() * &eigen.vectors;
+        assert!((gram.get(0, 0) - 1.0).abs() < 1e-12 && gram.get(0, 1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn eig_symmetric_reports_convergence() {
+        let matrix = Matrix::from((0..6).map(|i| (0..6).map(|j| 1.0 / (i + j + 1) as f64).collect()).collect());
+
+        let mut one_sweep = SolverOptions::default().max_iterations(1).tolerance(1e-14);
+        let partial = matrix.eig_symmetric_with(&mut one_sweep).unwrap();
+        assert!(!partial.converged && partial.iterations == 1 && partial.residual > 1e-14);
+        assert_eq!(partial.clone().into_result(), Err(MatrixError::NoConvergence { iterations: 1 }));
+
+        let mut residuals = vec![];
+        let full = {
+            let mut options = SolverOptions::default().tolerance(1e-14).progress(|iteration| {
+                residuals.push(iteration.residual);
+                std::ops::ControlFlow::Continue(())
+            });
+            matrix.eig_symmetric_with(&mut options).unwrap()
+        };
+        assert!(full.converged && full.residual <= 1e-14);
+        assert_eq!(residuals.len(), full.iterations);
+        assert!(residuals.windows(2).all(|pair| pair[1] < pair[0]));
+        assert_eq!(residuals[0], partial.residual);
+    }
+
+    #[test]
+    fn symmetry_checks() {
+        let symmetric = Matrix::from(vec![vec![1, 2], vec![2, 3]]);
+        assert!(symmetric.is_symmetric());
+        assert!(!Matrix::from(vec![vec![1, 2], vec![0, 3]]).is_symmetric());
+        assert!(!Matrix::new(2, 3, 0).is_symmetric());
+
+        let asymmetric = Matrix::from(vec![vec![1.0, 2.0], vec![0.0, 3.0]]);
+        assert_eq!(asymmetric.eig_symmetric(), Err(MatrixError::NotSymmetric));
+        assert_eq!(Matrix::new(2, 3, 0.0).eig_symmetric(), Err(MatrixError::NotSquare { rows: 2, cols: 3 }));
+    }
+
+    #[test]
+    fn generalized_eigen_of_spring_mass_system() {
+        // Stiffness and mass matrices of two masses joined by springs.
+        let stiffness = Matrix::from(vec![
+            vec![6.0, -2.0],
+            vec![-2.0, 4.0]
+        ]);
+        let mass = Matrix::from(vec![
+            vec![2.0, 0.0],
+            vec![0.0, 1.0]
+        ]);
+
+        let eigen = Matrix::generalized_eigen(&stiffness, &mass).unwrap();
+
+        // det(K - λM) = 2λ² - 14λ + 20 = 0.
+        assert!((eigen.values[0] - 2.0).abs() < 1e-12);
+        assert!((eigen.values[1] - 5.0).abs() < 1e-12);
+        for (k, value) in eigen.values.iter().enumerate() {
+            let x = Matrix::from(vec![vec![*eigen.vectors.get(0, k)], vec![*eigen.vectors.get(1, k)]]);
+            let residual = &(&stiffness * &x) - &(&(&mass * &x) * *value);
+            assert!(residual.iter().all(|val| val.abs() < 1e-12));
+        }
+        let normalised = eigen.vectors.transpose() * &mass * &eigen.vectors;
+        assert!((normalised.get(0, 0) - 1.0).abs() < 1e-12 && normalised.get(0, 1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn generalized_eigen_errors() {
+        let symmetric = Matrix::from(vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+        let asymmetric = Matrix::from(vec![vec![1.0, 2.0], vec![0.0, 1.0]]);
+        let indefinite = Matrix::from(vec![vec![1.0, 0.0], vec![0.0, -1.0]]);
+
+        assert_eq!(Matrix::generalized_eigen(&asymmetric, &symmetric), Err(MatrixError::NotSymmetric));
+        assert_eq!(Matrix::generalized_eigen(&symmetric, &indefinite), Err(MatrixError::NotPositiveDefinite));
+    }
+
+    #[test]
+    fn eig_symmetric_with_nan_does_not_panic() {
+        let matrix = Matrix::from(vec![
+            vec![2.0, 0.0],
+            vec![0.0, f64::NAN]
+        ]);
+
+        let solution = matrix.eig_symmetric_with(&mut SolverOptions::default()).unwrap();
+        assert!(!solution.converged);
+        assert!(solution.value.values[1].is_nan());
+    }
+}
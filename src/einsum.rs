@@ -0,0 +1,182 @@
+use std::collections::BTreeMap;
+
+use crate::Matrix;
+use crate::element::MatrixElement;
+use crate::error::MatrixError;
+
+fn spec_error(reason: &str) -> MatrixError {
+    MatrixError::Parse { line: 1, reason: reason.to_string() }
+}
+
+/// Splits an einsum specification into the index pairs of each operand and the output indices.
+fn parse(spec: &str, operands: usize) -> Result<(Vec<[char; 2]>, Vec<char>), MatrixError> {
+    let spec: String = spec.chars().filter(|c| !c.is_whitespace()).collect();
+    let (inputs, output) = match spec.split_once("->") {
+        Some((inputs, output)) => (inputs, Some(output)),
+        None => (spec.as_str(), None)
+    };
+
+    let mut terms = vec![];
+    for term in inputs.split(',') {
+        let letters: Vec<char> = term.chars().collect();
+        if letters.len() != 2 || !letters.iter().all(char::is_ascii_lowercase) {
+            return Err(spec_error("each operand needs exactly two lowercase indices"));
+        }
+        terms.push([letters[0], letters[1]]);
+    }
+    if terms.len() != operands {
+        return Err(spec_error("the number of terms does not match the number of operands"));
+    }
+
+    let output: Vec<char> = match output {
+        Some(output) => output.chars().collect(),
+        None => {
+            // Implicit output: the indices used exactly once, in alphabetical order.
+            let mut counts = BTreeMap::new();
+            for letter in terms.iter().flatten() {
+                *counts.entry(*letter).or_insert(0) += 1;
+            }
+            counts.into_iter().filter(|&(_, count)| count == 1).map(|(letter, _)| letter).collect()
+        }
+    };
+    if output.len() > 2 {
+        return Err(spec_error("the output can have at most two indices"));
+    }
+    for (position, letter) in output.iter().enumerate() {
+        if !terms.iter().flatten().any(|l| l == letter) || output[..position].contains(letter) {
+            return Err(spec_error("output indices must be distinct and appear in an operand"));
+        }
+    }
+    Ok((terms, output))
+}
+
+impl<T: MatrixElement> Matrix<T> {
+    /// Evaluates an Einstein summation over matrices, as NumPy's `einsum`.
+    ///
+    /// Each comma-separated term names the two indices of one operand, and the
+    /// indices after `->` give the output; every other index is summed over. An
+    /// index repeated within a term takes the diagonal. Without `->`, the output
+    /// is the indices that appear exactly once, in alphabetical order.
+    ///
+    /// A two-index output is a matrix, a one-index output is a column vector and
+    /// an empty output is a `1 x 1` matrix, so `"ij,jk->ik"` multiplies,
+    /// `"ij->ji"` transposes, `"ii->"` takes the trace, `"ii->i"` extracts the
+    /// diagonal and `"ij,ij->"` is the Frobenius inner product.
+    pub fn einsum(spec: &str, operands: &[&Matrix<T>]) -> Result<Matrix<T>, MatrixError> {
+        let (terms, output) = parse(spec, operands.len())?;
+
+        let mut sizes: BTreeMap<char, (usize, usize)> = BTreeMap::new();
+        for (operand_index, (term, operand)) in terms.iter().zip(operands.iter()).enumerate() {
+            for (letter, size) in term.iter().zip([operand.rows, operand.cols].iter()) {
+                let (expected, first) = *sizes.entry(*letter).or_insert((*size, operand_index));
+                if expected != *size {
+                    let other = operands[first];
+                    return Err(MatrixError::DimensionMismatch {
+                        left: other.shape(),
+                        right: operand.shape()
+                    });
+                }
+            }
+        }
+
+        // Output indices vary slowest so results come out in row-major order.
+        let mut letters = output.clone();
+        letters.extend(sizes.keys().filter(|letter| !output.contains(letter)));
+        let extents: Vec<usize> = letters.iter().map(|letter| sizes[letter].0).collect();
+        let position = |letter: char| letters.iter().position(|&l| l == letter).unwrap();
+        let lookups: Vec<[usize; 2]> = terms.iter().map(|term| [position(term[0]), position(term[1])]).collect();
+
+        let output_len: usize = extents[..output.len()].iter().product();
+        let summed_len: usize = extents[output.len()..].iter().product();
+        let mut data = Vec::with_capacity(output_len);
+        let mut index = vec![0; letters.len()];
+        for _ in 0..output_len {
+            let mut total = T::zero();
+            for _ in 0..summed_len {
+                let product = operands.iter()
+                    .zip(lookups.iter())
+                    .fold(T::one(), |product, (operand, lookup)| {
+                        product * operand.get(index[lookup[0]], index[lookup[1]]).clone()
+                    });
+                total = total + product;
+                increment(&mut index[output.len()..], &extents[output.len()..]);
+            }
+            data.push(total);
+            increment(&mut index[..output.len()], &extents[..output.len()]);
+        }
+
+        let (rows, cols) = match output.len() {
+            0 => (1, 1),
+            1 => (extents[0], 1),
+            _ => (extents[0], extents[1])
+        };
+        Ok(Matrix {
+            rows,
+            cols,
+            data
+        })
+    }
+}
+
+/// Advances a multi-index odometer-style, the last position fastest.
+fn increment(index: &mut [usize], extents: &[usize]) {
+    for k in (0..index.len()).rev() {
+        index[k] += 1;
+        if index[k] < extents[k] {
+            return;
+        }
+        index[k] = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Shape;
+
+    fn example() -> Matrix<i32> {
+        Matrix::from(vec![
+            vec![1, 2],
+            vec![3, 4]
+        ])
+    }
+
+    #[test]
+    fn einsum_products_and_transposes() {
+        let b = Matrix::from(vec![
+            vec![0, 1, 2],
+            vec![1, 0, 3]
+        ]);
+
+        assert_eq!(Matrix::einsum("ij,jk->ik", &[&example(), &b]).unwrap(), example() * b.clone());
+        assert_eq!(Matrix::einsum("ij,jk", &[&example(), &b]).unwrap(), example() * b.clone());
+
+        let transposed = Matrix::einsum("ij->ji", &[&b]).unwrap();
+        assert_eq!((transposed.rows, transposed.cols), (3, 2));
+        assert_eq!(transposed.data, vec![0, 1, 1, 0, 2, 3]);
+    }
+
+    #[test]
+    fn einsum_reductions() {
+        assert_eq!(Matrix::einsum("ii->", &[&example()]).unwrap().data, vec![5]);
+        assert_eq!(Matrix::einsum("ii->i", &[&example()]).unwrap().data, vec![1, 4]);
+        assert_eq!(Matrix::einsum("ij->i", &[&example()]).unwrap().data, vec![3, 7]);
+        assert_eq!(Matrix::einsum("ij,ij->", &[&example(), &example()]).unwrap().data, vec![30]);
+        // trace(A * B) without forming the product.
+        assert_eq!(Matrix::einsum("ij,ji", &[&example(), &example()]).unwrap().data, vec![29]);
+    }
+
+    #[test]
+    fn einsum_errors() {
+        let a = example();
+        assert!(matches!(Matrix::einsum("ijk->i", &[&a]), Err(MatrixError::Parse { .. })));
+        assert!(matches!(Matrix::einsum("ij,jk->ik", &[&a]), Err(MatrixError::Parse { .. })));
+        assert!(matches!(Matrix::einsum("ij->iz", &[&a]), Err(MatrixError::Parse { .. })));
+
+        let wide = Matrix::new(3, 3, 1);
+        assert_eq!(
+            Matrix::einsum("ij,jk->ik", &[&a, &wide]),
+            Err(MatrixError::DimensionMismatch { left: Shape::new(2, 2), right: Shape::new(3, 3) })
+        );
+    }
+}
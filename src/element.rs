@@ -0,0 +1,119 @@
+use std::fmt::Debug;
+
+use num::Num;
+
+/// The bounds the crate's generic arithmetic needs from an element type.
+///
+/// This is implemented automatically for every `Clone + Debug` type that
+/// implements [`num::Num`] (zero, one, `+ - * / %`), so a custom scalar such as a
+/// dual number or a units wrapper works with every generic operation once it
+/// implements `Num`. Elements are cloned rather than copied, so types with heap
+/// storage (e.g. big integers) are supported too.
+///
+/// Quantities from a units-of-measure crate such as `uom` can't implement
+/// `Num`, since the product of two lengths is an area rather than a length.
+/// Construction, addition, subtraction, negation and [`Matrix::scale`] only
+/// ask for `Clone` and the matching `std::ops` trait, so such matrices keep
+/// their dimensional checking for those operations.
+///
+/// [`Matrix::scale`]: crate::Matrix::scale
+pub trait MatrixElement: Num + Clone + Debug {}
+
+impl<T: Num + Clone + Debug> MatrixElement for T {}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::{Add, Mul, Neg, Sub};
+
+    use num::BigInt;
+
+    use crate::Matrix;
+
+    #[test]
+    fn non_copy_elements() {
+        let big = |val: i64| BigInt::from(val) * BigInt::from(u64::MAX);
+        let matrix = Matrix::from(vec![
+            vec![big(1), big(2)],
+            vec![big(3), big(4)]
+        ]);
+        let identity = Matrix::from(vec![
+            vec![BigInt::from(1), BigInt::from(0)],
+            vec![BigInt::from(0), BigInt::from(1)]
+        ]);
+
+        assert_eq!(matrix.clone() * identity, matrix);
+        assert_eq!(matrix.clone() * BigInt::from(2), Matrix::from(vec![
+            vec![big(2), big(4)],
+            vec![big(6), big(8)]
+        ]));
+    }
+
+    /// A length in metres, standing in for a dimensioned quantity.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Metres(f64);
+
+    /// An area in square metres.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct SquareMetres(f64);
+
+    impl Add for Metres {
+        type Output = Metres;
+
+        fn add(self, rhs: Metres) -> Metres {
+            Metres(self.0 + rhs.0)
+        }
+    }
+
+    impl Sub for Metres {
+        type Output = Metres;
+
+        fn sub(self, rhs: Metres) -> Metres {
+            Metres(self.0 - rhs.0)
+        }
+    }
+
+    impl Neg for Metres {
+        type Output = Metres;
+
+        fn neg(self) -> Metres {
+            Metres(-self.0)
+        }
+    }
+
+    impl Mul<f64> for Metres {
+        type Output = Metres;
+
+        fn mul(self, rhs: f64) -> Metres {
+            Metres(self.0 * rhs)
+        }
+    }
+
+    impl Mul<Metres> for Metres {
+        type Output = SquareMetres;
+
+        fn mul(self, rhs: Metres) -> SquareMetres {
+            SquareMetres(self.0 * rhs.0)
+        }
+    }
+
+    #[test]
+    fn dimensioned_elements() {
+        let a = Matrix::from(vec![
+            vec![Metres(1.0), Metres(2.0)],
+            vec![Metres(3.0), Metres(4.0)]
+        ]);
+        let b = Matrix::new(2, 2, Metres(0.5));
+
+        assert_eq!(&a + &b, Matrix::from(vec![
+            vec![Metres(1.5), Metres(2.5)],
+            vec![Metres(3.5), Metres(4.5)]
+        ]));
+        assert_eq!(a.checked_sub(&b).unwrap().get(1, 1), &Metres(3.5));
+        assert_eq!(-&a + a.clone(), Matrix::new(2, 2, Metres(0.0)));
+        assert!(a.checked_add(&Matrix::new(1, 2, Metres(0.0))).is_err());
+
+        assert_eq!(a.scale(2.0), &a + &a);
+        let areas: Matrix<SquareMetres> = a.scale(Metres(2.0));
+        assert_eq!(areas.get(0, 1), &SquareMetres(4.0));
+    }
+}
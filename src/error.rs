@@ -0,0 +1,96 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+
+use crate::shape::Shape;
+
+/// Errors reported by the fallible matrix operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatrixError {
+    /// A builder was asked to `build` before a required setting was provided.
+    IncompleteBuilder { missing: &'static str },
+    /// An index fell outside a `rows x cols` matrix.
+    IndexOutOfBounds { row: usize, col: usize, rows: usize, cols: usize },
+    /// Two operands have shapes the operation cannot combine.
+    DimensionMismatch { left: Shape, right: Shape },
+    /// Two batches of matrices, such as [`Tensor3`](crate::Tensor3)s, have
+    /// different numbers of matrices.
+    DepthMismatch { left: usize, right: usize },
+    /// An operation that requires a square matrix was given a `rows x cols` one.
+    NotSquare { rows: usize, cols: usize },
+    /// A system could not be solved because its matrix is singular.
+    Singular,
+    /// An operation that requires a symmetric matrix was given one that is not.
+    NotSymmetric,
+    /// A Cholesky factorization met a non-positive pivot.
+    NotPositiveDefinite,
+    /// A matrix was requested from an empty list of rows, which has no column count.
+    EmptyInput,
+    /// Rows expected to share a length did not.
+    RaggedRows { row: usize, expected: usize, found: usize },
+    /// Columns expected to share a length did not.
+    RaggedColumns { column: usize, expected: usize, found: usize },
+    /// An iterative algorithm did not converge within its iteration limit.
+    NoConvergence { iterations: usize },
+    /// An iterative algorithm was stopped by its progress callback.
+    Cancelled { iterations: usize },
+    /// Text could not be read as a matrix; `line` is one-based.
+    Parse { line: usize, reason: String },
+    /// Text could not be read as an element; `line` and `column` (the field
+    /// number within the line) are one-based.
+    InvalidElement { line: usize, column: usize, text: String },
+    /// A probability matrix had a negative (or NaN) entry.
+    NegativeEntry { row: usize, col: usize },
+    /// A row of a probability matrix did not sum to one.
+    UnnormalizedRow { row: usize },
+    /// A parameter, such as a kernel's smoothness, was outside its allowed values.
+    InvalidParameter { name: &'static str, reason: &'static str }
+}
+
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            MatrixError::IncompleteBuilder { missing } => {
+                write!(f, "matrix builder is missing a value for `{}`", missing)
+            }
+            MatrixError::IndexOutOfBounds { row, col, rows, cols } => {
+                write!(f, "index ({}, {}) is out of bounds for a {}x{} matrix", row, col, rows, cols)
+            }
+            MatrixError::DimensionMismatch { left, right } => {
+                write!(f, "incompatible dimensions: {} and {}", left, right)
+            }
+            MatrixError::DepthMismatch { left, right } => {
+                write!(f, "incompatible batch depths: {} and {}", left, right)
+            }
+            MatrixError::NotSquare { rows, cols } => {
+                write!(f, "expected a square matrix but found {}x{}", rows, cols)
+            }
+            MatrixError::Singular => write!(f, "matrix is singular"),
+            MatrixError::NotSymmetric => write!(f, "matrix is not symmetric"),
+            MatrixError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+            MatrixError::EmptyInput => write!(f, "cannot build a matrix from an empty list of rows"),
+            MatrixError::RaggedRows { row, expected, found } => {
+                write!(f, "row {} has length {} but {} was expected", row, found, expected)
+            }
+            MatrixError::RaggedColumns { column, expected, found } => {
+                write!(f, "column {} has length {} but {} was expected", column, found, expected)
+            }
+            MatrixError::NoConvergence { iterations } => {
+                write!(f, "no convergence after {} iterations", iterations)
+            }
+            MatrixError::Cancelled { iterations } => {
+                write!(f, "computation cancelled after {} iterations", iterations)
+            }
+            MatrixError::Parse { line, reason } => write!(f, "parse error on line {}: {}", line, reason),
+            MatrixError::InvalidElement { line, column, text } => {
+                write!(f, "invalid element `{}` on line {}, column {}", text, line, column)
+            }
+            MatrixError::NegativeEntry { row, col } => {
+                write!(f, "entry ({}, {}) is not a valid probability", row, col)
+            }
+            MatrixError::UnnormalizedRow { row } => write!(f, "row {} does not sum to one", row),
+            MatrixError::InvalidParameter { name, reason } => write!(f, "invalid `{}`: {}", name, reason)
+        }
+    }
+}
+
+impl Error for MatrixError {}
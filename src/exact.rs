@@ -0,0 +1,87 @@
+use crate::Matrix;
+use crate::element::MatrixElement;
+
+impl<T: MatrixElement> Matrix<T> {
+    /// Gauss-Jordan elimination for exact arithmetic, where any nonzero pivot is acceptable.
+    ///
+    /// Returns the reduced row echelon form, its pivot columns, and the determinant
+    /// of the leading square block (meaningful only when every column of that block has a pivot).
+    pub(crate) fn exact_rref(&self) -> (Matrix<T>, Vec<usize>, T) {
+        let mut matrix = self.clone();
+        let mut pivots = vec![];
+        let mut determinant = T::one();
+
+        let mut row = 0;
+        for col in 0..matrix.cols {
+            if row == matrix.rows {
+                break;
+            }
+            let pivot_row = match (row..matrix.rows).find(|&i| !matrix.get(i, col).is_zero()) {
+                Some(pivot_row) => pivot_row,
+                None => continue
+            };
+            if pivot_row != row {
+                matrix.swap_rows(row, pivot_row);
+                determinant = T::zero() - determinant;
+            }
+
+            let pivot = matrix.get(row, col).clone();
+            determinant = determinant * pivot.clone();
+            for j in 0..matrix.cols {
+                let val = matrix.get(row, j).clone();
+                matrix.set(row, j, val / pivot.clone());
+            }
+
+            for i in (0..matrix.rows).filter(|&i| i != row) {
+                let factor = matrix.get(i, col).clone();
+                if !factor.is_zero() {
+                    matrix.add_scaled_row(row, i, T::zero() - factor);
+                }
+            }
+
+            pivots.push(col);
+            row += 1;
+        }
+
+        (matrix, pivots, determinant)
+    }
+
+    /// Places `rhs` to the right of `self` and reduces the result, returning the
+    /// right-hand block if the (square) left-hand block is invertible.
+    pub(crate) fn exact_solve(&self, rhs: &Matrix<T>) -> Option<Matrix<T>> {
+        assert_eq!(self.rows, self.cols);
+        assert_eq!(self.rows, rhs.rows);
+        // An empty system is trivially invertible, with an empty solution.
+        if self.rows == 0 {
+            return Some(rhs.clone());
+        }
+
+        let mut data = vec![];
+        for i in 0..self.rows {
+            data.extend(self.get_row(i));
+            data.extend(rhs.get_row(i));
+        }
+        let augmented = Matrix {
+            rows: self.rows,
+            cols: self.cols + rhs.cols,
+            data
+        };
+
+        let (reduced, pivots, _) = augmented.exact_rref();
+        if pivots.len() < self.rows || pivots[self.rows - 1] >= self.cols {
+            return None;
+        }
+
+        let mut data = vec![];
+        for i in 0..self.rows {
+            for j in self.cols..reduced.cols {
+                data.push(reduced.get(i, j).clone());
+            }
+        }
+        Some(Matrix {
+            rows: rhs.rows,
+            cols: rhs.cols,
+            data
+        })
+    }
+}
@@ -0,0 +1,116 @@
+use std::ops::{Index, IndexMut};
+
+use crate::Matrix;
+use crate::error::MatrixError;
+
+impl<T> Matrix<T> {
+    fn offset(&self, row: usize, col: usize) -> Result<usize, MatrixError> {
+        if row >= self.rows || col >= self.cols {
+            return Err(MatrixError::IndexOutOfBounds { row, col, rows: self.rows, cols: self.cols });
+        }
+        Ok(row * self.cols + col)
+    }
+
+    /// The element at `(row, col)`, checking the row and column separately.
+    pub fn try_get(&self, row: usize, col: usize) -> Result<&T, MatrixError> {
+        let offset = self.offset(row, col)?;
+        Ok(&self.data[offset])
+    }
+
+    pub fn try_get_mut(&mut self, row: usize, col: usize) -> Result<&mut T, MatrixError> {
+        let offset = self.offset(row, col)?;
+        Ok(&mut self.data[offset])
+    }
+
+    /// The element at `(row, col)` without any bounds check.
+    ///
+    /// # Safety
+    ///
+    /// `row` must be less than `rows` and `col` less than `cols`.
+    pub unsafe fn get_unchecked(&self, row: usize, col: usize) -> &T {
+        self.data.get_unchecked(row * self.cols + col)
+    }
+
+    /// The mutable counterpart of [`Matrix::get_unchecked`].
+    ///
+    /// # Safety
+    ///
+    /// `row` must be less than `rows` and `col` less than `cols`.
+    pub unsafe fn get_unchecked_mut(&mut self, row: usize, col: usize) -> &mut T {
+        let cols = self.cols;
+        self.data.get_unchecked_mut(row * cols + col)
+    }
+}
+
+/// `matrix[(row, col)]`, panicking if either index is out of bounds.
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.try_get(row, col).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        self.try_get_mut(row, col).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+/// `matrix[row]`, the row as a slice.
+impl<T> Index<usize> for Matrix<T> {
+    type Output = [T];
+
+    fn index(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "row {} is out of bounds for a matrix with {} rows", row, self.rows);
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+}
+
+impl<T> IndexMut<usize> for Matrix<T> {
+    fn index_mut(&mut self, row: usize) -> &mut [T] {
+        assert!(row < self.rows, "row {} is out of bounds for a matrix with {} rows", row, self.rows);
+        &mut self.data[row * self.cols..(row + 1) * self.cols]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_elements_and_rows() {
+        let mut matrix = Matrix::from(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6]
+        ]);
+
+        assert_eq!(matrix[(1, 2)], 6);
+        matrix[(1, 2)] = 5;
+        assert_eq!(matrix[(1, 2)], 5);
+
+        assert_eq!(matrix[0], [1, 2, 3]);
+        matrix[0][1] = 7;
+        assert_eq!(*matrix.get(0, 1), 7);
+        assert_eq!(unsafe { *matrix.get_unchecked(1, 0) }, 4);
+        unsafe { *matrix.get_unchecked_mut(1, 0) = 0 };
+        assert_eq!(matrix[(1, 0)], 0);
+    }
+
+    #[test]
+    fn checked_element_access() {
+        let mut matrix = Matrix::new(2, 3, 0);
+
+        // (0, 3) would be inside the buffer but is past the end of row 0.
+        assert_eq!(matrix.try_get(0, 3), Err(MatrixError::IndexOutOfBounds { row: 0, col: 3, rows: 2, cols: 3 }));
+        *matrix.try_get_mut(1, 1).unwrap() = 4;
+        assert_eq!(matrix.try_get(1, 1), Ok(&4));
+    }
+
+    #[test]
+    #[should_panic(expected = "index (0, 3) is out of bounds for a 2x3 matrix")]
+    fn index_past_row_end_panics() {
+        let matrix = Matrix::new(2, 3, 0);
+        let _ = matrix[(0, 3)];
+    }
+}
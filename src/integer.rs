@@ -0,0 +1,182 @@
+use num::{BigInt, Integer};
+use num::rational::Ratio;
+
+use crate::Matrix;
+use crate::element::MatrixElement;
+
+impl<T: MatrixElement + Integer> Matrix<T> {
+    /// Fraction-free Gaussian elimination (Bareiss), returning the exact determinant.
+    ///
+    /// Every division performed is exact, and intermediate entries are minors of
+    /// the input, so their size stays bounded by Hadamard's inequality rather than
+    /// growing exponentially as in naive integer elimination.
+    pub fn bareiss_determinant(&self) -> T {
+        assert_eq!(self.rows, self.cols);
+
+        let n = self.rows;
+        let mut matrix = self.clone();
+        let mut previous = T::one();
+        let mut negate = false;
+
+        for k in 0..n {
+            if matrix.get(k, k).is_zero() {
+                match (k + 1..n).find(|&i| !matrix.get(i, k).is_zero()) {
+                    Some(pivot_row) => {
+                        for j in 0..n {
+                            matrix.data.swap(k * n + j, pivot_row * n + j);
+                        }
+                        negate = !negate;
+                    }
+                    None => return T::zero()
+                }
+            }
+
+            let pivot = matrix.get(k, k).clone();
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    let val = (matrix.get(i, j).clone() * pivot.clone()
+                        - matrix.get(i, k).clone() * matrix.get(k, j).clone())
+                        / previous.clone();
+                    matrix.set(i, j, val);
+                }
+            }
+            previous = pivot;
+        }
+
+        if negate {
+            T::zero() - previous
+        } else {
+            previous
+        }
+    }
+
+    /// The exact inverse over the rationals, or `None` if the matrix is singular.
+    pub fn rational_inverse(&self) -> Option<Matrix<Ratio<T>>> {
+        assert_eq!(self.rows, self.cols);
+
+        let rational = Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().cloned().map(Ratio::from_integer).collect()
+        };
+        rational.exact_solve(&Matrix::identity(self.rows))
+    }
+}
+
+impl Matrix<i64> {
+    /// The exact determinant via Bareiss elimination.
+    ///
+    /// Intermediate values are minors of the matrix and must fit in an `i64`;
+    /// use `Matrix<BigInt>` when they might not.
+    pub fn determinant(&self) -> i64 {
+        self.bareiss_determinant()
+    }
+
+    /// The exact inverse with rational entries, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Matrix<Ratio<i64>>> {
+        self.rational_inverse()
+    }
+}
+
+impl Matrix<BigInt> {
+    /// The exact determinant via Bareiss elimination.
+    pub fn determinant(&self) -> BigInt {
+        self.bareiss_determinant()
+    }
+
+    /// The exact inverse with rational entries, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Matrix<Ratio<BigInt>>> {
+        self.rational_inverse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modular::ModInt;
+
+    #[test]
+    fn integer_determinant() {
+        let matrix: Matrix<i64> = Matrix::from(vec![
+            vec![2, -3, 1],
+            vec![2, 0, -1],
+            vec![1, 4, 5]
+        ]);
+        assert_eq!(matrix.determinant(), 49);
+
+        let needs_pivot: Matrix<i64> = Matrix::from(vec![
+            vec![0, 1],
+            vec![1, 0]
+        ]);
+        assert_eq!(needs_pivot.determinant(), -1);
+
+        let singular: Matrix<i64> = Matrix::from(vec![
+            vec![1, 2],
+            vec![2, 4]
+        ]);
+        assert_eq!(singular.determinant(), 0);
+    }
+
+    #[test]
+    fn big_integer_determinant() {
+        // Entries up to 10^14 give a determinant far beyond the range of an i64 or f64 mantissa.
+        let n = 8;
+        let mut data = vec![];
+        for i in 0..n {
+            let mut row = vec![];
+            for j in 0..n {
+                row.push(BigInt::from(10_i64.pow(((i * j) % 15) as u32) + (i + j) as i64));
+            }
+            data.push(row);
+        }
+        let matrix = Matrix::from(data);
+
+        let rational = Matrix {
+            rows: n,
+            cols: n,
+            data: matrix.data.iter().cloned().map(Ratio::from_integer).collect()
+        };
+        let (_, _, expected) = rational.exact_rref();
+
+        let determinant = matrix.determinant();
+        assert_eq!(Ratio::from_integer(determinant.clone()), expected);
+        assert!(determinant.bits() > 64);
+    }
+
+    #[test]
+    fn integer_inverse_is_rational() {
+        let matrix: Matrix<i64> = Matrix::from(vec![
+            vec![2, 1],
+            vec![1, 1]
+        ]);
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(inverse.data, vec![
+            Ratio::from_integer(1),
+            Ratio::from_integer(-1),
+            Ratio::from_integer(-1),
+            Ratio::from_integer(2)
+        ]);
+
+        let matrix: Matrix<i64> = Matrix::from(vec![
+            vec![2, 0],
+            vec![0, 4]
+        ]);
+        assert_eq!(matrix.inverse().unwrap().data[3], Ratio::new(1, 4));
+
+        let singular: Matrix<i64> = Matrix::from(vec![
+            vec![1, 1],
+            vec![1, 1]
+        ]);
+        assert!(singular.inverse().is_none());
+    }
+
+    #[test]
+    fn empty_matrix_inverse() {
+        let empty = Matrix::<i64>::new(0, 0, 0);
+        assert_eq!(empty.rational_inverse().unwrap().shape(), (0, 0).into());
+
+        let empty_gf = Matrix::<ModInt<7>>::new(0, 0, ModInt::new(0));
+        assert_eq!(empty_gf.inverse().unwrap().shape(), (0, 0).into());
+        assert_eq!(empty_gf.solve(&Matrix::new(0, 2, ModInt::new(0))).unwrap().shape(), (0, 2).into());
+    }
+}
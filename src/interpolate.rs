@@ -0,0 +1,90 @@
+use num::Float;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+
+impl<T: Float> Matrix<T> {
+    /// Linear interpolation `(1 - t) * self + t * other`.
+    ///
+    /// `t = 0` gives `self` and `t = 1` gives `other` exactly; values outside
+    /// `[0, 1]` extrapolate.
+    pub fn lerp(&self, other: &Matrix<T>, t: T) -> Result<Matrix<T>, MatrixError> {
+        self.shape().elementwise_with(other.shape())?;
+
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter()
+                .zip(other.data.iter())
+                .map(|(&a, &b)| (T::one() - t) * a + t * b)
+                .collect()
+        })
+    }
+
+    /// Element-wise [`Matrix::lerp`], taking the interpolation parameter for each
+    /// element from the matching element of `weights`.
+    pub fn blend(&self, other: &Matrix<T>, weights: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.shape().elementwise_with(other.shape())?;
+        self.shape().elementwise_with(weights.shape())?;
+
+        let mut data = vec![];
+        for i in 0..self.data.len() {
+            let (a, b, t) = (self.data[i], other.data[i], weights.data[i]);
+            data.push((T::one() - t) * a + t * b);
+        }
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Shape;
+
+    #[test]
+    fn lerp_between_matrices() {
+        let start = Matrix::from(vec![
+            vec![0.0, 2.0],
+            vec![4.0, -1.0]
+        ]);
+        let end = Matrix::from(vec![
+            vec![1.0, 4.0],
+            vec![0.0, 1.0]
+        ]);
+
+        assert_eq!(start.lerp(&end, 0.0).unwrap(), start);
+        assert_eq!(start.lerp(&end, 1.0).unwrap(), end);
+
+        let expected_matrix = Matrix::from(vec![
+            vec![0.5, 3.0],
+            vec![2.0, 0.0]
+        ]);
+        assert_eq!(start.lerp(&end, 0.5).unwrap(), expected_matrix);
+    }
+
+    #[test]
+    fn blend_with_weights() {
+        let a = Matrix::new(2, 2, 0.0);
+        let b = Matrix::new(2, 2, 8.0);
+        let weights = Matrix::from(vec![
+            vec![0.0, 0.25],
+            vec![0.5, 1.0]
+        ]);
+
+        let expected_matrix = Matrix::from(vec![
+            vec![0.0, 2.0],
+            vec![4.0, 8.0]
+        ]);
+        assert_eq!(a.blend(&b, &weights).unwrap(), expected_matrix);
+
+        let wrong_shape = Matrix::new(1, 2, 0.5);
+        assert_eq!(a.blend(&b, &wrong_shape), Err(MatrixError::DimensionMismatch {
+            left: Shape::new(2, 2),
+            right: Shape::new(1, 2)
+        }));
+    }
+}
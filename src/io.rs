@@ -0,0 +1,355 @@
+use std::fmt::Display;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::Matrix;
+use crate::element::MatrixElement;
+use crate::error::MatrixError;
+
+/// Wraps a format error so it can be recovered with `io::Error::get_ref` and a
+/// downcast to [`MatrixError`].
+fn invalid_data(error: MatrixError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+fn parse_error(line: usize, reason: &str) -> io::Error {
+    invalid_data(MatrixError::Parse { line, reason: reason.to_string() })
+}
+
+fn parse_element<T: FromStr>(text: &str, line: usize, column: usize) -> io::Result<T> {
+    text.parse().map_err(|_| invalid_data(MatrixError::InvalidElement { line, column, text: text.to_string() }))
+}
+
+impl<T: FromStr> Matrix<T> {
+    /// Reads comma separated values, one row per line.
+    ///
+    /// Whitespace around each field and blank lines are ignored. Read failures
+    /// are returned as they are; malformed input gives an
+    /// [`io::ErrorKind::InvalidData`] error wrapping a [`MatrixError`], which is
+    /// [`MatrixError::InvalidElement`] with the line and field of an element
+    /// that `T` cannot parse, [`MatrixError::RaggedRows`] or
+    /// [`MatrixError::EmptyInput`].
+    pub fn from_csv<R: BufRead>(reader: R) -> io::Result<Matrix<T>> {
+        let mut data = vec![];
+        let mut rows = 0;
+        let mut cols = 0;
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let before = data.len();
+            for (field, text) in line.split(',').enumerate() {
+                data.push(parse_element(text.trim(), index + 1, field + 1)?);
+            }
+            let found = data.len() - before;
+            if rows == 0 {
+                cols = found;
+            } else if found != cols {
+                return Err(invalid_data(MatrixError::RaggedRows { row: rows, expected: cols, found }));
+            }
+            rows += 1;
+        }
+        if rows == 0 {
+            return Err(invalid_data(MatrixError::EmptyInput));
+        }
+
+        Ok(Matrix {
+            rows,
+            cols,
+            data
+        })
+    }
+}
+
+impl<T: Display> Matrix<T> {
+    /// Writes the matrix as comma separated values, one row per line, readable
+    /// by [`Matrix::from_csv`], spreadsheets, MATLAB's `readmatrix` and NumPy's
+    /// `loadtxt(..., delimiter=",")`.
+    pub fn to_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        for row in self.data.chunks(self.cols.max(1)).take(self.rows) {
+            let fields: Vec<String> = row.iter().map(|val| val.to_string()).collect();
+            writeln!(writer, "{}", fields.join(","))?;
+        }
+        writer.flush()
+    }
+
+    /// Writes the matrix in the Matrix Market exchange format, as a dense
+    /// `array real general` matrix, readable by [`Matrix::from_matrix_market`],
+    /// MATLAB's `mmread` and SciPy's `scipy.io.mmread`.
+    pub fn to_matrix_market<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "%%MatrixMarket matrix array real general")?;
+        writeln!(writer, "{} {}", self.rows, self.cols)?;
+        // Array entries are listed in column-major order.
+        for j in 0..self.cols {
+            for i in 0..self.rows {
+                writeln!(writer, "{}", self.data[i * self.cols + j])?;
+            }
+        }
+        writer.flush()
+    }
+}
+
+/// How a Matrix Market file stores the entries it leaves out.
+#[derive(Clone, Copy, PartialEq)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric
+}
+
+impl<T: MatrixElement + FromStr> Matrix<T> {
+    /// Reads a real or integer matrix in the Matrix Market exchange format,
+    /// written by MATLAB's `mmwrite` or SciPy's `scipy.io.mmwrite`.
+    ///
+    /// Both the dense `array` and sparse `coordinate` layouts are supported,
+    /// with `general`, `symmetric` or `skew-symmetric` storage; entries missing
+    /// from a coordinate file are zero. `complex` and `pattern` matrices are
+    /// rejected. Errors are reported as for [`Matrix::from_csv`], with a
+    /// malformed header or size line giving [`MatrixError::Parse`].
+    pub fn from_matrix_market<R: BufRead>(reader: R) -> io::Result<Matrix<T>> {
+        let mut lines = reader.lines().enumerate();
+
+        let header = match lines.next() {
+            Some((_, line)) => line?,
+            None => return Err(invalid_data(MatrixError::EmptyInput))
+        };
+        let banner: Vec<String> = header.split_whitespace().map(str::to_lowercase).collect();
+        if banner.len() != 5 || banner[0] != "%%matrixmarket" || banner[1] != "matrix" {
+            return Err(parse_error(1, "expected a `%%MatrixMarket matrix` header"));
+        }
+        let coordinate = match banner[2].as_str() {
+            "coordinate" => true,
+            "array" => false,
+            _ => return Err(parse_error(1, "the format must be `coordinate` or `array`"))
+        };
+        if banner[3] != "real" && banner[3] != "integer" {
+            return Err(parse_error(1, "only `real` and `integer` matrices are supported"));
+        }
+        let symmetry = match banner[4].as_str() {
+            "general" => Symmetry::General,
+            "symmetric" => Symmetry::Symmetric,
+            "skew-symmetric" => Symmetry::SkewSymmetric,
+            _ => return Err(parse_error(1, "the symmetry must be `general`, `symmetric` or `skew-symmetric`"))
+        };
+
+        // Comments may follow the header; the first other line gives the size.
+        let mut entries = vec![];
+        for (index, line) in lines {
+            let line = line?;
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('%') {
+                continue;
+            }
+            entries.push((index + 1, line));
+        }
+        let mut entries = entries.into_iter();
+        let (size_line, size) = entries.next().ok_or_else(|| parse_error(1, "missing the size line"))?;
+        let size = size.split_whitespace()
+            .map(|field| field.parse::<usize>())
+            .collect::<Result<Vec<usize>, _>>()
+            .map_err(|_| parse_error(size_line, "invalid size"))?;
+        let (rows, cols) = match (coordinate, size.as_slice()) {
+            (true, &[rows, cols, _]) | (false, &[rows, cols]) => (rows, cols),
+            _ => return Err(parse_error(size_line, "expected the number of rows and columns"))
+        };
+        if symmetry != Symmetry::General && rows != cols {
+            return Err(parse_error(size_line, "a symmetric matrix must be square"));
+        }
+        // The size comes from the file, so check it before allocating the matrix.
+        let too_large = || parse_error(size_line, "matrix dimensions are too large");
+        let count = rows.checked_mul(cols).ok_or_else(too_large)?;
+        if count.checked_mul(std::mem::size_of::<T>()).is_none_or(|bytes| bytes > isize::MAX as usize) {
+            return Err(too_large());
+        }
+        if !coordinate {
+            // Every entry of an array is listed, so a short file can be rejected up front.
+            let expected = match symmetry {
+                Symmetry::General => count,
+                Symmetry::Symmetric => rows * (rows + 1) / 2,
+                Symmetry::SkewSymmetric => rows * rows.saturating_sub(1) / 2
+            };
+            let found: usize = entries.as_slice().iter().map(|(_, line)| line.split_whitespace().count()).sum();
+            if found < expected {
+                return Err(parse_error(size_line, &format!("expected {} entries but found {}", expected, found)));
+            }
+        }
+
+        let mut matrix = Matrix::new(rows, cols, T::zero());
+        let mut set = |i: usize, j: usize, val: T| {
+            match symmetry {
+                Symmetry::General => {}
+                Symmetry::Symmetric => matrix.data[j * cols + i] = val.clone(),
+                Symmetry::SkewSymmetric => matrix.data[j * cols + i] = T::zero() - val.clone()
+            }
+            matrix.data[i * cols + j] = val;
+        };
+
+        if coordinate {
+            let count = size[2];
+            let mut read = 0;
+            for (line_number, line) in entries {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                if fields.len() != 3 {
+                    return Err(parse_error(line_number, "expected a row, a column and a value"));
+                }
+                let index = |column: usize| -> io::Result<usize> {
+                    match parse_element::<usize>(fields[column - 1], line_number, column)? {
+                        0 => Err(invalid_data(MatrixError::InvalidElement {
+                            line: line_number,
+                            column,
+                            text: fields[column - 1].to_string()
+                        })),
+                        index => Ok(index - 1)
+                    }
+                };
+                let (i, j) = (index(1)?, index(2)?);
+                if i >= rows || j >= cols {
+                    return Err(invalid_data(MatrixError::IndexOutOfBounds { row: i, col: j, rows, cols }));
+                }
+                set(i, j, parse_element(fields[2], line_number, 3)?);
+                read += 1;
+            }
+            if read != count {
+                return Err(parse_error(size_line, &format!("expected {} entries but found {}", count, read)));
+            }
+        } else {
+            // Column-major, and only the lower triangle when symmetric.
+            let positions: Vec<(usize, usize)> = (0..cols)
+                .flat_map(|j| {
+                    let first = match symmetry {
+                        Symmetry::General => 0,
+                        Symmetry::Symmetric => j,
+                        Symmetry::SkewSymmetric => j + 1
+                    };
+                    (first..rows).map(move |i| (i, j))
+                })
+                .collect();
+            let mut read = 0;
+            for (line_number, line) in entries {
+                for (field, text) in line.split_whitespace().enumerate() {
+                    let &(i, j) = positions.get(read)
+                        .ok_or_else(|| parse_error(line_number, "more entries than the size allows"))?;
+                    set(i, j, parse_element(text, line_number, field + 1)?);
+                    read += 1;
+                }
+            }
+            if read != positions.len() {
+                return Err(parse_error(size_line, &format!("expected {} entries but found {}", positions.len(), read)));
+            }
+        }
+        Ok(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix_error(error: io::Error) -> MatrixError {
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        error.get_ref().unwrap().downcast_ref::<MatrixError>().unwrap().clone()
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        let matrix = Matrix::from(vec![
+            vec![1.5, -2.0, 3.0],
+            vec![0.25, 1e-20, f64::MAX]
+        ]);
+
+        let mut csv = vec![];
+        matrix.to_csv(&mut csv).unwrap();
+        assert_eq!(String::from_utf8(csv.clone()).unwrap().lines().next(), Some("1.5,-2,3"));
+
+        let loaded = Matrix::<f64>::from_csv(&csv[..]).unwrap();
+        assert_eq!(loaded, matrix);
+        assert_eq!(loaded.shape(), matrix.shape());
+
+        let spaced = Matrix::<i32>::from_csv(" 1, 2 \n\n3 ,4\n".as_bytes()).unwrap();
+        assert_eq!(spaced, Matrix::from(vec![vec![1, 2], vec![3, 4]]));
+    }
+
+    #[test]
+    fn csv_errors() {
+        let error = Matrix::<f64>::from_csv("1,2,3\n4,five,6\n".as_bytes()).unwrap_err();
+        assert_eq!(matrix_error(error), MatrixError::InvalidElement { line: 2, column: 2, text: "five".to_string() });
+
+        let error = Matrix::<f64>::from_csv("1,2\n3\n".as_bytes()).unwrap_err();
+        assert_eq!(matrix_error(error), MatrixError::RaggedRows { row: 1, expected: 2, found: 1 });
+
+        let error = Matrix::<f64>::from_csv("\n".as_bytes()).unwrap_err();
+        assert_eq!(matrix_error(error), MatrixError::EmptyInput);
+    }
+
+    #[test]
+    fn matrix_market_round_trip() {
+        let matrix = Matrix::from(vec![
+            vec![1.0, 0.0, -2.5],
+            vec![4.0, 5.0, 6.0]
+        ]);
+
+        let mut mtx = vec![];
+        matrix.to_matrix_market(&mut mtx).unwrap();
+        let text = String::from_utf8(mtx.clone()).unwrap();
+        assert!(text.starts_with("%%MatrixMarket matrix array real general\n2 3\n1\n4\n0\n"));
+
+        let loaded = Matrix::<f64>::from_matrix_market(&mtx[..]).unwrap();
+        assert_eq!(loaded, matrix);
+        assert_eq!(loaded.shape(), matrix.shape());
+    }
+
+    #[test]
+    fn matrix_market_coordinate_and_symmetric() {
+        let coordinate = "%%MatrixMarket matrix coordinate integer general\n\
+                          % written by hand\n\
+                          3 4 3\n\
+                          1 1 5\n\
+                          3 2 -1\n\
+                          2 4 7\n";
+        assert_eq!(
+            Matrix::<i64>::from_matrix_market(coordinate.as_bytes()).unwrap(),
+            Matrix::from(vec![vec![5, 0, 0, 0], vec![0, 0, 0, 7], vec![0, -1, 0, 0]])
+        );
+
+        let symmetric = "%%MatrixMarket matrix coordinate real symmetric\n2 2 2\n1 1 2.0\n2 1 -1.0\n";
+        assert_eq!(
+            Matrix::<f64>::from_matrix_market(symmetric.as_bytes()).unwrap(),
+            Matrix::from(vec![vec![2.0, -1.0], vec![-1.0, 0.0]])
+        );
+
+        let skew = "%%MatrixMarket matrix array real skew-symmetric\n3 3\n1\n2\n3\n";
+        assert_eq!(
+            Matrix::<f64>::from_matrix_market(skew.as_bytes()).unwrap(),
+            Matrix::from(vec![vec![0.0, -1.0, -2.0], vec![1.0, 0.0, -3.0], vec![2.0, 3.0, 0.0]])
+        );
+    }
+
+    #[test]
+    fn matrix_market_errors() {
+        let error = Matrix::<f64>::from_matrix_market("%%MatrixMarket matrix coordinate complex general\n".as_bytes()).unwrap_err();
+        assert!(matches!(matrix_error(error), MatrixError::Parse { line: 1, .. }));
+
+        let bad_value = "%%MatrixMarket matrix coordinate real general\n2 2 1\n1 2 x\n";
+        let error = Matrix::<f64>::from_matrix_market(bad_value.as_bytes()).unwrap_err();
+        assert_eq!(matrix_error(error), MatrixError::InvalidElement { line: 3, column: 3, text: "x".to_string() });
+
+        let out_of_bounds = "%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1.0\n";
+        let error = Matrix::<f64>::from_matrix_market(out_of_bounds.as_bytes()).unwrap_err();
+        assert_eq!(matrix_error(error), MatrixError::IndexOutOfBounds { row: 2, col: 0, rows: 2, cols: 2 });
+
+        let short = "%%MatrixMarket matrix array real general\n2 2\n1\n2\n3\n";
+        let error = Matrix::<f64>::from_matrix_market(short.as_bytes()).unwrap_err();
+        assert!(matches!(matrix_error(error), MatrixError::Parse { line: 2, .. }));
+
+        let overflowing = format!("%%MatrixMarket matrix coordinate real general\n{} 2 0\n", usize::MAX);
+        let error = Matrix::<f64>::from_matrix_market(overflowing.as_bytes()).unwrap_err();
+        assert!(matches!(matrix_error(error), MatrixError::Parse { line: 2, .. }));
+
+        // Far too large to allocate, but rejected from the entry count alone.
+        let huge = "%%MatrixMarket matrix array real general\n1000000 1000000\n1\n";
+        let error = Matrix::<f64>::from_matrix_market(huge.as_bytes()).unwrap_err();
+        assert!(matches!(matrix_error(error), MatrixError::Parse { line: 2, .. }));
+    }
+}
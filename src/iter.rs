@@ -0,0 +1,124 @@
+use std::iter::StepBy;
+use std::slice;
+
+use crate::Matrix;
+
+impl<T> Matrix<T> {
+    /// The elements in row-major order.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Each row as a slice, from top to bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        (0..self.rows).map(move |i| &self.data[i * self.cols..(i + 1) * self.cols])
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> + '_ {
+        let cols = self.cols;
+        let mut rest = &mut self.data[..];
+        (0..self.rows).map(move |_| {
+            let (row, tail) = std::mem::take(&mut rest).split_at_mut(cols);
+            rest = tail;
+            row
+        })
+    }
+
+    /// The elements of column `col` from top to bottom, read in place.
+    pub fn column(&self, col: usize) -> StepBy<slice::Iter<'_, T>> {
+        assert!(col < self.cols, "column {} is out of bounds for a matrix with {} columns", col, self.cols);
+        self.data[col..].iter().step_by(self.cols)
+    }
+
+    /// Each column as a strided iterator, from left to right.
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = StepBy<slice::Iter<'_, T>>> + ExactSizeIterator + '_ {
+        (0..self.cols).map(move |j| self.column(j))
+    }
+
+    /// Every element together with its `(row, col)` position, in row-major order.
+    pub fn enumerate_indices(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        let cols = self.cols;
+        self.data.iter().enumerate().map(move |(index, val)| ((index / cols, index % cols), val))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Matrix<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Matrix<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Matrix<i32> {
+        Matrix::from(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6]
+        ])
+    }
+
+    #[test]
+    fn iterate_elements() {
+        let mut matrix = example();
+        assert_eq!(matrix.iter().sum::<i32>(), 21);
+
+        for val in matrix.iter_mut() {
+            *val *= 2;
+        }
+        for val in &mut matrix {
+            *val += 1;
+        }
+        assert_eq!((&matrix).into_iter().cloned().collect::<Vec<_>>(), vec![3, 5, 7, 9, 11, 13]);
+
+        let positions: Vec<_> = example().enumerate_indices().filter(|(_, &val)| val % 2 == 0).map(|(position, _)| position).collect();
+        assert_eq!(positions, vec![(0, 1), (1, 0), (1, 2)]);
+    }
+
+    #[test]
+    fn iterate_rows() {
+        let mut matrix = example();
+        let rows: Vec<&[i32]> = matrix.rows().collect();
+        assert_eq!(rows, vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+        assert_eq!(matrix.rows().len(), 2);
+        assert_eq!(matrix.rows().next_back(), Some(&[4, 5, 6][..]));
+
+        for row in matrix.rows_mut() {
+            row.reverse();
+        }
+        assert_eq!(matrix, Matrix::from(vec![
+            vec![3, 2, 1],
+            vec![6, 5, 4]
+        ]));
+
+        let mut empty_rows: Matrix<i32> = Matrix::new(3, 0, 0);
+        assert_eq!(empty_rows.rows().count(), 3);
+        assert_eq!(empty_rows.rows_mut().filter(|row| row.is_empty()).count(), 3);
+    }
+
+    #[test]
+    fn iterate_columns() {
+        let matrix = example();
+        let sums: Vec<i32> = matrix.columns().map(|column| column.sum()).collect();
+        assert_eq!(sums, vec![5, 7, 9]);
+        assert_eq!(matrix.column(1).cloned().collect::<Vec<_>>(), matrix.get_column(1));
+        assert_eq!(matrix.columns().len(), 3);
+    }
+}
@@ -0,0 +1,170 @@
+use num::Float;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+
+/// A covariance function between points, as used in Gaussian process models.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kernel<T> {
+    /// The squared exponential `exp(-r² / 2l²)`.
+    Rbf { lengthscale: T },
+    /// The Matérn kernel with smoothness `nu`, which must be 0.5, 1.5 or 2.5;
+    /// those are the values with a closed form and the ones used in practice.
+    Matern { lengthscale: T, nu: T },
+    /// The dot product plus a constant `bias`.
+    Linear { bias: T }
+}
+
+impl<T: Float> Kernel<T> {
+    /// Checks the kernel's parameters, returning [`MatrixError::InvalidParameter`]
+    /// for a Matérn smoothness without a closed form.
+    pub fn validate(&self) -> Result<(), MatrixError> {
+        match *self {
+            Kernel::Matern { nu, .. } => {
+                if [0.5, 1.5, 2.5].iter().any(|&supported| nu == T::from(supported).unwrap()) {
+                    Ok(())
+                } else {
+                    Err(MatrixError::InvalidParameter { name: "nu", reason: "Matern smoothness must be 0.5, 1.5 or 2.5" })
+                }
+            }
+            _ => Ok(())
+        }
+    }
+
+    /// The kernel's value between two points.
+    ///
+    /// Panics if [`Kernel::validate`] would fail; [`Matrix::kernel_matrix`]
+    /// reports that as an error instead.
+    pub fn evaluate(&self, x: &[T], y: &[T]) -> T {
+        match *self {
+            Kernel::Rbf { lengthscale } => {
+                let squared = squared_distance(x, y) / (lengthscale * lengthscale);
+                (-squared / T::from(2.0).unwrap()).exp()
+            }
+            Kernel::Matern { lengthscale, nu } => {
+                let r = squared_distance(x, y).sqrt() / lengthscale;
+                if nu == T::from(0.5).unwrap() {
+                    (-r).exp()
+                } else if nu == T::from(1.5).unwrap() {
+                    let scaled = T::from(3.0).unwrap().sqrt() * r;
+                    (T::one() + scaled) * (-scaled).exp()
+                } else if nu == T::from(2.5).unwrap() {
+                    let scaled = T::from(5.0).unwrap().sqrt() * r;
+                    (T::one() + scaled + scaled * scaled / T::from(3.0).unwrap()) * (-scaled).exp()
+                } else {
+                    panic!("Matern smoothness must be 0.5, 1.5 or 2.5");
+                }
+            }
+            Kernel::Linear { bias } => x.iter().zip(y.iter()).fold(bias, |total, (&a, &b)| total + a * b)
+        }
+    }
+}
+
+fn squared_distance<T: Float>(x: &[T], y: &[T]) -> T {
+    x.iter().zip(y.iter()).fold(T::zero(), |total, (&a, &b)| total + (a - b) * (a - b))
+}
+
+impl<T: Float> Matrix<T> {
+    /// The Gram matrix `k[i][j] = kernel(x1 row i, x2 row j)` between two sets of
+    /// points stored one per row.
+    ///
+    /// With `x1 == x2` this is the covariance matrix of a Gaussian process prior,
+    /// which is symmetric positive semi-definite.
+    ///
+    /// Returns [`MatrixError::InvalidParameter`] for an unsupported Matérn smoothness.
+    pub fn kernel_matrix(x1: &Matrix<T>, x2: &Matrix<T>, kernel: &Kernel<T>) -> Result<Matrix<T>, MatrixError> {
+        kernel.validate()?;
+        if x1.cols != x2.cols {
+            return Err(MatrixError::DimensionMismatch {
+                left: x1.shape(),
+                right: x2.shape()
+            });
+        }
+
+        let mut data = vec![];
+        for i in 0..x1.rows {
+            let point = &x1.data[i * x1.cols..(i + 1) * x1.cols];
+            for j in 0..x2.rows {
+                data.push(kernel.evaluate(point, &x2.data[j * x2.cols..(j + 1) * x2.cols]));
+            }
+        }
+        Ok(Matrix {
+            rows: x1.rows,
+            cols: x2.rows,
+            data
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Shape;
+
+    #[test]
+    fn kernel_values() {
+        let rbf = Kernel::Rbf { lengthscale: 2.0 };
+        assert_eq!(rbf.evaluate(&[1.0, 1.0], &[1.0, 1.0]), 1.0);
+        assert!((rbf.evaluate(&[0.0], &[2.0]) - (-0.5f64).exp()).abs() < 1e-15);
+
+        let exponential = Kernel::Matern { lengthscale: 1.0, nu: 0.5 };
+        assert!((exponential.evaluate(&[0.0, 0.0], &[3.0, 4.0]) - (-5.0f64).exp()).abs() < 1e-15);
+        for nu in [1.5, 2.5] {
+            let matern = Kernel::Matern { lengthscale: 1.0, nu };
+            assert_eq!(matern.evaluate(&[1.0], &[1.0]), 1.0);
+            assert!(matern.evaluate(&[0.0], &[1.0]) < matern.evaluate(&[0.0], &[0.5]));
+        }
+
+        let linear = Kernel::Linear { bias: 1.0 };
+        assert_eq!(linear.evaluate(&[1.0, 2.0], &[3.0, 4.0]), 12.0);
+    }
+
+    #[test]
+    fn kernel_matrix_shape_and_symmetry() {
+        let x = Matrix::from(vec![
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![0.0, 2.0]
+        ]);
+        let y = Matrix::from(vec![vec![1.0, 1.0]]);
+        let kernel = Kernel::Rbf { lengthscale: 1.0 };
+
+        let gram = Matrix::kernel_matrix(&x, &x, &kernel).unwrap();
+        for i in 0..3 {
+            assert_eq!(*gram.get(i, i), 1.0);
+            for j in 0..3 {
+                assert_eq!(gram.get(i, j), gram.get(j, i));
+            }
+        }
+
+        let cross = Matrix::kernel_matrix(&x, &y, &kernel).unwrap();
+        assert_eq!(cross.shape(), Shape::new(3, 1));
+        assert_eq!(
+            Matrix::kernel_matrix(&x, &Matrix::new(1, 3, 0.0), &kernel),
+            Err(MatrixError::DimensionMismatch { left: Shape::new(3, 2), right: Shape::new(1, 3) })
+        );
+
+        let unsupported = Kernel::Matern { lengthscale: 1.0, nu: 1.0 };
+        assert!(Kernel::Rbf { lengthscale: 1.0 }.validate().is_ok());
+        assert_eq!(
+            Matrix::kernel_matrix(&x, &x, &unsupported),
+            Err(MatrixError::InvalidParameter { name: "nu", reason: "Matern smoothness must be 0.5, 1.5 or 2.5" })
+        );
+    }
+
+    #[test]
+    fn gaussian_process_regression() {
+        // Noise-free observations of sin(x); the posterior mean interpolates them.
+        let train = Matrix::from(vec![vec![0.0], vec![1.0], vec![2.0], vec![3.0]]);
+        let targets = Matrix::from(vec![vec![0.0], vec![1.0f64.sin()], vec![2.0f64.sin()], vec![3.0f64.sin()]]);
+        let test = Matrix::from(vec![vec![1.0], vec![1.5]]);
+        let kernel = Kernel::Matern { lengthscale: 1.5, nu: 2.5 };
+
+        let covariance = Matrix::kernel_matrix(&train, &train, &kernel).unwrap();
+        let weights = covariance.solve(&targets).unwrap();
+        let mean = Matrix::kernel_matrix(&test, &train, &kernel).unwrap() * weights;
+
+        assert!((mean.get(0, 0) - 1.0f64.sin()).abs() < 1e-10);
+        assert!((mean.get(1, 0) - 1.5f64.sin()).abs() < 0.05);
+    }
+}
@@ -0,0 +1,297 @@
+use std::fmt::Debug;
+
+use num::Float;
+
+use crate::error::MatrixError;
+use crate::precondition::Preconditioner;
+use crate::progress::SolverOptions;
+use crate::reflectors::{givens, Givens};
+use crate::traits::MatrixOps;
+
+fn dot<T: Float>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b.iter()).fold(T::zero(), |total, (&x, &y)| total + x * y)
+}
+
+fn norm<T: Float>(a: &[T]) -> T {
+    dot(a, a).sqrt()
+}
+
+/// `y += alpha * x`
+fn axpy<T: Float>(y: &mut [T], alpha: T, x: &[T]) {
+    for (val, &other) in y.iter_mut().zip(x.iter()) {
+        *val = *val + alpha * other;
+    }
+}
+
+fn residual<T, M>(a: &M, x: &[T], b: &[T]) -> Vec<T>
+    where T: Float + Debug, M: MatrixOps<T>
+{
+    a.matvec(x).into_iter().zip(b.iter()).map(|(ax, &val)| val - ax).collect()
+}
+
+fn check_system<T, M>(a: &M, b: &[T]) -> Result<(), MatrixError>
+    where T: Float + Debug, M: MatrixOps<T>
+{
+    let shape = a.shape();
+    if !shape.is_square() {
+        return Err(MatrixError::NotSquare { rows: shape.rows, cols: shape.cols });
+    }
+    if b.len() != shape.rows {
+        return Err(MatrixError::DimensionMismatch {
+            left: shape,
+            right: (b.len(), 1).into()
+        });
+    }
+    Ok(())
+}
+
+/// Solves `a * x = b` for symmetric positive definite `a` by the preconditioned
+/// conjugate gradient method.
+///
+/// `preconditioner` must be symmetric positive definite too, e.g. [`Jacobi`],
+/// [`Ssor`] or [`IncompleteCholesky`]; pass [`Identity`] for plain CG. Iteration
+/// stops once `‖b - a * x‖ <= tolerance * ‖b‖`, and each iteration reports that
+/// relative residual to the progress callback. Running out of iterations gives
+/// [`MatrixError::NoConvergence`].
+///
+/// [`Jacobi`]: crate::Jacobi
+/// [`Ssor`]: crate::Ssor
+/// [`IncompleteCholesky`]: crate::IncompleteCholesky
+/// [`Identity`]: crate::Identity
+pub fn conjugate_gradient<T, M, P>(a: &M, b: &[T], preconditioner: &P, options: &mut SolverOptions) -> Result<Vec<T>, MatrixError>
+    where T: Float + Debug, M: MatrixOps<T>, P: Preconditioner<T>
+{
+    check_system(a, b)?;
+    let b_norm = norm(b);
+    let mut x = vec![T::zero(); b.len()];
+    if b_norm.is_zero() {
+        return Ok(x);
+    }
+    let tolerance = T::from(options.tolerance).unwrap() * b_norm;
+
+    let mut r = b.to_vec();
+    let mut z = preconditioner.apply(&r);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+    for iteration in 0..options.max_iterations {
+        let ap = a.matvec(&p);
+        let alpha = rz / dot(&p, &ap);
+        axpy(&mut x, alpha, &p);
+        axpy(&mut r, -alpha, &ap);
+
+        let r_norm = norm(&r);
+        if options.report(iteration, (r_norm / b_norm).to_f64().unwrap()).is_break() {
+            return Err(MatrixError::Cancelled { iterations: iteration + 1 });
+        }
+        if r_norm <= tolerance {
+            return Ok(x);
+        }
+
+        z = preconditioner.apply(&r);
+        let next_rz = dot(&r, &z);
+        let beta = next_rz / rz;
+        rz = next_rz;
+        for (val, &other) in p.iter_mut().zip(z.iter()) {
+            *val = other + beta * *val;
+        }
+    }
+    Err(MatrixError::NoConvergence { iterations: options.max_iterations })
+}
+
+/// Solves a general square system `a * x = b` by restarted GMRES with right
+/// preconditioning.
+///
+/// Each cycle builds an orthonormal Krylov basis of up to `restart` vectors for
+/// `a * M⁻¹` and picks the combination minimizing the residual, so the residual
+/// never grows within a cycle; larger `restart` values converge in fewer
+/// iterations but store more vectors. Right preconditioning leaves the residual
+/// that is monitored equal to the true one. Stopping, progress reporting and
+/// errors are as for [`conjugate_gradient`], with one iteration per basis vector.
+pub fn gmres<T, M, P>(
+    a: &M,
+    b: &[T],
+    preconditioner: &P,
+    restart: usize,
+    options: &mut SolverOptions
+) -> Result<Vec<T>, MatrixError>
+    where T: Float + Debug, M: MatrixOps<T>, P: Preconditioner<T>
+{
+    assert!(restart > 0, "GMRES needs at least one basis vector per cycle");
+    check_system(a, b)?;
+    let b_norm = norm(b);
+    let mut x = vec![T::zero(); b.len()];
+    if b_norm.is_zero() {
+        return Ok(x);
+    }
+    let tolerance = T::from(options.tolerance).unwrap() * b_norm;
+
+    let mut iteration = 0;
+    while iteration < options.max_iterations {
+        let r = residual(a, &x, b);
+        let beta = norm(&r);
+        if beta <= tolerance {
+            return Ok(x);
+        }
+
+        let mut basis = vec![r.into_iter().map(|val| val / beta).collect::<Vec<T>>()];
+        let mut preconditioned: Vec<Vec<T>> = vec![];
+        // Columns of the Hessenberg matrix, reduced to upper triangular by the rotations as they are built.
+        let mut hessenberg: Vec<Vec<T>> = vec![];
+        let mut rotations: Vec<Givens<T>> = vec![];
+        let mut g = vec![beta];
+
+        while preconditioned.len() < restart && iteration < options.max_iterations {
+            let j = preconditioned.len();
+            let z = preconditioner.apply(&basis[j]);
+            let mut w = a.matvec(&z);
+            preconditioned.push(z);
+
+            // Modified Gram-Schmidt against the basis so far.
+            let mut column = Vec::with_capacity(j + 2);
+            for v in basis.iter() {
+                let h = dot(&w, v);
+                axpy(&mut w, -h, v);
+                column.push(h);
+            }
+            let next_norm = norm(&w);
+            column.push(next_norm);
+
+            for (i, rotation) in rotations.iter().enumerate() {
+                let (x0, x1) = (column[i], column[i + 1]);
+                column[i] = rotation.c * x0 + rotation.s * x1;
+                column[i + 1] = rotation.c * x1 - rotation.s * x0;
+            }
+            let rotation = givens(column[j], column[j + 1]);
+            column[j] = rotation.r;
+            column[j + 1] = T::zero();
+            g.push(-rotation.s * g[j]);
+            g[j] = rotation.c * g[j];
+            rotations.push(rotation);
+            hessenberg.push(column);
+
+            let r_norm = g[j + 1].abs();
+            if options.report(iteration, (r_norm / b_norm).to_f64().unwrap()).is_break() {
+                return Err(MatrixError::Cancelled { iterations: iteration + 1 });
+            }
+            iteration += 1;
+            // A zero next vector means the Krylov space is invariant and the solution exact.
+            if r_norm <= tolerance || next_norm.is_zero() {
+                break;
+            }
+            basis.push(w.into_iter().map(|val| val / next_norm).collect());
+        }
+
+        // Back substitution through the triangular factor for the basis coefficients.
+        let k = hessenberg.len();
+        let mut y = vec![T::zero(); k];
+        for i in (0..k).rev() {
+            let total = (i + 1..k).fold(g[i], |total, l| total - hessenberg[l][i] * y[l]);
+            y[i] = total / hessenberg[i][i];
+        }
+        for (coefficient, z) in y.into_iter().zip(preconditioned.iter()) {
+            axpy(&mut x, coefficient, z);
+        }
+    }
+
+    if norm(&residual(a, &x, b)) <= tolerance {
+        return Ok(x);
+    }
+    Err(MatrixError::NoConvergence { iterations: options.max_iterations })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+
+    use super::*;
+    use crate::Matrix;
+    use crate::precondition::{Identity, IncompleteCholesky, Ilu0, Jacobi, Ssor};
+
+    /// The 5-point Laplacian on a `side x side` grid, a typical sparse SPD test problem.
+    fn laplacian(side: usize) -> Matrix<f64> {
+        let n = side * side;
+        let mut matrix = Matrix::new(n, n, 0.0);
+        for i in 0..side {
+            for j in 0..side {
+                let row = i * side + j;
+                matrix.set(row, row, 4.0);
+                if i > 0 {
+                    matrix.set(row, row - side, -1.0);
+                }
+                if i + 1 < side {
+                    matrix.set(row, row + side, -1.0);
+                }
+                if j > 0 {
+                    matrix.set(row, row - 1, -1.0);
+                }
+                if j + 1 < side {
+                    matrix.set(row, row + 1, -1.0);
+                }
+            }
+        }
+        matrix
+    }
+
+    fn iterations_to_solve(solve: impl FnOnce(&mut SolverOptions) -> Result<Vec<f64>, MatrixError>, a: &Matrix<f64>, b: &[f64]) -> usize {
+        let mut count = 0;
+        let x = {
+            let mut options = SolverOptions::default().tolerance(1e-10).progress(|_| {
+                count += 1;
+                ControlFlow::Continue(())
+            });
+            solve(&mut options).unwrap()
+        };
+        let r = residual(a, &x, b);
+        assert!(norm(&r) <= 1e-9 * norm(b), "residual {:?}", norm(&r));
+        count
+    }
+
+    #[test]
+    fn preconditioning_speeds_up_conjugate_gradient() {
+        let a = laplacian(12);
+        let b: Vec<f64> = (0..144).map(|i| ((i * 7) % 11) as f64 - 5.0).collect();
+
+        let plain = iterations_to_solve(|options| conjugate_gradient(&a, &b, &Identity, options), &a, &b);
+        let ssor = Ssor::new(&a, 1.5).unwrap();
+        let with_ssor = iterations_to_solve(|options| conjugate_gradient(&a, &b, &ssor, options), &a, &b);
+        let ichol = IncompleteCholesky::new(&a).unwrap();
+        let with_ichol = iterations_to_solve(|options| conjugate_gradient(&a, &b, &ichol, options), &a, &b);
+
+        assert!(with_ssor < plain && with_ichol < plain, "{} {} {}", plain, with_ssor, with_ichol);
+    }
+
+    #[test]
+    fn gmres_solves_nonsymmetric_system() {
+        // A convection-diffusion style operator: the Laplacian plus an upwind term.
+        let mut a = laplacian(8);
+        for row in 1..64 {
+            let val = *a.get(row, row - 1) - 0.8;
+            a.set(row, row - 1, val);
+        }
+        let b: Vec<f64> = (0..64).map(|i| (i % 5) as f64).collect();
+
+        let plain = iterations_to_solve(|options| gmres(&a, &b, &Identity, 10, options), &a, &b);
+        let ilu = Ilu0::new(&a).unwrap();
+        let with_ilu = iterations_to_solve(|options| gmres(&a, &b, &ilu, 10, options), &a, &b);
+        let jacobi = Jacobi::new(&a).unwrap();
+        iterations_to_solve(|options| gmres(&a, &b, &jacobi, 30, options), &a, &b);
+
+        assert!(with_ilu < plain, "{} {}", plain, with_ilu);
+    }
+
+    #[test]
+    fn krylov_errors() {
+        let a = laplacian(4);
+        let b = vec![1.0; 16];
+        let mut options = SolverOptions::default().max_iterations(2);
+        assert_eq!(conjugate_gradient(&a, &b, &Identity, &mut options), Err(MatrixError::NoConvergence { iterations: 2 }));
+        assert_eq!(gmres(&a, &b, &Identity, 5, &mut options), Err(MatrixError::NoConvergence { iterations: 2 }));
+
+        let mut cancelling = SolverOptions::default().progress(|_| ControlFlow::Break(()));
+        assert_eq!(gmres(&a, &b, &Identity, 5, &mut cancelling), Err(MatrixError::Cancelled { iterations: 1 }));
+
+        let mut options = SolverOptions::default();
+        assert_eq!(conjugate_gradient(&a, &[0.0; 16], &Identity, &mut options), Ok(vec![0.0; 16]));
+        assert!(conjugate_gradient(&a, &[1.0; 3], &Identity, &mut options).is_err());
+    }
+}
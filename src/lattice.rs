@@ -0,0 +1,177 @@
+use num::{Integer, One, Signed, Zero};
+use num::rational::Ratio;
+
+use crate::Matrix;
+use crate::element::MatrixElement;
+
+fn dot<T: MatrixElement + Integer>(a: &[Ratio<T>], b: &[Ratio<T>]) -> Ratio<T> {
+    a.iter().zip(b.iter()).fold(Ratio::zero(), |total, (x, y)| total + x.clone() * y.clone())
+}
+
+/// Gram-Schmidt orthogonalisation without normalisation, returning the squared
+/// norms of the orthogonal vectors and the projection coefficients `mu`.
+fn gram_schmidt<T>(basis: &[Vec<Ratio<T>>]) -> (Vec<Ratio<T>>, Vec<Vec<Ratio<T>>>)
+    where T: MatrixElement + Integer
+{
+    let n = basis.len();
+    let mut orthogonal: Vec<Vec<Ratio<T>>> = vec![];
+    let mut norms: Vec<Ratio<T>> = vec![];
+    let mut mu = vec![vec![Ratio::zero(); n]; n];
+
+    for i in 0..n {
+        let mut vector = basis[i].clone();
+        for j in 0..i {
+            mu[i][j] = dot(&basis[i], &orthogonal[j]) / norms[j].clone();
+            for (val, other) in vector.iter_mut().zip(orthogonal[j].iter()) {
+                *val = val.clone() - mu[i][j].clone() * other.clone();
+            }
+        }
+        let norm = dot(&vector, &vector);
+        assert!(!norm.is_zero(), "lattice basis rows must be linearly independent");
+        norms.push(norm);
+        orthogonal.push(vector);
+    }
+    (norms, mu)
+}
+
+/// LLL reduction of the rows of `basis` using exact rational arithmetic.
+fn reduce<T>(mut basis: Vec<Vec<Ratio<T>>>, delta: Ratio<T>) -> Vec<Vec<Ratio<T>>>
+    where T: MatrixElement + Integer + Signed
+{
+    let n = basis.len();
+    let (mut norms, mut mu) = gram_schmidt(&basis);
+
+    let mut k = 1;
+    while k < n {
+        // Size reduction: make |mu[k][j]| <= 1/2 for every j < k.
+        for j in (0..k).rev() {
+            let q = mu[k][j].round();
+            if q.is_zero() {
+                continue;
+            }
+            for col in 0..basis[k].len() {
+                let val = basis[k][col].clone() - q.clone() * basis[j][col].clone();
+                basis[k][col] = val;
+            }
+            for i in 0..j {
+                mu[k][i] = mu[k][i].clone() - q.clone() * mu[j][i].clone();
+            }
+            mu[k][j] = mu[k][j].clone() - q;
+        }
+
+        // Lovász condition.
+        let projection = mu[k][k - 1].clone() * mu[k][k - 1].clone();
+        if norms[k] >= (delta.clone() - projection) * norms[k - 1].clone() {
+            k += 1;
+        } else {
+            basis.swap(k, k - 1);
+            let (new_norms, new_mu) = gram_schmidt(&basis);
+            norms = new_norms;
+            mu = new_mu;
+            k = (k - 1).max(1);
+        }
+    }
+    basis
+}
+
+fn check_delta<T: MatrixElement + Integer + Signed>(delta: &Ratio<T>) {
+    let quarter = Ratio::new(T::one(), T::one() + T::one() + T::one() + T::one());
+    assert!(*delta > quarter && *delta <= Ratio::one(), "delta must lie in (1/4, 1]");
+}
+
+impl<T: MatrixElement + Integer + Signed> Matrix<T> {
+    /// Lenstra-Lenstra-Lovász reduction of the lattice spanned by the rows.
+    ///
+    /// Returns a basis of the same lattice with short, nearly orthogonal rows.
+    /// `delta` must lie in `(1/4, 1]`; `3/4` is the classic choice and values closer
+    /// to 1 give a stronger reduction at a higher cost. The rows must be linearly
+    /// independent.
+    pub fn lll(&self, delta: Ratio<T>) -> Matrix<T> {
+        check_delta(&delta);
+
+        let basis = (0..self.rows)
+            .map(|i| self.get_row(i).into_iter().map(Ratio::from_integer).collect())
+            .collect();
+        let reduced = reduce(basis, delta);
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: reduced.into_iter().flatten().map(|val| val.to_integer()).collect()
+        }
+    }
+}
+
+impl<T: MatrixElement + Integer + Signed> Matrix<Ratio<T>> {
+    /// [`Matrix::lll`] for a lattice with rational basis vectors.
+    pub fn lll_rational(&self, delta: Ratio<T>) -> Matrix<Ratio<T>> {
+        check_delta(&delta);
+
+        let basis = (0..self.rows).map(|i| self.get_row(i)).collect();
+        let reduced = reduce(basis, delta);
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: reduced.into_iter().flatten().collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn squared_norm(row: &[i64]) -> i64 {
+        row.iter().map(|val| val * val).sum()
+    }
+
+    #[test]
+    fn lll_reduces_basis() {
+        let basis: Matrix<i64> = Matrix::from(vec![
+            vec![1, 1, 1],
+            vec![-1, 0, 2],
+            vec![3, 5, 6]
+        ]);
+
+        let reduced = basis.lll(Ratio::new(3, 4));
+
+        let expected_matrix = Matrix::from(vec![
+            vec![0, 1, 0],
+            vec![1, 0, 1],
+            vec![-2, 0, 1]
+        ]);
+        assert_eq!(reduced, expected_matrix);
+        assert_eq!(reduced.hermite_normal_form().h, basis.hermite_normal_form().h);
+    }
+
+    #[test]
+    fn lll_finds_short_vector() {
+        // A knapsack-style basis whose lattice contains much shorter vectors.
+        let basis: Matrix<i64> = Matrix::from(vec![
+            vec![1, 0, 0, 1345],
+            vec![0, 1, 0, 35],
+            vec![0, 0, 1, 154]
+        ]);
+
+        let reduced = basis.lll(Ratio::new(99, 100));
+
+        let shortest = (0..3).map(|i| squared_norm(&reduced.get_row(i))).min().unwrap();
+        assert!(shortest < squared_norm(&basis.get_row(1)));
+        // The row-style Hermite normal form is canonical, so equal forms mean equal lattices.
+        assert_eq!(reduced.hermite_normal_form().h, basis.hermite_normal_form().h);
+    }
+
+    #[test]
+    fn lll_rational_basis() {
+        let basis = Matrix::from(vec![
+            vec![Ratio::new(1, 2), Ratio::new(0, 1)],
+            vec![Ratio::new(5, 2), Ratio::new(1, 3)]
+        ]);
+
+        let reduced = basis.lll_rational(Ratio::new(3, 4));
+
+        assert_eq!(reduced.get_row(0), vec![Ratio::new(0, 1), Ratio::new(1, 3)]);
+        assert_eq!(reduced.get_row(1), vec![Ratio::new(1, 2), Ratio::new(0, 1)]);
+    }
+}
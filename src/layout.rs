@@ -0,0 +1,160 @@
+use crate::Matrix;
+use crate::error::MatrixError;
+use crate::shape::Shape;
+
+impl<T> Matrix<T> {
+    /// The same elements in row-major order, reinterpreted as a `rows x cols`
+    /// matrix without copying, or an error if the element count differs.
+    pub fn reshape(self, rows: usize, cols: usize) -> Result<Matrix<T>, MatrixError> {
+        if rows * cols != self.data.len() {
+            return Err(MatrixError::DimensionMismatch { left: self.shape(), right: Shape::new(rows, cols) });
+        }
+        Ok(Matrix {
+            rows,
+            cols,
+            data: self.data
+        })
+    }
+
+    /// The elements in row-major order, taking ownership of the storage.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// The elements in row-major order, borrowed from the storage.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// The mutable counterpart of [`Matrix::as_slice`]. The shape cannot
+    /// change through it, so every slice position stays a valid element.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Changes the shape to `rows x cols` in place, keeping the elements that
+    /// lie within both shapes at their positions and setting new ones to `fill`.
+    pub fn resize(&mut self, rows: usize, cols: usize, fill: T) {
+        if cols == self.cols {
+            self.data.resize(rows * cols, fill);
+        } else {
+            let mut data = Vec::with_capacity(rows * cols);
+            for i in 0..rows {
+                if i < self.rows {
+                    let kept = cols.min(self.cols);
+                    data.extend_from_slice(&self.data[i * self.cols..i * self.cols + kept]);
+                    data.resize((i + 1) * cols, fill.clone());
+                } else {
+                    data.resize(rows * cols, fill);
+                    break;
+                }
+            }
+            self.data = data;
+        }
+        self.rows = rows;
+        self.cols = cols;
+    }
+
+    /// Builds a `rows x cols` matrix from elements stored column by column, as
+    /// produced by Fortran and LAPACK, or an error if there aren't exactly
+    /// `rows * cols` of them. The error gives `data` as a single column.
+    pub fn from_column_slice(rows: usize, cols: usize, data: &[T]) -> Result<Matrix<T>, MatrixError> {
+        if rows.checked_mul(cols) != Some(data.len()) {
+            return Err(MatrixError::DimensionMismatch { left: Shape::new(data.len(), 1), right: Shape::new(rows, cols) });
+        }
+
+        let mut row_major = Vec::with_capacity(data.len());
+        for i in 0..rows {
+            for j in 0..cols {
+                row_major.push(data[j * rows + i].clone());
+            }
+        }
+
+        Ok(Matrix {
+            rows,
+            cols,
+            data: row_major
+        })
+    }
+
+    /// The elements in column-major order, the inverse of [`Matrix::from_column_slice`].
+    pub fn to_column_major_vec(&self) -> Vec<T> {
+        let mut output = Vec::with_capacity(self.data.len());
+        for j in 0..self.cols {
+            output.extend(self.get_column(j));
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reshape_and_flat_access() {
+        let matrix = Matrix::from(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6]
+        ]);
+
+        let reshaped = matrix.clone().reshape(3, 2).unwrap();
+        assert_eq!(reshaped.shape(), Shape::new(3, 2));
+        assert_eq!(*reshaped.get(2, 0), 5);
+        assert_eq!(
+            matrix.clone().reshape(4, 2),
+            Err(MatrixError::DimensionMismatch { left: Shape::new(2, 3), right: Shape::new(4, 2) })
+        );
+
+        let mut matrix = matrix;
+        assert_eq!(matrix.as_slice(), &[1, 2, 3, 4, 5, 6]);
+        matrix.as_mut_slice()[4] = 0;
+        assert_eq!(*matrix.get(1, 1), 0);
+        assert_eq!(matrix.into_vec(), vec![1, 2, 3, 4, 0, 6]);
+    }
+
+    #[test]
+    fn resize_keeps_overlap() {
+        let mut matrix = Matrix::from(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6]
+        ]);
+
+        matrix.resize(3, 4, 0);
+        assert_eq!(matrix.shape(), Shape::new(3, 4));
+        assert_eq!(matrix, Matrix::from(vec![vec![1, 2, 3, 0], vec![4, 5, 6, 0], vec![0, 0, 0, 0]]));
+
+        matrix.resize(2, 2, 9);
+        assert_eq!(matrix, Matrix::from(vec![vec![1, 2], vec![4, 5]]));
+
+        matrix.resize(1, 2, 9);
+        matrix.resize(2, 2, 7);
+        assert_eq!(matrix, Matrix::from(vec![vec![1, 2], vec![7, 7]]));
+
+        matrix.resize(0, 5, 1);
+        assert_eq!((matrix.shape(), matrix.as_slice().len()), (Shape::new(0, 5), 0));
+    }
+
+    #[test]
+    fn column_major_round_trip() {
+        let matrix = Matrix::from_column_slice(2, 3, &[1, 4, 2, 5, 3, 6]).unwrap();
+
+        let expected_matrix = Matrix::from(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6]
+        ]);
+        assert_eq!(matrix, expected_matrix);
+        assert_eq!((matrix.rows, matrix.cols), (2, 3));
+        assert_eq!(matrix.to_column_major_vec(), vec![1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn column_slice_wrong_length() {
+        assert_eq!(
+            Matrix::from_column_slice(2, 2, &[1, 2, 3]),
+            Err(MatrixError::DimensionMismatch { left: Shape::new(3, 1), right: Shape::new(2, 2) })
+        );
+    }
+}
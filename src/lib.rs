@@ -1,31 +1,224 @@
-use std::ops::{Mul, AddAssign, MulAssign};
+//! Dense matrices and the numerical algorithms built on them.
+//!
+//! The central type is [`Matrix`], a row-major matrix generic over its element
+//! type. Most operations only ask for [`MatrixElement`] (any `num::Num` that is
+//! `Clone + Debug`), so integers, rationals, big integers, modular integers and
+//! dual numbers all work; floating-point algorithms additionally need
+//! `num::Float`. Fallible operations return [`MatrixError`].
+//!
+//! `use rustices::prelude::*;` brings the commonly used items into scope.
+
+// Index loops mirror the textbook formulation of the numerical algorithms in this crate.
+#![allow(clippy::needless_range_loop)]
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 use std::cmp::{Eq, PartialEq};
-use std::fmt::{Display, Debug, Formatter, Result};
-use std::default::Default;
-use std::time::Instant;
 
-use rand::Rng;
-
-use num::Num;
+mod append_log;
+mod arena;
+mod atomic;
+#[cfg(feature = "autodiff")]
+mod autodiff;
+mod balance;
+mod binary;
+mod bit_matrix;
+mod block;
+mod broadcast;
+mod buffer;
+mod builder;
+mod cholesky;
+mod chunked;
+mod condition;
+mod constructors;
+mod diff;
+mod display;
+mod dual;
+mod eigen;
+mod einsum;
+mod element;
+mod error;
+mod exact;
+mod index;
+mod integer;
+mod interpolate;
+mod io;
+mod iter;
+mod kernel;
+mod krylov;
+mod lattice;
+mod layout;
+mod linear_model;
+mod low_rank;
+mod lu;
+mod map;
+mod matmul;
+mod mask;
+mod missing;
+#[cfg(unix)]
+mod mmap;
+mod modular;
+mod nmf;
+mod normal_form;
+mod normalize;
+mod orientation;
+mod overflow;
+mod pad;
+#[cfg(feature = "parallel")]
+mod par_ops;
+#[cfg(feature = "parallel")]
+mod par_sparse;
+mod parallel;
+mod precondition;
+mod probability;
+mod program;
+mod progress;
+#[cfg(feature = "property-testing")]
+mod property;
+mod qr;
+mod random;
+mod reduce;
+mod reflectors;
+mod resample;
+mod riccati;
+mod rolling;
+mod row_ops;
+mod schur;
+mod schur_form;
+mod shape;
+mod similarity;
+mod sign;
+mod small;
+mod smart;
+mod snapshot;
+mod solve;
+mod sort;
+mod sparse;
+mod sparse_cholesky;
+mod square;
+mod statistics;
+mod strassen;
+mod svd;
+mod sylvester;
+mod tensor;
+mod traits;
+mod update;
+mod view;
+
+pub use crate::append_log::MatrixLog;
+pub use crate::arena::{Arena, Frame};
+pub use crate::atomic::AtomicMatrix;
+#[cfg(feature = "autodiff")]
+pub use crate::autodiff::{Gradients, Tape, Var};
+pub use crate::balance::Balanced;
+pub use crate::bit_matrix::BitMatrix;
+pub use crate::block::BlockDiagonal;
+pub use crate::buffer::MatrixMut;
+pub use crate::builder::MatrixBuilder;
+pub use crate::cholesky::Cholesky;
+pub use crate::chunked::ChunkedMatrix;
+pub use crate::diff::{Difference, MatrixDiff};
+pub use crate::display::MatrixDisplay;
+pub use crate::dual::Dual;
+pub use crate::eigen::SymmetricEigen;
+pub use crate::element::MatrixElement;
+pub use crate::error::MatrixError;
+pub use crate::kernel::Kernel;
+pub use crate::linear_model::{fit_logistic_regression, LinearModel};
+pub use crate::krylov::{conjugate_gradient, gmres};
+pub use crate::low_rank::LowRank;
+pub use crate::lu::Lu;
+#[cfg(unix)]
+pub use crate::mmap::MappedMatrix;
+pub use crate::modular::ModInt;
+pub use crate::nmf::Nmf;
+pub use crate::normal_form::{HermiteNormalForm, SmithNormalForm};
+pub use crate::normalize::Norm;
+pub use crate::pad::PadMode;
+pub use crate::parallel::{RowChunkMut, RowChunksMut};
+pub use crate::precondition::{Identity, IncompleteCholesky, Ilu0, Jacobi, Preconditioner, Ssor};
+pub use crate::probability::ProbabilityMatrix;
+pub use crate::program::{Program, ProgramBuilder, Slot};
+pub use crate::progress::{Iteration, Solution, SolverOptions};
+#[cfg(feature = "property-testing")]
+pub use crate::property::{matrices, MatrixStrategy};
+pub use crate::qr::{PivotedQr, Qr};
+pub use crate::random::Normal;
+pub use crate::reflectors::{givens, householder_vector, Givens, Householder};
+pub use crate::resample::{Aggregation, Interp};
+pub use crate::schur_form::RealSchur;
+pub use crate::shape::Shape;
+pub use crate::sign::SpectralProjectors;
+pub use crate::small::SmallMatrix;
+pub use crate::smart::SmartMatrix;
+pub use crate::sparse::CsrMatrix;
+pub use crate::sparse_cholesky::SparseCholesky;
+pub use crate::square::SquareMatrix;
+pub use crate::statistics::Histogram;
+pub use crate::svd::Svd;
+pub use crate::tensor::{MatrixSlice, Tensor3, TensorAxis};
+pub use crate::traits::MatrixOps;
+pub use crate::view::{MatrixView, MatrixViewMut};
+
+/// The items most programs need: `use rustices::prelude::*;`.
+pub mod prelude {
+    pub use crate::{Axis, Matrix, MatrixElement, MatrixError, MatrixOps, Shape, SquareMatrix};
+}
 
+/// Selects which direction an operation runs in.
+///
+/// `Axis::Rows` processes each row independently (moving across its columns),
+/// while `Axis::Cols` processes each column independently (moving down its rows).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    Rows,
+    Cols
+}
 
-#[derive(Debug, Eq)]
-struct Matrix<T> {
+/// A dense `rows x cols` matrix.
+///
+/// Elements are stored in a single `Vec` in row-major order, so element
+/// `(row, col)` lives at index `row * cols + col`. Every constructor and
+/// operation maintains the invariant that the buffer holds exactly
+/// `rows * cols` elements.
+///
+/// [`Matrix::get`] and [`Matrix::set`] only check the flattened index against
+/// the buffer, so callers must keep `col < cols`: a column past the end of a row
+/// addresses the start of the next row instead of panicking.
+///
+/// Equality compares the elements in storage order only, so two matrices with
+/// the same elements laid out in different shapes (a `2x3` and a `3x2`, say)
+/// compare equal. Compare [`Matrix::shape`] as well when the shape matters.
+#[derive(Clone, Eq)]
+pub struct Matrix<T> {
     rows: usize,
     cols: usize,
     data: Vec<T>
 }
 
 impl<T> Matrix<T> {
-    fn from(data: Vec<Vec<T>>) -> Matrix<T> {
-        Matrix {
+    /// Builds a matrix from a list of rows, which must all have the same length.
+    ///
+    /// Panics on an empty list or ragged rows; see [`Matrix::try_from`].
+    pub fn from(data: Vec<Vec<T>>) -> Matrix<T> {
+        Matrix::try_from(data).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// [`Matrix::from`], reporting an empty list or ragged rows as an error.
+    pub fn try_from(data: Vec<Vec<T>>) -> std::result::Result<Matrix<T>, MatrixError> {
+        let cols = data.first().ok_or(MatrixError::EmptyInput)?.len();
+        if let Some((row, found)) = data.iter().map(Vec::len).enumerate().find(|&(_, len)| len != cols) {
+            return Err(MatrixError::RaggedRows { row, expected: cols, found });
+        }
+
+        Ok(Matrix {
             rows: data.len(),
-            cols: data[0].len(),
+            cols,
             data: data.into_iter().flatten().collect()
-        }
+        })
     }
 
-    fn new(rows: usize, cols: usize, value: T) -> Matrix<T>
+    /// A `rows x cols` matrix with every element set to `value`.
+    pub fn new(rows: usize, cols: usize, value: T) -> Matrix<T>
         where T: Clone
     {
         let mut data = vec![];
@@ -42,17 +235,25 @@ impl<T> Matrix<T> {
         }
     }
 
-    fn get(&self, row: usize, col: usize) -> &T {
+    pub fn nrows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &T {
         &self.data[row * self.cols + col]
     }
 
-    fn get_row(&self, row: usize) -> Vec<T> 
+    pub fn get_row(&self, row: usize) -> Vec<T> 
         where T: Clone
     {
         self.data[row * self.cols..(row + 1) * self.cols].to_vec()
     }
 
-    fn get_column(&self, column: usize) -> Vec<T>
+    pub fn get_column(&self, column: usize) -> Vec<T>
         where T: Clone
     {
         let mut output = vec![];
@@ -62,26 +263,70 @@ impl<T> Matrix<T> {
         output
     }
 
-    fn set(&mut self, row: usize, col: usize, value: T) {
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
         self.data[row * self.cols + col] = value;
     }
-}
 
-impl Matrix<i32> {
-    fn new_random(rows: usize, cols: usize, min: i32, max: i32) -> Matrix<i32> {
-        let mut rng = rand::thread_rng();
-        let mut data = vec![];
-        for _ in 0..rows * cols {
-            data.push(rng.gen_range(min..max));
+    /// How many independent lanes an `axis` operation processes: the number of
+    /// rows for `Axis::Rows` and of columns for `Axis::Cols`.
+    pub fn lane_count(&self, axis: Axis) -> usize {
+        match axis {
+            Axis::Rows => self.rows,
+            Axis::Cols => self.cols
         }
-        data.push(1);
+    }
 
-        Matrix {
-            rows,
-            cols,
-            data
+    /// Row `index` for `Axis::Rows`, or column `index` for `Axis::Cols`.
+    pub fn lane(&self, axis: Axis, index: usize) -> Vec<T>
+        where T: Clone
+    {
+        match axis {
+            Axis::Rows => self.get_row(index),
+            Axis::Cols => self.get_column(index)
         }
     }
+
+    /// The element-wise sum, or an error if the shapes differ.
+    ///
+    /// Like the `+` operator this only needs `T: Add`, not [`MatrixElement`],
+    /// so dimensioned quantities can be added.
+    pub fn checked_add(&self, rhs: &Matrix<T>) -> std::result::Result<Matrix<T>, MatrixError>
+        where T: Clone + Add<Output = T>
+    {
+        self.zip_map(rhs, |a, b| a.clone() + b.clone())
+    }
+
+    /// The element-wise difference, or an error if the shapes differ.
+    pub fn checked_sub(&self, rhs: &Matrix<T>) -> std::result::Result<Matrix<T>, MatrixError>
+        where T: Clone + Sub<Output = T>
+    {
+        self.zip_map(rhs, |a, b| a.clone() - b.clone())
+    }
+}
+
+impl<T: MatrixElement> Matrix<T> {
+    /// The product `self * rhs`, or an error if the inner dimensions differ.
+    pub fn checked_mul(&self, rhs: &Matrix<T>) -> std::result::Result<Matrix<T>, MatrixError> {
+        let shape = self.shape().matmul_with(rhs.shape())?;
+        Ok(Matrix {
+            rows: shape.rows,
+            cols: shape.cols,
+            data: matmul::blocked_product(self, rhs)
+        })
+    }
+
+    /// The element-wise (Hadamard) product, or an error if the shapes differ.
+    pub fn component_mul(&self, rhs: &Matrix<T>) -> std::result::Result<Matrix<T>, MatrixError> {
+        self.zip_map(rhs, |a, b| a.clone() * b.clone())
+    }
+
+    /// The element-wise quotient, or an error if the shapes differ.
+    ///
+    /// Division by a zero element follows `T`: floats give an infinity or NaN,
+    /// while integer types panic.
+    pub fn component_div(&self, rhs: &Matrix<T>) -> std::result::Result<Matrix<T>, MatrixError> {
+        self.zip_map(rhs, |a, b| a.clone() / b.clone())
+    }
 }
 
 impl<T: PartialEq> PartialEq for Matrix<T> {
@@ -90,34 +335,52 @@ impl<T: PartialEq> PartialEq for Matrix<T> {
     }
 }
 
-impl<T> Mul<Matrix<T>> for Matrix<T>
-    where T: Clone + Mul<Output = T> + Default + AddAssign + Copy
+impl<T: MatrixElement> Mul<Matrix<T>> for Matrix<T>
 {
     type Output = Self;
 
     fn mul(self, rhs: Matrix<T>) -> Self {
-        assert_eq!(self.rows, rhs.cols);
+        self.checked_mul(&rhs).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
 
-        let mut output_data = vec![];
+impl<T: MatrixElement> Mul<&Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
 
-        for i in 0..self.rows {
-            for j in 0..rhs.cols {
-                let mut total = T::default();
-                for k in 0..self.cols {
-                    total += self.get(i, k).clone() * rhs.get(k, j).clone();
-                }
-                output_data.push(total);
-            }
-        }
+    fn mul(self, rhs: &Matrix<T>) -> Matrix<T> {
+        self.checked_mul(rhs).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl<T: MatrixElement> Mul<Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Matrix<T>) -> Matrix<T> {
+        self * &rhs
+    }
+}
+
+impl<T: MatrixElement> Mul<&Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Matrix<T> {
+        &self * rhs
+    }
+}
+
+impl<T: Clone + Mul<Output = T>> Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: T) -> Matrix<T> {
         Matrix {
             rows: self.rows,
-            cols: rhs.cols,
-            data: output_data
+            cols: self.cols,
+            data: self.data.iter().map(|val| val.clone() * rhs.clone()).collect()
         }
     }
 }
 
-impl<T: Num + MulAssign + Copy> Mul<T> for Matrix<T>
+impl<T: Clone + Mul<Output = T>> Mul<T> for Matrix<T>
 {
     type Output = Self;
 
@@ -125,7 +388,7 @@ impl<T: Num + MulAssign + Copy> Mul<T> for Matrix<T>
         let mut data = vec![];
 
         for val in self.data.into_iter() {
-            data.push(val * rhs);
+            data.push(val * rhs.clone());
         }
 
         Matrix {
@@ -136,20 +399,87 @@ impl<T: Num + MulAssign + Copy> Mul<T> for Matrix<T>
     }
 }
 
-impl<T> Display for Matrix<T> 
-    where T: Display
-{
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result { 
-        for i in 0..self.rows {
-            for j in 0..self.cols {
-                write!(f, "{}, ", self.get(i, j))?;
+/// Implements an element-wise operator for every owned/borrowed combination of
+/// operands, plus its compound assignment form. Mismatched shapes panic with the
+/// message of the corresponding `checked_*` method's error.
+macro_rules! impl_elementwise_op {
+    ($op:ident, $method:ident, $checked:ident, $assign_op:ident, $assign_method:ident) => {
+        impl<T: Clone + $op<Output = T>> $op<&Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: &Matrix<T>) -> Matrix<T> {
+                self.$checked(rhs).unwrap_or_else(|error| panic!("{}", error))
+            }
+        }
+
+        impl<T: Clone + $op<Output = T>> $op<Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: Matrix<T>) -> Matrix<T> {
+                self.$method(&rhs)
             }
-            write!(f, "\n")?;
         }
-        write!(f, "Rows: {}, ", self.rows)?;
-        write!(f, "Columns: {}", self.cols)?;
 
-        Ok(())
+        impl<T: Clone + $op<Output = T>> $op<&Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(mut self, rhs: &Matrix<T>) -> Matrix<T> {
+                self.$assign_method(rhs);
+                self
+            }
+        }
+
+        impl<T: Clone + $op<Output = T>> $op<Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(mut self, rhs: Matrix<T>) -> Matrix<T> {
+                self.$assign_method(&rhs);
+                self
+            }
+        }
+
+        impl<T: Clone + $op<Output = T>> $assign_op<&Matrix<T>> for Matrix<T> {
+            fn $assign_method(&mut self, rhs: &Matrix<T>) {
+                if let Err(error) = self.shape().elementwise_with(rhs.shape()) {
+                    panic!("{}", error);
+                }
+                for (val, other) in self.data.iter_mut().zip(rhs.data.iter()) {
+                    *val = val.clone().$method(other.clone());
+                }
+            }
+        }
+
+        impl<T: Clone + $op<Output = T>> $assign_op<Matrix<T>> for Matrix<T> {
+            fn $assign_method(&mut self, rhs: Matrix<T>) {
+                self.$assign_method(&rhs);
+            }
+        }
+    };
+}
+
+impl_elementwise_op!(Add, add, checked_add, AddAssign, add_assign);
+impl_elementwise_op!(Sub, sub, checked_sub, SubAssign, sub_assign);
+
+impl<T: Clone + Neg<Output = T>> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Matrix<T> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().cloned().map(Neg::neg).collect()
+        }
+    }
+}
+
+impl<T: Clone + Neg<Output = T>> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(mut self) -> Matrix<T> {
+        for val in self.data.iter_mut() {
+            *val = -val.clone();
+        }
+        self
     }
 }
 
@@ -179,6 +509,13 @@ mod tests {
         assert_eq!(matrix.data, data_flattened);
     }
 
+    #[test]
+    fn shape_accessors() {
+        let matrix = Matrix::new(2, 5, 1.0);
+        assert_eq!((matrix.nrows(), matrix.ncols()), (2, 5));
+        assert_eq!(matrix.lane(Axis::Cols, 4), vec![1.0, 1.0]);
+    }
+
     #[test]
     fn multiply_matrices() {
         let matrix1 = Matrix::from(vec![
@@ -202,6 +539,29 @@ mod tests {
         assert_eq!(matrix1 * matrix2, expected_matrix);
     }
 
+    #[test]
+    fn multiply_borrowed_matrices() {
+        let a = Matrix::from(vec![
+            vec![1, 2],
+            vec![3, 4]
+        ]);
+        let b = Matrix::from(vec![
+            vec![0, 1],
+            vec![1, 0]
+        ]);
+
+        let expected_matrix = a.clone() * b.clone();
+        assert_eq!(&a * &b, expected_matrix);
+        assert_eq!(&a * b.clone(), expected_matrix);
+        assert_eq!(a.clone() * &b, expected_matrix);
+        assert_eq!(&a * 3, a.clone() * 3);
+
+        // Operands stay usable, so an expression can reuse them.
+        let squared = &a * &a;
+        assert_eq!(&squared - &a * &a, Matrix::new(2, 2, 0));
+        assert_eq!(&(&a + &b) * &a, &a * &a + &b * &a);
+    }
+
     #[test]
     fn scalar_multiply() {
         let matrix = Matrix::from(vec![
@@ -220,12 +580,99 @@ mod tests {
     }
 
     #[test]
-    fn random_matrix() {
-        let max = 10;
-        let random_matrix = Matrix::new_random(5, 5, 0, max);
+    fn add_and_subtract_matrices() {
+        let a = Matrix::from(vec![
+            vec![1, 2],
+            vec![3, 4]
+        ]);
+        let b = Matrix::from(vec![
+            vec![4, 3],
+            vec![2, 1]
+        ]);
+        let c = Matrix::from(vec![
+            vec![1, 0],
+            vec![0, 1]
+        ]);
+
+        assert_eq!(a.clone() + b.clone() - c.clone() * 2, Matrix::from(vec![
+            vec![3, 5],
+            vec![5, 3]
+        ]));
+        assert_eq!(&a + &b, Matrix::new(2, 2, 5));
+        assert_eq!(&a - b.clone(), Matrix::from(vec![vec![-3, -1], vec![1, 3]]));
+        assert_eq!(a.clone() - &a, Matrix::new(2, 2, 0));
+        assert_eq!(-&a + a.clone(), Matrix::new(2, 2, 0));
+        assert_eq!(-c, Matrix::from(vec![vec![-1, 0], vec![0, -1]]));
+
+        let mut total = a.clone();
+        total += &b;
+        total -= a;
+        total += b.clone();
+        assert_eq!(total, b * 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "incompatible dimensions: 2x2 and 1x2")]
+    fn add_mismatched_shapes_panics() {
+        let mut matrix = Matrix::new(2, 2, 1);
+        matrix += Matrix::new(1, 2, 1);
+    }
+
+    #[test]
+    fn fallible_construction() {
+        let empty: Vec<Vec<i32>> = vec![];
+        assert_eq!(Matrix::try_from(empty), Err(MatrixError::EmptyInput));
+
+        let ragged = vec![
+            vec![1, 2],
+            vec![3]
+        ];
+        assert_eq!(Matrix::try_from(ragged), Err(MatrixError::RaggedRows { row: 1, expected: 2, found: 1 }));
+
+        let matrix = Matrix::try_from(vec![vec![1, 2]]).unwrap();
+        assert_eq!(matrix.shape(), Shape::new(1, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 has length 1")]
+    fn from_ragged_rows_panics() {
+        Matrix::from(vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn checked_operations() {
+        let a = Matrix::from(vec![
+            vec![1, 2],
+            vec![3, 4]
+        ]);
+        let b = Matrix::from(vec![vec![1, 1]]);
+
+        assert_eq!(a.checked_mul(&a).unwrap(), a.clone() * a.clone());
+        assert_eq!(b.checked_mul(&a), Ok(Matrix::from(vec![vec![4, 6]])));
+        assert_eq!(a.checked_mul(&b), Err(MatrixError::DimensionMismatch { left: Shape::new(2, 2), right: Shape::new(1, 2) }));
+
+        assert_eq!(a.checked_add(&a), Ok(a.clone() * 2));
+        assert_eq!(a.checked_sub(&a), Ok(Matrix::new(2, 2, 0)));
+        assert!(a.checked_add(&b).is_err());
+    }
+
+    #[test]
+    fn component_operations() {
+        let a = Matrix::from(vec![
+            vec![1.0, 2.0],
+            vec![3.0, 4.0]
+        ]);
+        let b = Matrix::from(vec![
+            vec![2.0, 0.5],
+            vec![-1.0, 8.0]
+        ]);
 
-        assert_eq!(random_matrix.rows, 5);
-        assert_eq!(random_matrix.cols, 5);
-        assert!(random_matrix.data.iter().max().unwrap() < &max);
+        assert_eq!(a.component_mul(&b), Ok(Matrix::from(vec![vec![2.0, 1.0], vec![-3.0, 32.0]])));
+        assert_eq!(a.component_div(&b), Ok(Matrix::from(vec![vec![0.5, 4.0], vec![-3.0, 0.5]])));
+        assert!(a.component_div(&Matrix::new(2, 2, 0.0)).unwrap().iter().all(|val: &f64| val.is_infinite()));
+        assert_eq!(
+            a.component_mul(&Matrix::new(1, 2, 1.0)),
+            Err(MatrixError::DimensionMismatch { left: Shape::new(2, 2), right: Shape::new(1, 2) })
+        );
     }
 }
@@ -0,0 +1,460 @@
+use num::Float;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+use crate::progress::{Solution, SolverOptions};
+
+/// A fitted linear model `y ≈ x * coefficients + intercept`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearModel<T> {
+    /// One coefficient per feature, i.e. per column of the data matrix.
+    pub coefficients: Vec<T>,
+    pub intercept: T
+}
+
+impl<T: Float> LinearModel<T> {
+    /// The predictions for each row of `x`, which must have one column per coefficient.
+    pub fn predict(&self, x: &Matrix<T>) -> Result<Vec<T>, MatrixError> {
+        if x.cols != self.coefficients.len() {
+            return Err(MatrixError::DimensionMismatch {
+                left: x.shape(),
+                right: (self.coefficients.len(), 1).into()
+            });
+        }
+        Ok(x.data.chunks(x.cols.max(1))
+            .take(x.rows)
+            .map(|row| row.iter().zip(self.coefficients.iter()).fold(self.intercept, |total, (&a, &b)| total + a * b))
+            .collect())
+    }
+
+    /// The predicted probabilities `1 / (1 + e^-(x * coefficients + intercept))`
+    /// for each row of `x`, for a model from [`fit_logistic_regression`].
+    pub fn predict_probability(&self, x: &Matrix<T>) -> Result<Vec<T>, MatrixError> {
+        Ok(self.predict(x)?.into_iter().map(sigmoid).collect())
+    }
+}
+
+fn sigmoid<T: Float>(val: T) -> T {
+    T::one() / (T::one() + (-val).exp())
+}
+
+/// Fits a logistic regression of the binary outcomes `y` on the rows of `x`
+/// by iteratively reweighted least squares, i.e. Newton's method on the
+/// log-likelihood.
+///
+/// Each iteration solves the weighted least-squares problem for the working
+/// response with [`Matrix::lstsq_weighted`], and reports the relative change
+/// in deviance `|D - D_prev| / (|D| + 0.1)`, the criterion R's `glm` uses, to
+/// the progress callback. Newton's method converges quadratically, so a few
+/// iterations usually suffice; if the classes are perfectly separable the
+/// coefficients grow without bound instead.
+///
+/// The returned [`Solution`] records whether the tolerance was met; features
+/// that are collinear give [`MatrixError::Singular`]. Outcomes may also be
+/// fractions in `[0, 1]`, such as observed proportions.
+///
+/// Panics if an outcome lies outside `[0, 1]`.
+pub fn fit_logistic_regression<T: Float>(
+    x: &Matrix<T>,
+    y: &[T],
+    options: &mut SolverOptions
+) -> Result<Solution<LinearModel<T>>, MatrixError> {
+    if y.len() != x.rows {
+        return Err(MatrixError::DimensionMismatch {
+            left: x.shape(),
+            right: (y.len(), 1).into()
+        });
+    }
+    if x.rows == 0 {
+        return Err(MatrixError::EmptyInput);
+    }
+    assert!(y.iter().all(|&val| val >= T::zero() && val <= T::one()), "outcomes must lie in [0, 1]");
+
+    let design = Matrix::from_fn(x.rows, x.cols + 1, |i, j| if j == 0 { T::one() } else { *x.get(i, j - 1) });
+    let deviance = |probabilities: &[T]| {
+        let log_likelihood = probabilities.iter().zip(y.iter()).fold(T::zero(), |total, (&p, &val)| {
+            let mut total = total;
+            if val > T::zero() {
+                total = total + val * p.ln();
+            }
+            if val < T::one() {
+                total = total + (T::one() - val) * (T::one() - p).ln();
+            }
+            total
+        });
+        -(log_likelihood + log_likelihood)
+    };
+    let model = |beta: &[T]| LinearModel { coefficients: beta[1..].to_vec(), intercept: beta[0] };
+
+    let mut beta = vec![T::zero(); x.cols + 1];
+    let mut probabilities = vec![T::from(0.5).unwrap(); x.rows];
+    let mut previous = deviance(&probabilities);
+    let tolerance = T::from(options.tolerance).unwrap();
+    let mut change = T::infinity();
+
+    for iteration in 0..options.max_iterations {
+        let mut weights = Vec::with_capacity(x.rows);
+        let mut working = Matrix::new(x.rows, 1, T::zero());
+        for i in 0..x.rows {
+            let p = probabilities[i];
+            // Fitted probabilities of exactly 0 or 1 would give a zero weight.
+            let weight = (p * (T::one() - p)).max(T::epsilon());
+            let eta = (0..design.cols).fold(T::zero(), |total, j| total + *design.get(i, j) * beta[j]);
+            working.set(i, 0, eta + (y[i] - p) / weight);
+            weights.push(weight);
+        }
+        beta = design.lstsq_weighted(&working, &weights)?.data;
+
+        probabilities = model(&beta).predict_probability(x)?;
+        let current = deviance(&probabilities);
+        change = (current - previous).abs() / (current.abs() + T::from(0.1).unwrap());
+        previous = current;
+
+        if options.report(iteration, change.to_f64().unwrap()).is_break() {
+            return Err(MatrixError::Cancelled { iterations: iteration + 1 });
+        }
+        if change <= tolerance {
+            return Ok(Solution {
+                value: model(&beta),
+                converged: true,
+                iterations: iteration + 1,
+                residual: change.to_f64().unwrap()
+            });
+        }
+    }
+    Ok(Solution {
+        value: model(&beta),
+        converged: false,
+        iterations: options.max_iterations,
+        residual: change.to_f64().unwrap()
+    })
+}
+
+/// A data matrix and targets with the column means subtracted, so the intercept
+/// drops out of the fit and is never penalized.
+struct Centered<T> {
+    x: Matrix<T>,
+    y: Vec<T>,
+    x_means: Vec<T>,
+    y_mean: T
+}
+
+impl<T: Float> Centered<T> {
+    fn new(x: &Matrix<T>, y: &[T]) -> Result<Centered<T>, MatrixError> {
+        if y.len() != x.rows {
+            return Err(MatrixError::DimensionMismatch {
+                left: x.shape(),
+                right: (y.len(), 1).into()
+            });
+        }
+        if x.rows == 0 {
+            return Err(MatrixError::EmptyInput);
+        }
+
+        let n = T::from(x.rows).unwrap();
+        let x_means: Vec<T> = (0..x.cols)
+            .map(|j| (0..x.rows).fold(T::zero(), |total, i| total + *x.get(i, j)) / n)
+            .collect();
+        let y_mean = y.iter().fold(T::zero(), |total, &val| total + val) / n;
+
+        let mut centered = x.clone();
+        for row in centered.data.chunks_mut(x.cols.max(1)) {
+            for (val, &mean) in row.iter_mut().zip(x_means.iter()) {
+                *val = *val - mean;
+            }
+        }
+        Ok(Centered {
+            x: centered,
+            y: y.iter().map(|&val| val - y_mean).collect(),
+            x_means,
+            y_mean
+        })
+    }
+
+    fn model(&self, coefficients: Vec<T>) -> LinearModel<T> {
+        let intercept = coefficients.iter()
+            .zip(self.x_means.iter())
+            .fold(self.y_mean, |total, (&coefficient, &mean)| total - coefficient * mean);
+        LinearModel {
+            coefficients,
+            intercept
+        }
+    }
+}
+
+fn soft_threshold<T: Float>(val: T, threshold: T) -> T {
+    if val > threshold {
+        val - threshold
+    } else if val < -threshold {
+        val + threshold
+    } else {
+        T::zero()
+    }
+}
+
+/// Linear models fitted to a data matrix with one row per sample and one column
+/// per feature. Every fit includes an unpenalized intercept, and the penalized
+/// objectives are scaled by the number of samples `n`, as in glmnet and
+/// scikit-learn, so `lambda` means the same thing whatever the sample size.
+impl<T: Float> Matrix<T> {
+    /// The ordinary least-squares fit of `y`, by [`Matrix::lstsq`] on the
+    /// centered data.
+    pub fn linear_regression(&self, y: &[T]) -> Result<LinearModel<T>, MatrixError> {
+        let centered = Centered::new(self, y)?;
+        let targets = Matrix { rows: y.len(), cols: 1, data: centered.y.clone() };
+        let coefficients = centered.x.lstsq(&targets)?.data;
+        Ok(centered.model(coefficients))
+    }
+
+    /// Ridge regression, minimizing `‖y - x * β - b‖² / 2n + lambda * ‖β‖² / 2`.
+    ///
+    /// Solved in closed form from `(xᵀx + n * lambda * I) β = xᵀy` by Cholesky.
+    /// Any positive `lambda` makes the system positive definite, so unlike
+    /// [`Matrix::linear_regression`] this copes with collinear features.
+    ///
+    /// Panics if `lambda` is negative.
+    pub fn ridge(&self, y: &[T], lambda: T) -> Result<LinearModel<T>, MatrixError> {
+        assert!(lambda >= T::zero(), "the ridge penalty must not be negative");
+        let centered = Centered::new(self, y)?;
+        let x = &centered.x;
+
+        let shift = T::from(self.rows).unwrap() * lambda;
+        let mut gram = Matrix::new(self.cols, self.cols, T::zero());
+        let mut moments = Matrix::new(self.cols, 1, T::zero());
+        for j in 0..self.cols {
+            for k in 0..=j {
+                let val = (0..self.rows).fold(T::zero(), |total, i| total + *x.get(i, j) * *x.get(i, k));
+                gram.set(j, k, if j == k { val + shift } else { val });
+            }
+            let val = (0..self.rows).fold(T::zero(), |total, i| total + *x.get(i, j) * centered.y[i]);
+            moments.set(j, 0, val);
+        }
+        let coefficients = gram.cholesky()?.solve(&moments)?.data;
+        Ok(centered.model(coefficients))
+    }
+
+    /// The Lasso, minimizing `‖y - x * β - b‖² / 2n + lambda * ‖β‖₁`, which drives
+    /// the coefficients of unhelpful features to exactly zero.
+    ///
+    /// This is [`Matrix::elastic_net`] with `l1_ratio` one.
+    pub fn lasso(&self, y: &[T], lambda: T, options: &mut SolverOptions) -> Result<Solution<LinearModel<T>>, MatrixError> {
+        self.elastic_net(y, lambda, T::one(), options)
+    }
+
+    /// The elastic net, minimizing
+    /// `‖y - x * β - b‖² / 2n + lambda * (l1_ratio * ‖β‖₁ + (1 - l1_ratio) * ‖β‖² / 2)`,
+    /// which blends the sparsity of the Lasso with the stability of ridge
+    /// regression among correlated features.
+    ///
+    /// Solved by cyclic coordinate descent. Each sweep over the features reports
+    /// the largest change in a coefficient, scaled by the spread of its feature
+    /// and of `y`, to the progress callback, and the fit stops once that falls
+    /// to the tolerance. As with [`fit_logistic_regression`], the returned
+    /// [`Solution`] records whether it did before the sweeps ran out.
+    ///
+    /// Panics if `lambda` is negative or `l1_ratio` is outside `[0, 1]`.
+    pub fn elastic_net(&self, y: &[T], lambda: T, l1_ratio: T, options: &mut SolverOptions) -> Result<Solution<LinearModel<T>>, MatrixError> {
+        assert!(lambda >= T::zero(), "the penalty must not be negative");
+        assert!(l1_ratio >= T::zero() && l1_ratio <= T::one(), "the L1 ratio must lie in [0, 1]");
+        let centered = Centered::new(self, y)?;
+        let x = &centered.x;
+        let n = T::from(self.rows).unwrap();
+        let l1 = lambda * l1_ratio;
+        let l2 = lambda * (T::one() - l1_ratio);
+
+        let variances: Vec<T> = (0..self.cols)
+            .map(|j| (0..self.rows).fold(T::zero(), |total, i| total + *x.get(i, j) * *x.get(i, j)) / n)
+            .collect();
+        let mut coefficients = vec![T::zero(); self.cols];
+        let mut residual = centered.y.clone();
+        let scale = (residual.iter().fold(T::zero(), |total, &val| total + val * val) / n).sqrt();
+        if scale.is_zero() {
+            return Ok(Solution {
+                value: centered.model(coefficients),
+                converged: true,
+                iterations: 0,
+                residual: 0.0
+            });
+        }
+        let tolerance = T::from(options.tolerance).unwrap();
+        let mut change = T::infinity();
+
+        for sweep in 0..options.max_iterations {
+            let mut largest_change = T::zero();
+            for j in 0..self.cols {
+                let denominator = variances[j] + l2;
+                if denominator.is_zero() {
+                    continue;
+                }
+                let old = coefficients[j];
+                // The correlation of feature j with the residual that leaves it out.
+                let correlation = (0..self.rows)
+                    .fold(T::zero(), |total, i| total + *x.get(i, j) * residual[i]) / n
+                    + variances[j] * old;
+                let new = soft_threshold(correlation, l1) / denominator;
+                if new != old {
+                    for i in 0..self.rows {
+                        residual[i] = residual[i] - *x.get(i, j) * (new - old);
+                    }
+                    coefficients[j] = new;
+                    largest_change = largest_change.max((new - old).abs() * variances[j].sqrt());
+                }
+            }
+
+            change = largest_change / scale;
+            if options.report(sweep, change.to_f64().unwrap()).is_break() {
+                return Err(MatrixError::Cancelled { iterations: sweep + 1 });
+            }
+            if change <= tolerance {
+                return Ok(Solution {
+                    value: centered.model(coefficients),
+                    converged: true,
+                    iterations: sweep + 1,
+                    residual: change.to_f64().unwrap()
+                });
+            }
+        }
+        Ok(Solution {
+            value: centered.model(coefficients),
+            converged: false,
+            iterations: options.max_iterations,
+            residual: change.to_f64().unwrap()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    /// Two centered, orthogonal features with unit variance, for which the
+    /// penalized fits have closed forms.
+    fn orthogonal_design() -> (Matrix<f64>, Vec<f64>) {
+        let x = Matrix::from(vec![
+            vec![11.0, 1.0],
+            vec![9.0, 1.0],
+            vec![11.0, -1.0],
+            vec![9.0, -1.0]
+        ]);
+        let y = vec![5.0, 2.0, 4.0, 1.0];
+        (x, y)
+    }
+
+    #[test]
+    fn ordinary_and_ridge_regression() {
+        let (x, y) = orthogonal_design();
+        // xᵀy / n is 1.5 and 0.5 for the centered columns, and y has mean 3.
+        let ols = x.linear_regression(&y).unwrap();
+        assert_close(ols.coefficients[0], 1.5);
+        assert_close(ols.coefficients[1], 0.5);
+        assert_close(ols.intercept, 3.0 - 15.0);
+        let predictions = ols.predict(&x).unwrap();
+        assert!(predictions.iter().zip(y.iter()).all(|(p, t)| (p - t).abs() < 1e-9));
+
+        let ridge = x.ridge(&y, 1.0).unwrap();
+        assert_close(ridge.coefficients[0], 0.75);
+        assert_close(ridge.coefficients[1], 0.25);
+        assert_close(ridge.intercept, 3.0 - 7.5);
+
+        // Collinear features are fine with a penalty, but not without one.
+        let collinear = Matrix::from_fn(5, 2, |i, j| (i * (j + 1)) as f64);
+        let targets = [0.0, 1.0, 2.0, 3.0, 4.5];
+        assert!(collinear.ridge(&targets, 0.1).is_ok());
+        assert_eq!(collinear.linear_regression(&targets), Err(MatrixError::Singular));
+    }
+
+    #[test]
+    fn lasso_and_elastic_net_soft_threshold() {
+        let (x, y) = orthogonal_design();
+
+        let lasso = x.lasso(&y, 0.75, &mut SolverOptions::default()).unwrap().value;
+        assert_close(lasso.coefficients[0], 0.75);
+        assert_eq!(lasso.coefficients[1], 0.0);
+
+        let net = x.elastic_net(&y, 0.4, 0.5, &mut SolverOptions::default()).unwrap().value;
+        assert_close(net.coefficients[0], 1.3 / 1.2);
+        assert_close(net.coefficients[1], 0.3 / 1.2);
+
+        // A large enough penalty zeroes every coefficient, leaving the mean.
+        let empty = x.lasso(&y, 10.0, &mut SolverOptions::default()).unwrap().value;
+        assert_eq!(empty.coefficients, vec![0.0, 0.0]);
+        assert_close(empty.intercept, 3.0);
+    }
+
+    #[test]
+    fn elastic_net_without_l1_matches_ridge() {
+        let x = Matrix::from(vec![
+            vec![1.0, 2.0, 0.5],
+            vec![2.0, 1.0, 1.5],
+            vec![3.0, 3.5, 0.0],
+            vec![4.0, 3.0, 2.0],
+            vec![5.0, 6.0, 1.0],
+            vec![6.0, 5.0, 3.0]
+        ]);
+        let y = [1.0, 2.5, 2.0, 4.0, 4.5, 6.5];
+
+        let ridge = x.ridge(&y, 0.3).unwrap();
+        let net = x.elastic_net(&y, 0.3, 0.0, &mut SolverOptions::default().tolerance(1e-13)).unwrap().value;
+        for (a, b) in ridge.coefficients.iter().zip(net.coefficients.iter()) {
+            assert!((a - b).abs() < 1e-8, "{} != {}", a, b);
+        }
+        assert!((ridge.intercept - net.intercept).abs() < 1e-8);
+    }
+
+    #[test]
+    fn logistic_regression_by_irls() {
+        // Hours studied and whether the exam was passed.
+        let hours = Matrix::from(vec![
+            vec![0.5], vec![0.75], vec![1.0], vec![1.25], vec![1.5], vec![1.75], vec![1.75],
+            vec![2.0], vec![2.25], vec![2.5], vec![2.75], vec![3.0], vec![3.25], vec![3.5],
+            vec![4.0], vec![4.25], vec![4.5], vec![4.75], vec![5.0], vec![5.5]
+        ]);
+        let passed = [
+            0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0,
+            1.0, 0.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0
+        ];
+
+        let solution = fit_logistic_regression(&hours, &passed, &mut SolverOptions::default()).unwrap();
+        assert!(solution.converged && solution.iterations < 10, "{:?}", solution);
+        let model = solution.value;
+        // The published maximum likelihood estimates for this data set.
+        assert!((model.intercept + 4.0777).abs() < 1e-4 && (model.coefficients[0] - 1.5046).abs() < 1e-4, "{:?}", model);
+
+        // The score equations xᵀ(y - p) = 0 hold at the optimum.
+        let probabilities = model.predict_probability(&hours).unwrap();
+        let residuals: Vec<f64> = passed.iter().zip(probabilities.iter()).map(|(y, p)| y - p).collect();
+        assert!(residuals.iter().sum::<f64>().abs() < 1e-8);
+        assert!((0..20).map(|i| hours.get(i, 0) * residuals[i]).sum::<f64>().abs() < 1e-8);
+
+        let mut options = SolverOptions::default().max_iterations(1);
+        let partial = fit_logistic_regression(&hours, &passed, &mut options).unwrap();
+        assert!(!partial.converged);
+        assert_eq!(partial.into_result(), Err(MatrixError::NoConvergence { iterations: 1 }));
+        assert!(matches!(
+            fit_logistic_regression(&hours, &passed[..5], &mut SolverOptions::default()),
+            Err(MatrixError::DimensionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn linear_model_errors() {
+        let (x, y) = orthogonal_design();
+        assert!(matches!(x.ridge(&y[..3], 1.0), Err(MatrixError::DimensionMismatch { .. })));
+        assert!(matches!(x.linear_regression(&y).unwrap().predict(&Matrix::new(2, 3, 1.0)), Err(MatrixError::DimensionMismatch { .. })));
+
+        let x = Matrix::from(vec![
+            vec![1.0, 2.0, 0.5],
+            vec![2.0, 1.0, 1.5],
+            vec![3.0, 3.5, 0.0],
+            vec![4.0, 3.0, 2.0]
+        ]);
+        let mut options = SolverOptions::default().max_iterations(1);
+        let partial = x.lasso(&[1.0, 3.0, 2.0, 5.0], 0.01, &mut options).unwrap();
+        assert!(!partial.converged && partial.residual > 0.0);
+        assert_eq!(partial.into_result(), Err(MatrixError::NoConvergence { iterations: 1 }));
+    }
+}
@@ -0,0 +1,140 @@
+use std::fmt::Debug;
+
+use num::Float;
+
+use crate::Matrix;
+use crate::shape::Shape;
+use crate::traits::MatrixOps;
+
+/// A rank-`k` matrix stored as its factors `u * diag(s) * vt`.
+///
+/// An `m x n` matrix of rank `k` takes `k * (m + n + 1)` values instead of
+/// `m * n`, and a matrix-vector product costs the same.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LowRank<T> {
+    /// `m x k`, with orthonormal columns.
+    pub u: Matrix<T>,
+    pub s: Vec<T>,
+    /// `k x n`, with orthonormal rows.
+    pub vt: Matrix<T>
+}
+
+impl<T: Float + Debug> LowRank<T> {
+    pub fn rank(&self) -> usize {
+        self.s.len()
+    }
+
+    /// Multiplies the factors back into a dense matrix.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let mut scaled = self.u.clone();
+        for i in 0..scaled.rows {
+            for (k, sigma) in self.s.iter().enumerate() {
+                let val = *scaled.get(i, k) * *sigma;
+                scaled.set(i, k, val);
+            }
+        }
+        scaled * &self.vt
+    }
+}
+
+impl<T: Float + Debug> MatrixOps<T> for LowRank<T> {
+    fn shape(&self) -> Shape {
+        Shape::new(self.u.rows, self.vt.cols)
+    }
+
+    fn element(&self, row: usize, col: usize) -> T {
+        (0..self.rank()).fold(T::zero(), |total, k| total + *self.u.get(row, k) * self.s[k] * *self.vt.get(k, col))
+    }
+
+    fn row_entries(&self, row: usize) -> Box<dyn Iterator<Item = (usize, T)> + '_> {
+        Box::new((0..self.vt.cols).map(move |col| (col, self.element(row, col))))
+    }
+
+    /// `u * (s .* (vt * x))`, in `O(k * (m + n))` operations.
+    fn matvec(&self, x: &[T]) -> Vec<T> {
+        let projected: Vec<T> = self.vt.matvec(x).iter().zip(self.s.iter()).map(|(&val, &sigma)| val * sigma).collect();
+        self.u.matvec(&projected)
+    }
+}
+
+impl<T: Float + Debug> Matrix<T> {
+    /// The best rank-`rank` approximation in the Frobenius and spectral norms,
+    /// from the truncated SVD (Eckart-Young), together with its relative
+    /// Frobenius error `‖self - approx‖ / ‖self‖`.
+    ///
+    /// A `rank` beyond the matrix's dimensions keeps every singular value.
+    pub fn low_rank_approx(&self, rank: usize) -> (LowRank<T>, T) {
+        let svd = self.svd();
+        let rank = rank.min(svd.singular_values.len());
+
+        let total = svd.singular_values.iter().fold(T::zero(), |total, &sigma| total + sigma * sigma);
+        let discarded = svd.singular_values[rank..].iter().fold(T::zero(), |total, &sigma| total + sigma * sigma);
+        let relative_error = if total == T::zero() { T::zero() } else { (discarded / total).sqrt() };
+
+        let mut u = Matrix::new(self.rows, rank, T::zero());
+        let mut vt = Matrix::new(rank, self.cols, T::zero());
+        for k in 0..rank {
+            for i in 0..self.rows {
+                u.set(i, k, *svd.u.get(i, k));
+            }
+            for j in 0..self.cols {
+                vt.set(k, j, *svd.v.get(j, k));
+            }
+        }
+
+        let approximation = LowRank {
+            u,
+            s: svd.singular_values[..rank].to_vec(),
+            vt
+        };
+        (approximation, relative_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frobenius(matrix: &Matrix<f64>) -> f64 {
+        matrix.data.iter().map(|val| val * val).sum::<f64>().sqrt()
+    }
+
+    #[test]
+    fn low_rank_approximation_error() {
+        let matrix = Matrix::from(vec![
+            vec![4.0, 0.0, 1.0, 2.0],
+            vec![1.0, 3.0, 0.0, 1.0],
+            vec![2.0, 1.0, 5.0, 0.0]
+        ]);
+
+        let (approximation, relative_error) = matrix.low_rank_approx(2);
+        assert_eq!(approximation.rank(), 2);
+        assert_eq!(approximation.shape(), Shape::new(3, 4));
+
+        let residual = &matrix - &approximation.to_matrix();
+        assert!((frobenius(&residual) / frobenius(&matrix) - relative_error).abs() < 1e-12);
+        assert!(relative_error > 0.0 && relative_error < 0.5);
+
+        let (full, error) = matrix.low_rank_approx(10);
+        assert_eq!(full.rank(), 3);
+        assert!(error.abs() < 1e-15);
+        assert!(frobenius(&(&matrix - &full.to_matrix())) < 1e-12);
+    }
+
+    #[test]
+    fn low_rank_matvec() {
+        let u = Matrix::from(vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![1.0, 1.0]]);
+        let v = Matrix::from(vec![vec![1.0, 2.0, 0.0, 1.0], vec![0.0, 1.0, 3.0, 1.0]]);
+        let matrix = &u * &v;
+
+        let (approximation, relative_error) = matrix.low_rank_approx(2);
+        assert!(relative_error < 1e-12);
+
+        let x = [1.0, -1.0, 2.0, 0.5];
+        let expected = matrix.matvec(&x);
+        for (val, expected) in approximation.matvec(&x).iter().zip(expected.iter()) {
+            assert!((val - expected).abs() < 1e-12);
+        }
+        assert!((approximation.element(2, 2) - 3.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,381 @@
+use num::Float;
+
+use crate::Matrix;
+use crate::condition::inverse_one_norm_estimate;
+use crate::error::MatrixError;
+use crate::sign::one_norm;
+use crate::sort::nan_first_cmp;
+
+/// An LU decomposition with partial pivoting, `p * a = l * u`.
+///
+/// `l` is unit lower triangular and `u` upper triangular; both are stored
+/// together in one matrix, with the unit diagonal of `l` implied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lu<T> {
+    factors: Matrix<T>,
+    permutation: Vec<usize>,
+    odd_permutation: bool,
+    norm: T
+}
+
+impl<T: Float> Matrix<T> {
+    /// Factorizes a square matrix by Gaussian elimination with partial pivoting.
+    ///
+    /// A singular matrix still has a decomposition, with a zero on the diagonal
+    /// of `u`; it is reported by the operations that need an invertible matrix.
+    pub fn lu(&self) -> Result<Lu<T>, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        let n = self.rows;
+        let mut factors = self.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut odd_permutation = false;
+
+        for k in 0..n {
+            // NaN ranks lowest, so it only becomes the pivot when nothing else is left.
+            let pivot_row = (k..n)
+                .max_by(|&p, &q| nan_first_cmp(&factors.get(p, k).abs(), &factors.get(q, k).abs()))
+                .unwrap();
+            if pivot_row != k {
+                for j in 0..n {
+                    factors.data.swap(k * n + j, pivot_row * n + j);
+                }
+                permutation.swap(k, pivot_row);
+                odd_permutation = !odd_permutation;
+            }
+
+            let pivot = *factors.get(k, k);
+            if pivot.is_zero() {
+                continue;
+            }
+            for i in k + 1..n {
+                let factor = *factors.get(i, k) / pivot;
+                factors.set(i, k, factor);
+                for j in k + 1..n {
+                    let val = *factors.get(i, j) - factor * *factors.get(k, j);
+                    factors.set(i, j, val);
+                }
+            }
+        }
+
+        Ok(Lu {
+            factors,
+            permutation,
+            odd_permutation,
+            norm: one_norm(self)
+        })
+    }
+}
+
+impl<T: Float> Lu<T> {
+    /// The unit lower triangular factor.
+    pub fn l(&self) -> Matrix<T> {
+        let n = self.factors.rows;
+        let mut l = Matrix::new(n, n, T::zero());
+        for i in 0..n {
+            for j in 0..i {
+                l.set(i, j, *self.factors.get(i, j));
+            }
+            l.set(i, i, T::one());
+        }
+        l
+    }
+
+    /// The upper triangular factor.
+    pub fn u(&self) -> Matrix<T> {
+        let n = self.factors.rows;
+        let mut u = Matrix::new(n, n, T::zero());
+        for i in 0..n {
+            for j in i..n {
+                u.set(i, j, *self.factors.get(i, j));
+            }
+        }
+        u
+    }
+
+    /// Row `i` of `p * a` is row `permutation()[i]` of `a`.
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn is_singular(&self) -> bool {
+        (0..self.factors.rows).any(|i| self.factors.get(i, i).is_zero())
+    }
+
+    /// The product of the pivots, negated for an odd number of row swaps.
+    pub fn determinant(&self) -> T {
+        let product = (0..self.factors.rows).fold(T::one(), |total, i| total * *self.factors.get(i, i));
+        if self.odd_permutation { -product } else { product }
+    }
+
+    /// The sign of the determinant and the natural log of its absolute value.
+    ///
+    /// The log is a sum over the pivots, so it stays finite where the product in
+    /// [`Lu::determinant`] would overflow or underflow. A singular matrix gives
+    /// `(0, -∞)`.
+    pub fn sign_and_log_det(&self) -> (T, T) {
+        let n = self.factors.rows;
+        if self.is_singular() {
+            return (T::zero(), T::neg_infinity());
+        }
+        let negative_pivots = (0..n).filter(|&i| self.factors.get(i, i).is_sign_negative()).count();
+        let log_det = (0..n).fold(T::zero(), |total, i| total + self.factors.get(i, i).abs().ln());
+        let sign = if (negative_pivots % 2 == 1) != self.odd_permutation { -T::one() } else { T::one() };
+        (sign, log_det)
+    }
+
+    /// Solves `a * x = b` for every column of `b` by forward and back substitution.
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let n = self.factors.rows;
+        if b.rows != n {
+            return Err(MatrixError::DimensionMismatch {
+                left: self.factors.shape(),
+                right: b.shape()
+            });
+        }
+        if self.is_singular() {
+            return Err(MatrixError::Singular);
+        }
+
+        let mut x = Matrix::new(n, b.cols, T::zero());
+        for i in 0..n {
+            for j in 0..b.cols {
+                x.set(i, j, *b.get(self.permutation[i], j));
+            }
+        }
+        for j in 0..b.cols {
+            for i in 0..n {
+                let val = (0..i).fold(*x.get(i, j), |val, k| val - *self.factors.get(i, k) * *x.get(k, j));
+                x.set(i, j, val);
+            }
+            for i in (0..n).rev() {
+                let val = (i + 1..n).fold(*x.get(i, j), |val, k| val - *self.factors.get(i, k) * *x.get(k, j));
+                x.set(i, j, val / *self.factors.get(i, i));
+            }
+        }
+        Ok(x)
+    }
+
+    /// An estimate of the reciprocal condition number `1 / (‖a‖₁ ‖a⁻¹‖₁)`.
+    ///
+    /// `‖a⁻¹‖₁` is estimated from a few solves with the factors, as LAPACK's
+    /// `xGECON` does, so this costs `O(n²)` rather than the `O(n³)` of an
+    /// inverse or SVD. Values near `T::epsilon()` mean a solve may have lost
+    /// all its accuracy; a singular matrix gives zero.
+    pub fn rcond(&self) -> T {
+        let n = self.factors.rows;
+        if n == 0 {
+            return T::one();
+        }
+        if self.is_singular() || self.norm.is_zero() {
+            return T::zero();
+        }
+
+        let solve = |x: &[T]| {
+            let b = Matrix { rows: n, cols: 1, data: x.to_vec() };
+            self.solve(&b).unwrap().data
+        };
+        let inverse_norm = inverse_one_norm_estimate(n, solve, |x| self.solve_transpose(x));
+        T::one() / (self.norm * inverse_norm)
+    }
+
+    /// Solves `aᵀ * x = b` for a single right-hand side. Since `aᵀ = uᵀ * lᵀ * p`,
+    /// this substitutes forward through `uᵀ`, back through `lᵀ`, then unpermutes.
+    fn solve_transpose(&self, b: &[T]) -> Vec<T> {
+        let n = self.factors.rows;
+        let mut w = b.to_vec();
+        for i in 0..n {
+            let val = (0..i).fold(w[i], |val, k| val - *self.factors.get(k, i) * w[k]);
+            w[i] = val / *self.factors.get(i, i);
+        }
+        for i in (0..n).rev() {
+            w[i] = (i + 1..n).fold(w[i], |val, k| val - *self.factors.get(k, i) * w[k]);
+        }
+
+        let mut x = vec![T::zero(); n];
+        for (i, &row) in self.permutation.iter().enumerate() {
+            x[row] = w[i];
+        }
+        x
+    }
+}
+
+/// Determinant, inverse and rank for the floating-point element types.
+///
+/// These are concrete impls, like the exact versions for `i64` and `BigInt`,
+/// because a generic `Float` impl would overlap with them.
+macro_rules! impl_float_lu_queries {
+    ($($t:ty),*) => {
+        $(
+            impl Matrix<$t> {
+                /// The determinant from the LU decomposition.
+                pub fn determinant(&self) -> Result<$t, MatrixError> {
+                    Ok(self.lu()?.determinant())
+                }
+
+                /// The sign of the determinant and the natural log of its absolute
+                /// value, from the LU decomposition; see [`Lu::sign_and_log_det`].
+                pub fn sign_and_log_det(&self) -> Result<($t, $t), MatrixError> {
+                    Ok(self.lu()?.sign_and_log_det())
+                }
+
+                /// The inverse, or [`MatrixError::Singular`] if a pivot is exactly zero.
+                pub fn inverse(&self) -> Result<Matrix<$t>, MatrixError> {
+                    let lu = self.lu()?;
+                    lu.solve(&Matrix::identity(self.rows))
+                }
+
+                /// The numerical rank.
+                ///
+                /// Elimination pivots on a square LU are not a reliable rank test once
+                /// rounding is involved, so this counts the significant diagonal
+                /// entries of the column-pivoted QR instead, which also handles
+                /// rectangular matrices.
+                pub fn rank(&self) -> usize {
+                    self.qr_pivoted().rank
+                }
+            }
+        )*
+    };
+}
+
+impl_float_lu_queries!(f64, f32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>) {
+        for (x, y) in a.data.iter().zip(b.data.iter()) {
+            assert!((x - y).abs() < 1e-12, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn lu_reconstructs_permuted_matrix() {
+        let matrix = Matrix::from(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 10.0]
+        ]);
+
+        let lu = matrix.lu().unwrap();
+
+        let mut permuted = Matrix::new(3, 3, 0.0);
+        for (i, &row) in lu.permutation().iter().enumerate() {
+            for j in 0..3 {
+                permuted.set(i, j, *matrix.get(row, j));
+            }
+        }
+        assert_close(&(lu.l() * lu.u()), &permuted);
+        assert_eq!(lu.permutation()[0], 2);
+        assert!((lu.determinant() + 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn float_determinant_and_inverse() {
+        let matrix: Matrix<f64> = Matrix::from(vec![
+            vec![4.0, 7.0],
+            vec![2.0, 6.0]
+        ]);
+        assert!((matrix.determinant().unwrap() - 10.0).abs() < 1e-12);
+
+        let inverse = matrix.inverse().unwrap();
+        assert_close(&inverse, &Matrix::from(vec![
+            vec![0.6, -0.7],
+            vec![-0.2, 0.4]
+        ]));
+        assert_close(&(&matrix * &inverse), &Matrix::from(vec![vec![1.0, 0.0], vec![0.0, 1.0]]));
+
+        let single: Matrix<f32> = Matrix::from(vec![
+            vec![0.0, 2.0],
+            vec![3.0, 0.0]
+        ]);
+        assert_eq!(single.determinant(), Ok(-6.0));
+        assert_eq!(single.inverse().unwrap().get_row(0), vec![0.0, 1.0 / 3.0]);
+    }
+
+    #[test]
+    fn singular_and_rectangular_errors() {
+        let singular: Matrix<f64> = Matrix::from(vec![
+            vec![1.0, 2.0],
+            vec![2.0, 4.0]
+        ]);
+        assert_eq!(singular.determinant(), Ok(0.0));
+        assert_eq!(singular.inverse(), Err(MatrixError::Singular));
+        assert_eq!(singular.rank(), 1);
+
+        let rectangular: Matrix<f64> = Matrix::new(2, 3, 1.0);
+        assert_eq!(rectangular.determinant(), Err(MatrixError::NotSquare { rows: 2, cols: 3 }));
+        assert_eq!(rectangular.rank(), 1);
+        assert_eq!(Matrix::new(3, 2, 0.0f64).rank(), 0);
+    }
+
+    #[test]
+    fn nan_entries_do_not_panic() {
+        let matrix: Matrix<f64> = Matrix::from(vec![
+            vec![f64::NAN, 1.0],
+            vec![2.0, 3.0]
+        ]);
+
+        let lu = matrix.lu().unwrap();
+        // The finite entry is chosen as the first pivot.
+        assert_eq!(lu.u().get(0, 0), &2.0);
+        assert!(matrix.determinant().unwrap().is_nan());
+    }
+
+    #[test]
+    fn log_determinant_beyond_float_range() {
+        // det = -(10^200)^2 overflows, but its log is modest.
+        let matrix: Matrix<f64> = Matrix::from(vec![
+            vec![0.0, 1e200, 0.0],
+            vec![1e200, 0.0, 0.0],
+            vec![0.0, 0.0, 1.0]
+        ]);
+        assert_eq!(matrix.determinant(), Ok(f64::NEG_INFINITY));
+        let (sign, log_det) = matrix.sign_and_log_det().unwrap();
+        assert_eq!(sign, -1.0);
+        assert!((log_det - 400.0 * 10f64.ln()).abs() < 1e-10);
+
+        let small: Matrix<f64> = Matrix::from(vec![
+            vec![2.0, 1.0],
+            vec![-1.0, -3.0]
+        ]);
+        let (sign, log_det) = small.sign_and_log_det().unwrap();
+        assert_eq!(sign, -1.0);
+        assert!((log_det - 5.0f64.ln()).abs() < 1e-12);
+
+        let singular: Matrix<f64> = Matrix::new(2, 2, 1.0);
+        assert_eq!(singular.sign_and_log_det(), Ok((0.0, f64::NEG_INFINITY)));
+    }
+
+    #[test]
+    fn rcond_tracks_conditioning() {
+        let matrix: Matrix<f64> = Matrix::from(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 10.0]
+        ]);
+        let inverse = matrix.inverse().unwrap();
+        let exact = 1.0 / (one_norm(&matrix) * one_norm(&inverse));
+
+        let rcond = matrix.lu().unwrap().rcond();
+        assert!(rcond >= exact * (1.0 - 1e-12) && rcond <= 3.0 * exact, "{} vs {}", rcond, exact);
+
+        let transpose = matrix.transpose().lu().unwrap();
+        let b = [1.0, -2.0, 0.5];
+        let x = transpose.solve_transpose(&b);
+        let residual = &matrix * &Matrix::from(vec![vec![x[0]], vec![x[1]], vec![x[2]]]);
+        assert!(residual.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+
+        // The Hilbert matrix is notoriously ill-conditioned: κ₁ ≈ 9.4e5 at size 5.
+        let hilbert = Matrix::from((0..5).map(|i| (0..5).map(|j| 1.0 / (i + j + 1) as f64).collect()).collect());
+        let rcond = hilbert.lu().unwrap().rcond();
+        assert!(rcond > 1.0 / 9.5e5 && rcond < 3.0 / 9.4e5, "{}", rcond);
+
+        let singular = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+        assert_eq!(singular.lu().unwrap().rcond(), 0.0);
+    }
+}
@@ -0,0 +1,248 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::Matrix;
+use crate::error::MatrixError;
+
+impl<T> Matrix<T> {
+    /// Builds a new matrix of the same shape by applying `f` to every element.
+    pub fn map<U, F>(&self, f: F) -> Matrix<U>
+        where F: FnMut(&T) -> U
+    {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(f).collect()
+        }
+    }
+
+    /// Transforms every element in place, e.g.
+    /// `matrix.apply(|val| *val = val.max(0.0))` for a ReLU.
+    pub fn apply<F>(&mut self, f: F)
+        where F: FnMut(&mut T)
+    {
+        self.data.iter_mut().for_each(f);
+    }
+
+    /// Builds a new matrix by applying `f` to each pair of elements at the same
+    /// position in `self` and `other`, or an error if their shapes differ.
+    pub fn zip_map<U, V, F>(&self, other: &Matrix<U>, mut f: F) -> Result<Matrix<V>, MatrixError>
+        where F: FnMut(&T, &U) -> V
+    {
+        let shape = self.shape().elementwise_with(other.shape())?;
+        Ok(Matrix {
+            rows: shape.rows,
+            cols: shape.cols,
+            data: self.data.iter().zip(other.data.iter()).map(|(a, b)| f(a, b)).collect()
+        })
+    }
+
+    /// Builds a new matrix from the result of applying `f` to each row.
+    ///
+    /// `f` may return rows of a different length than its input, e.g. to append
+    /// derived features, but every returned row must have the same length.
+    pub fn map_rows<U, F>(&self, f: F) -> Result<Matrix<U>, MatrixError>
+        where F: Fn(&[T]) -> Vec<U>
+    {
+        let mut data = vec![];
+        let mut cols = None;
+        for i in 0..self.rows {
+            let row = f(&self.data[i * self.cols..(i + 1) * self.cols]);
+            let expected = *cols.get_or_insert(row.len());
+            if row.len() != expected {
+                return Err(MatrixError::RaggedRows { row: i, expected, found: row.len() });
+            }
+            data.extend(row);
+        }
+
+        Ok(Matrix {
+            rows: self.rows,
+            cols: cols.unwrap_or(0),
+            data
+        })
+    }
+
+    /// Builds a new matrix from the result of applying `f` to each column.
+    ///
+    /// As with [`Matrix::map_rows`] the columns may change length, but must all agree.
+    pub fn map_columns<U, F>(&self, f: F) -> Result<Matrix<U>, MatrixError>
+        where T: Clone,
+              F: Fn(&[T]) -> Vec<U>
+    {
+        let mut columns: Vec<Vec<U>> = vec![];
+        for j in 0..self.cols {
+            let column = f(&self.get_column(j));
+            if let Some(first) = columns.first() {
+                let expected = first.len();
+                if column.len() != expected {
+                    return Err(MatrixError::RaggedColumns { column: j, expected, found: column.len() });
+                }
+            }
+            columns.push(column);
+        }
+
+        let rows = columns.first().map_or(0, Vec::len);
+        let mut iters: Vec<_> = columns.into_iter().map(Vec::into_iter).collect();
+        let mut data = vec![];
+        for _ in 0..rows {
+            for column in iters.iter_mut() {
+                data.push(column.next().unwrap());
+            }
+        }
+
+        Ok(Matrix {
+            rows,
+            cols: self.cols,
+            data
+        })
+    }
+}
+
+/// Scalar counterparts of the element-wise operators, broadcasting the scalar
+/// to every element. Scalar multiplication is the `Mul<T>` operator, or
+/// [`Matrix::scale`] when the scalar has a different type.
+impl<T: Clone> Matrix<T> {
+    pub fn add_scalar(&self, scalar: T) -> Matrix<T>
+        where T: Add<Output = T>
+    {
+        self.map(|val| val.clone() + scalar.clone())
+    }
+
+    pub fn sub_scalar(&self, scalar: T) -> Matrix<T>
+        where T: Sub<Output = T>
+    {
+        self.map(|val| val.clone() - scalar.clone())
+    }
+
+    /// Division by zero follows `T`, as for [`Matrix::component_div`].
+    pub fn div_scalar(&self, scalar: T) -> Matrix<T>
+        where T: Div<Output = T>
+    {
+        self.map(|val| val.clone() / scalar.clone())
+    }
+
+    /// Multiplies every element by `factor`, which may have a different type
+    /// than the elements and change their type, e.g. a matrix of lengths
+    /// times a plain `f64`, or times a length to give a matrix of areas.
+    ///
+    /// The `*` operator can't offer this: a blanket `Mul<S>` impl would
+    /// overlap the matrix-matrix product.
+    pub fn scale<S, U>(&self, factor: S) -> Matrix<U>
+        where S: Clone,
+              T: Mul<S, Output = U>
+    {
+        self.map(|val| val.clone() * factor.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_and_apply() {
+        let mut matrix: Matrix<f64> = Matrix::from(vec![
+            vec![-1.5, 2.0],
+            vec![0.5, -3.0]
+        ]);
+
+        let signs = matrix.map(|&val| val > 0.0);
+        assert_eq!(signs, Matrix::from(vec![vec![false, true], vec![true, false]]));
+        assert_eq!(signs.shape(), matrix.shape());
+
+        matrix.apply(|val| *val = val.max(0.0));
+        assert_eq!(matrix, Matrix::from(vec![vec![0.0, 2.0], vec![0.5, 0.0]]));
+    }
+
+    #[test]
+    fn scalar_operations() {
+        let matrix = Matrix::from(vec![
+            vec![2, 4],
+            vec![6, 9]
+        ]);
+
+        assert_eq!(matrix.add_scalar(1), Matrix::from(vec![vec![3, 5], vec![7, 10]]));
+        assert_eq!(matrix.sub_scalar(2), Matrix::from(vec![vec![0, 2], vec![4, 7]]));
+        assert_eq!(matrix.div_scalar(2), Matrix::from(vec![vec![1, 2], vec![3, 4]]));
+        assert_eq!(matrix.add_scalar(1).sub_scalar(1), matrix);
+    }
+
+    #[test]
+    fn zip_map_combines_elements() {
+        let values = Matrix::from(vec![
+            vec![1.0, 2.0],
+            vec![3.0, 4.0]
+        ]);
+        let mask = Matrix::from(vec![
+            vec![true, false],
+            vec![false, true]
+        ]);
+
+        let masked = values.zip_map(&mask, |&val, &keep| if keep { val } else { 0.0 }).unwrap();
+        assert_eq!(masked, Matrix::from(vec![vec![1.0, 0.0], vec![0.0, 4.0]]));
+
+        let comparison = values.zip_map(&masked, |a, b| a == b).unwrap();
+        assert_eq!(comparison, mask);
+
+        let wide = Matrix::new(1, 4, true);
+        assert_eq!(
+            values.zip_map(&wide, |&val, _| val),
+            Err(MatrixError::DimensionMismatch { left: values.shape(), right: wide.shape() })
+        );
+    }
+
+    #[test]
+    fn map_rows_appends_features() {
+        let matrix = Matrix::from(vec![
+            vec![1, 2],
+            vec![3, 4]
+        ]);
+
+        let with_sum = matrix.map_rows(|row| {
+            let mut output = row.to_vec();
+            output.push(row.iter().sum());
+            output
+        }).unwrap();
+
+        let expected_matrix = Matrix::from(vec![
+            vec![1, 2, 3],
+            vec![3, 4, 7]
+        ]);
+        assert_eq!(with_sum.cols, 3);
+        assert_eq!(with_sum, expected_matrix);
+    }
+
+    #[test]
+    fn map_columns_changes_length() {
+        let matrix = Matrix::from(vec![
+            vec![1, 10],
+            vec![2, 20],
+            vec![3, 30]
+        ]);
+
+        let summary = matrix.map_columns(|column| vec![column[0], column[column.len() - 1]]).unwrap();
+
+        assert_eq!((summary.rows, summary.cols), (2, 2));
+        assert_eq!(summary, Matrix::from(vec![vec![1, 10], vec![3, 30]]));
+    }
+
+    #[test]
+    fn map_rows_rejects_ragged_output() {
+        let matrix = Matrix::from(vec![
+            vec![1],
+            vec![2]
+        ]);
+
+        let result = matrix.map_rows(|row| vec![row[0]; row[0] as usize]);
+        assert_eq!(result, Err(MatrixError::RaggedRows { row: 1, expected: 1, found: 2 }));
+    }
+
+    #[test]
+    fn map_columns_rejects_ragged_output() {
+        let matrix = Matrix::from(vec![
+            vec![1, 2]
+        ]);
+
+        let result = matrix.map_columns(|column| vec![column[0]; column[0] as usize]);
+        assert_eq!(result, Err(MatrixError::RaggedColumns { column: 1, expected: 1, found: 2 }));
+    }
+}
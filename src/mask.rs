@@ -0,0 +1,113 @@
+use crate::Matrix;
+use crate::error::MatrixError;
+
+impl<T> Matrix<T> {
+    /// Sets every element whose entry in `mask` is `true` to `value`.
+    ///
+    /// Returns the number of elements written.
+    pub fn set_where(&mut self, mask: &Matrix<bool>, value: T) -> Result<usize, MatrixError>
+        where T: Clone
+    {
+        self.shape().elementwise_with(mask.shape())?;
+
+        let mut count = 0;
+        for (val, &selected) in self.data.iter_mut().zip(mask.data.iter()) {
+            if selected {
+                *val = value.clone();
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Replaces every element satisfying `predicate` with `f` applied to it.
+    ///
+    /// Returns the number of elements written.
+    pub fn set_where_fn<P, F>(&mut self, predicate: P, f: F) -> usize
+        where P: Fn(&T) -> bool, F: Fn(&T) -> T
+    {
+        let mut count = 0;
+        for val in self.data.iter_mut() {
+            if predicate(val) {
+                *val = f(val);
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// The `(row, col)` positions of the elements satisfying `predicate`, in row-major order.
+    pub fn positions<P>(&self, predicate: P) -> Vec<(usize, usize)>
+        where P: Fn(&T) -> bool
+    {
+        self.data.iter()
+            .enumerate()
+            .filter(|(_, val)| predicate(val))
+            .map(|(index, _)| (index / self.cols, index % self.cols))
+            .collect()
+    }
+
+    /// Replaces every element equal to `old` with `new`, returning how many were replaced.
+    ///
+    /// NaN never compares equal to itself, so use [`Matrix::set_where_fn`] with
+    /// `is_nan` to patch NaN sentinels.
+    pub fn replace_all(&mut self, old: &T, new: T) -> usize
+        where T: PartialEq + Clone
+    {
+        self.set_where_fn(|val| val == old, |_| new.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Shape;
+
+    #[test]
+    fn set_where_mask() {
+        let mut matrix = Matrix::from(vec![
+            vec![1, 2],
+            vec![3, 4]
+        ]);
+        let mask = Matrix::from(vec![
+            vec![true, false],
+            vec![false, true]
+        ]);
+
+        assert_eq!(matrix.set_where(&mask, 0), Ok(2));
+        assert_eq!(matrix.data, vec![0, 2, 3, 0]);
+
+        let wrong_shape = Matrix::new(1, 2, true);
+        assert_eq!(matrix.set_where(&wrong_shape, 0), Err(MatrixError::DimensionMismatch {
+            left: Shape::new(2, 2),
+            right: Shape::new(1, 2)
+        }));
+    }
+
+    #[test]
+    fn set_where_predicate() {
+        let mut matrix = Matrix::from(vec![
+            vec![-1.5, 2.0],
+            vec![3.0, -4.0]
+        ]);
+
+        let clipped = matrix.set_where_fn(|&x| x < 0.0, |&x| -x);
+
+        assert_eq!(clipped, 2);
+        assert_eq!(matrix.data, vec![1.5, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn find_and_replace_sentinels() {
+        let mut matrix = Matrix::from(vec![
+            vec![1, -999, 3],
+            vec![-999, 5, 6]
+        ]);
+
+        assert_eq!(matrix.positions(|&x| x == -999), vec![(0, 1), (1, 0)]);
+        assert_eq!(matrix.replace_all(&-999, 0), 2);
+        assert_eq!(matrix.data, vec![1, 0, 3, 0, 5, 6]);
+        assert_eq!(matrix.replace_all(&-999, 0), 0);
+        assert!(matrix.positions(|&x| x > 10).is_empty());
+    }
+}
@@ -0,0 +1,100 @@
+use num::Float;
+
+use crate::{Axis, Matrix};
+
+fn nan_mean<T: Float, I: Iterator<Item = T>>(values: I) -> T {
+    let (total, count) = values
+        .filter(|val| !val.is_nan())
+        .fold((T::zero(), 0), |(total, count), val| (total + val, count + 1));
+    if count == 0 {
+        T::nan()
+    } else {
+        total / T::from(count).unwrap()
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// The sum of all elements, skipping NaN.
+    pub fn nan_sum(&self) -> T {
+        self.data.iter().filter(|val| !val.is_nan()).fold(T::zero(), |total, &val| total + val)
+    }
+
+    /// The mean of each row (`Axis::Rows`) or column (`Axis::Cols`), skipping NaN.
+    ///
+    /// A row or column with no numbers at all has a NaN mean.
+    pub fn nan_mean_axis(&self, axis: Axis) -> Vec<T> {
+        (0..self.lane_count(axis))
+            .map(|index| nan_mean(self.lane(axis, index).into_iter()))
+            .collect()
+    }
+
+    /// Converts NaN elements to `None` and everything else to `Some`.
+    pub fn to_options(&self) -> Matrix<Option<T>> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(|&val| if val.is_nan() { None } else { Some(val) }).collect()
+        }
+    }
+}
+
+impl<T: Float> Matrix<Option<T>> {
+    /// Replaces each missing element with the mean of the present elements in its column.
+    ///
+    /// A column with no present elements is filled with NaN.
+    pub fn fill_missing_with_column_mean(&self) -> Matrix<T> {
+        let means: Vec<T> = (0..self.cols)
+            .map(|j| nan_mean((0..self.rows).map(|i| self.get(i, j).unwrap_or_else(T::nan))))
+            .collect();
+
+        let mut data = Vec::with_capacity(self.data.len());
+        for (index, val) in self.data.iter().enumerate() {
+            data.push(val.unwrap_or(means[index % self.cols]));
+        }
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nan_aware_reductions() {
+        let matrix = Matrix::from(vec![
+            vec![1.0, f64::NAN, 3.0],
+            vec![f64::NAN, f64::NAN, 6.0]
+        ]);
+
+        assert_eq!(matrix.nan_sum(), 10.0);
+        assert_eq!(matrix.nan_mean_axis(Axis::Rows), vec![2.0, 6.0]);
+
+        let column_means = matrix.nan_mean_axis(Axis::Cols);
+        assert_eq!(column_means[0], 1.0);
+        assert!(column_means[1].is_nan());
+        assert_eq!(column_means[2], 4.5);
+    }
+
+    #[test]
+    fn impute_missing_values() {
+        let matrix = Matrix::from(vec![
+            vec![Some(1.0), None],
+            vec![None, Some(4.0)],
+            vec![Some(3.0), Some(8.0)]
+        ]);
+
+        let expected_matrix = Matrix::from(vec![
+            vec![1.0, 6.0],
+            vec![2.0, 4.0],
+            vec![3.0, 8.0]
+        ]);
+        assert_eq!(matrix.fill_missing_with_column_mean(), expected_matrix);
+
+        let with_nan = Matrix::from(vec![vec![f64::NAN, 2.0]]);
+        assert_eq!(with_nan.to_options().data, vec![None, Some(2.0)]);
+    }
+}
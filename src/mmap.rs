@@ -0,0 +1,173 @@
+use std::fs::File;
+use std::io;
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+use std::{ptr, slice};
+
+use crate::Matrix;
+use crate::binary::{self, ELEMENT_LEN, HEADER_LEN};
+use crate::shape::Shape;
+use crate::traits::MatrixOps;
+
+/// A read-only matrix of `f64` whose elements live in a memory-mapped file.
+///
+/// Opening does no reading or copying; pages are loaded by the operating system
+/// as elements are touched. See [`Matrix::open_mmap`] for why the file must not
+/// change while it is mapped.
+pub struct MappedMatrix {
+    address: *mut libc::c_void,
+    len: usize,
+    rows: usize,
+    cols: usize
+}
+
+impl Matrix<f64> {
+    /// Maps a file written by [`Matrix::write_binary`] as a read-only matrix.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other process,
+    /// for as long as the returned [`MappedMatrix`] is alive. Changes would show
+    /// through the supposedly immutable slice, and reading a page cut off by
+    /// truncation raises `SIGBUS`.
+    pub unsafe fn open_mmap<P: AsRef<Path>>(path: P) -> io::Result<MappedMatrix> {
+        if cfg!(target_endian = "big") {
+            return Err(io::Error::other("matrix files are little-endian"));
+        }
+
+        let mut file = File::open(path)?;
+        let (rows, cols) = binary::read_header(&mut file)?;
+        // The header is untrusted, so a size that overflows must not wrap around
+        // into one that passes the truncation check below.
+        let required = rows.checked_mul(cols)
+            .and_then(|count| (count as u64).checked_mul(ELEMENT_LEN))
+            .and_then(|bytes| bytes.checked_add(HEADER_LEN))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "matrix dimensions are too large"))?;
+        let len = file.metadata()?.len();
+        if len < required {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "matrix file is truncated"));
+        }
+
+        // SAFETY: the file is open for reading and `len` is its current length, which
+        // is at least the 24 byte header and so non-zero. The mapping is private and
+        // read-only and outlives `file`, which may be closed.
+        let address = unsafe {
+            libc::mmap(ptr::null_mut(), len as usize, libc::PROT_READ, libc::MAP_PRIVATE, file.as_raw_fd(), 0)
+        };
+        if address == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(MappedMatrix {
+            address,
+            len: len as usize,
+            rows,
+            cols
+        })
+    }
+}
+
+// SAFETY: the mapping is read-only and owned by the `MappedMatrix`, so sharing or
+// moving it between threads is as safe as doing so with a `&[f64]` or `Box<[f64]>`.
+unsafe impl Send for MappedMatrix {}
+unsafe impl Sync for MappedMatrix {}
+
+impl MappedMatrix {
+    /// The elements in row-major order.
+    pub fn as_slice(&self) -> &[f64] {
+        // SAFETY: the mapping starts page-aligned, so the elements after the 24 byte
+        // header are 8-byte aligned. `open_mmap` computed `HEADER_LEN + rows * cols * 8`
+        // without overflow and checked it is at most the mapped length, so all
+        // `rows * cols` elements lie inside the mapping. The caller of `open_mmap`
+        // guaranteed the file isn't changed while mapped, so the slice is immutable.
+        unsafe {
+            let data = (self.address as *const u8).add(HEADER_LEN as usize) as *const f64;
+            slice::from_raw_parts(data, self.rows * self.cols)
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> f64 {
+        assert!(row < self.rows && col < self.cols);
+        self.as_slice()[row * self.cols + col]
+    }
+
+    /// Copies the elements into an owned [`Matrix`].
+    pub fn to_matrix(&self) -> Matrix<f64> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.as_slice().to_vec()
+        }
+    }
+}
+
+impl MatrixOps<f64> for MappedMatrix {
+    fn shape(&self) -> Shape {
+        Shape::new(self.rows, self.cols)
+    }
+
+    fn element(&self, row: usize, col: usize) -> f64 {
+        self.get(row, col)
+    }
+
+    fn row_entries(&self, row: usize) -> Box<dyn Iterator<Item = (usize, f64)> + '_> {
+        Box::new(self.as_slice()[row * self.cols..(row + 1) * self.cols].iter().cloned().enumerate())
+    }
+}
+
+impl Drop for MappedMatrix {
+    fn drop(&mut self) {
+        // SAFETY: `address` and `len` describe a mapping created in `open_mmap` that
+        // has not been unmapped, and no borrows of it outlive `self`.
+        unsafe {
+            libc::munmap(self.address, self.len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::TempPath;
+
+    #[test]
+    fn mapped_matrix_reads_file() {
+        let path = TempPath::new("mapped_matrix_reads_file");
+        let matrix = Matrix::from(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0]
+        ]);
+        matrix.write_binary(&path.0).unwrap();
+
+        // SAFETY: the file is private to this test and isn't modified while mapped.
+        let mapped = unsafe { Matrix::open_mmap(&path.0) }.unwrap();
+
+        assert_eq!(mapped.shape(), Shape::new(2, 3));
+        assert_eq!(mapped.get(1, 0), 4.0);
+        assert_eq!(mapped.as_slice(), &matrix.data[..]);
+        assert_eq!(mapped.matvec(&[1.0, 0.0, -1.0]), vec![-2.0, -2.0]);
+        assert_eq!(mapped.to_matrix(), matrix);
+    }
+
+    #[test]
+    fn mapped_matrix_rejects_truncated_file() {
+        let path = TempPath::new("mapped_matrix_truncated");
+        Matrix::new(4, 4, 1.0).write_binary(&path.0).unwrap();
+        let file = std::fs::OpenOptions::new().write(true).open(&path.0).unwrap();
+        file.set_len(HEADER_LEN + ELEMENT_LEN).unwrap();
+
+        assert!(unsafe { Matrix::open_mmap(&path.0) }.is_err());
+    }
+
+    #[test]
+    fn mapped_matrix_rejects_overflowing_header() {
+        let path = TempPath::new("mapped_matrix_overflow");
+        // 2^61 elements of 8 bytes wrap a 64-bit size around to just the header.
+        let mut file = std::fs::File::create(&path.0).unwrap();
+        binary::write_header(&mut file, 1 << 61, 1).unwrap();
+        drop(file);
+
+        let error = unsafe { Matrix::open_mmap(&path.0) }.err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
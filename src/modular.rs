@@ -0,0 +1,317 @@
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub};
+
+use num::{Integer, Num, One, Zero};
+
+use crate::Matrix;
+
+/// An element of the integers modulo `P`, which form the finite field `GF(P)` when `P` is prime.
+///
+/// Division relies on Fermat's little theorem, so `P` must be prime for
+/// [`Div`] and the elimination-based matrix routines to be meaningful.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ModInt<const P: u64> {
+    value: u64
+}
+
+impl<const P: u64> ModInt<P> {
+    pub fn new(value: i64) -> ModInt<P> {
+        ModInt {
+            value: value.rem_euclid(P as i64) as u64
+        }
+    }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn pow(&self, exp: u64) -> ModInt<P> {
+        let mut result = ModInt::one();
+        let mut base = *self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// The multiplicative inverse, or `None` for zero.
+    pub fn inverse(&self) -> Option<ModInt<P>> {
+        if self.value == 0 {
+            None
+        } else {
+            Some(self.pow(P - 2))
+        }
+    }
+}
+
+impl<const P: u64> Display for ModInt<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<const P: u64> Add for ModInt<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        ModInt {
+            value: ((self.value as u128 + rhs.value as u128) % P as u128) as u64
+        }
+    }
+}
+
+impl<const P: u64> Sub for ModInt<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<const P: u64> Neg for ModInt<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        ModInt {
+            value: (P - self.value) % P
+        }
+    }
+}
+
+impl<const P: u64> Mul for ModInt<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        ModInt {
+            value: ((self.value as u128 * rhs.value as u128) % P as u128) as u64
+        }
+    }
+}
+
+impl<const P: u64> Div for ModInt<P> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inverse().expect("division by zero in GF(P)")
+    }
+}
+
+impl<const P: u64> Rem for ModInt<P> {
+    type Output = Self;
+
+    // Every nonzero element is a unit in a field, so division is always exact.
+    fn rem(self, rhs: Self) -> Self {
+        assert!(!rhs.is_zero(), "division by zero in GF(P)");
+        ModInt::zero()
+    }
+}
+
+impl<const P: u64> AddAssign for ModInt<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u64> MulAssign for ModInt<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u64> Zero for ModInt<P> {
+    fn zero() -> Self {
+        ModInt { value: 0 }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+impl<const P: u64> One for ModInt<P> {
+    fn one() -> Self {
+        ModInt { value: 1 % P }
+    }
+}
+
+impl<const P: u64> Num for ModInt<P> {
+    type FromStrRadixErr = std::num::ParseIntError;
+
+    fn from_str_radix(s: &str, radix: u32) -> std::result::Result<Self, Self::FromStrRadixErr> {
+        i64::from_str_radix(s, radix).map(ModInt::new)
+    }
+}
+
+impl<T: Integer + Copy> Matrix<T> {
+    /// Raises a square matrix to the power `exp` with every entry reduced modulo `modulus`.
+    ///
+    /// Uses exponentiation by squaring, so the cost is `O(n^3 log exp)`. Entries are
+    /// multiplied before being reduced, so `modulus * modulus * n` must fit in `T`.
+    pub fn pow_mod(&self, exp: u64, modulus: T) -> Matrix<T> {
+        assert_eq!(self.rows, self.cols);
+        assert!(modulus > T::zero());
+
+        let n = self.rows;
+        let mut result = Matrix::new(n, n, T::zero());
+        for i in 0..n {
+            result.set(i, i, T::one().mod_floor(&modulus));
+        }
+
+        let mut base = Matrix {
+            rows: n,
+            cols: n,
+            data: self.data.iter().map(|val| val.mod_floor(&modulus)).collect()
+        };
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul_mod(&base, modulus);
+            }
+            base = base.mul_mod(&base, modulus);
+            exp >>= 1;
+        }
+        result
+    }
+
+    fn mul_mod(&self, rhs: &Matrix<T>, modulus: T) -> Matrix<T> {
+        let mut data = vec![];
+        for i in 0..self.rows {
+            for j in 0..rhs.cols {
+                let mut total = T::zero();
+                for k in 0..self.cols {
+                    total = (total + *self.get(i, k) * *rhs.get(k, j)).mod_floor(&modulus);
+                }
+                data.push(total);
+            }
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: rhs.cols,
+            data
+        }
+    }
+}
+
+impl<const P: u64> Matrix<ModInt<P>> {
+    pub fn determinant(&self) -> ModInt<P> {
+        assert_eq!(self.rows, self.cols);
+
+        let (_, pivots, determinant) = self.exact_rref();
+        if pivots.len() < self.rows {
+            ModInt::zero()
+        } else {
+            determinant
+        }
+    }
+
+    /// The inverse over `GF(P)`, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Matrix<ModInt<P>>> {
+        let identity = Matrix::identity(self.rows);
+        self.exact_solve(&identity)
+    }
+
+    /// Solves `self * x = b` over `GF(P)` for each column of `b`, or `None` if `self` is singular.
+    pub fn solve(&self, b: &Matrix<ModInt<P>>) -> Option<Matrix<ModInt<P>>> {
+        self.exact_solve(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gf7(data: Vec<Vec<i64>>) -> Matrix<ModInt<7>> {
+        Matrix::from(data.into_iter().map(|row| row.into_iter().map(ModInt::new).collect()).collect())
+    }
+
+    #[test]
+    fn mod_int_arithmetic() {
+        let a = ModInt::<7>::new(5);
+        let b = ModInt::<7>::new(-3);
+
+        assert_eq!(b.value(), 4);
+        assert_eq!((a + b).value(), 2);
+        assert_eq!((a - b).value(), 1);
+        assert_eq!((a * b).value(), 6);
+        assert_eq!((a / b) * b, a);
+        assert_eq!(ModInt::<7>::zero().inverse(), None);
+    }
+
+    #[test]
+    fn gf_determinant_and_inverse() {
+        let matrix = gf7(vec![
+            vec![1, 2],
+            vec![3, 4]
+        ]);
+
+        // det = -2 = 5 (mod 7)
+        assert_eq!(matrix.determinant().value(), 5);
+
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(matrix.clone() * inverse, gf7(vec![vec![1, 0], vec![0, 1]]));
+
+        let singular = gf7(vec![
+            vec![1, 2],
+            vec![2, 4]
+        ]);
+        assert_eq!(singular.determinant().value(), 0);
+        assert!(singular.inverse().is_none());
+    }
+
+    #[test]
+    fn gf_rref_and_solve() {
+        let matrix = gf7(vec![
+            vec![2, 4, 1],
+            vec![1, 2, 0]
+        ]);
+
+        let (reduced, pivots) = matrix.rref();
+        assert_eq!(pivots, vec![0, 2]);
+        assert_eq!(reduced, gf7(vec![vec![1, 2, 0], vec![0, 0, 1]]));
+
+        let a = gf7(vec![
+            vec![3, 1],
+            vec![1, 4]
+        ]);
+        let b = gf7(vec![
+            vec![1],
+            vec![0]
+        ]);
+        let x = a.solve(&b).unwrap();
+        assert_eq!(a * x, b);
+    }
+
+    #[test]
+    fn fibonacci_by_matrix_power() {
+        let matrix: Matrix<u64> = Matrix::from(vec![
+            vec![1, 1],
+            vec![1, 0]
+        ]);
+
+        // F(90) = 2880067194370816120, which fits in a u64.
+        let modulus = 1_000_000_007;
+        let result = matrix.pow_mod(90, modulus);
+        assert_eq!(*result.get(0, 1), 2880067194370816120 % modulus);
+
+        let result = matrix.pow_mod(10, 1000);
+        assert_eq!(*result.get(0, 1), 55);
+    }
+
+    #[test]
+    fn pow_mod_zero_is_identity() {
+        let matrix = Matrix::from(vec![
+            vec![-4, 7],
+            vec![2, 9]
+        ]);
+
+        assert_eq!(matrix.pow_mod(0, 5), Matrix::from(vec![vec![1, 0], vec![0, 1]]));
+        assert_eq!(matrix.pow_mod(1, 5), Matrix::from(vec![vec![1, 2], vec![2, 4]]));
+    }
+}
@@ -0,0 +1,167 @@
+use std::fmt::Debug;
+
+use num::Float;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+
+/// A nonnegative factorization `v ≈ w * h` of a `rows x cols` matrix, with `w`
+/// of shape `rows x rank` and `h` of shape `rank x cols`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Nmf<T> {
+    pub w: Matrix<T>,
+    pub h: Matrix<T>,
+    /// The Frobenius norm of `v - w * h`.
+    pub error: T,
+    pub iterations: usize
+}
+
+/// `aᵀ * b`
+fn transpose_mul<T: Float>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    let mut output = Matrix::new(a.cols, b.cols, T::zero());
+    for k in 0..a.rows {
+        for i in 0..a.cols {
+            let factor = *a.get(k, i);
+            for j in 0..b.cols {
+                let val = *output.get(i, j) + factor * *b.get(k, j);
+                output.set(i, j, val);
+            }
+        }
+    }
+    output
+}
+
+/// `a * bᵀ`
+fn mul_transpose<T: Float>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    let mut output = Matrix::new(a.rows, b.rows, T::zero());
+    for i in 0..a.rows {
+        for j in 0..b.rows {
+            let total = (0..a.cols).fold(T::zero(), |total, k| total + *a.get(i, k) * *b.get(j, k));
+            output.set(i, j, total);
+        }
+    }
+    output
+}
+
+/// `factor = factor * numerator / denominator`, element-wise.
+fn multiplicative_update<T: Float>(factor: &mut Matrix<T>, numerator: &Matrix<T>, denominator: &Matrix<T>) {
+    // Keeps the update defined where the denominator vanishes.
+    let epsilon = T::epsilon();
+    for ((val, num), den) in factor.data.iter_mut().zip(numerator.data.iter()).zip(denominator.data.iter()) {
+        *val = *val * *num / (*den + epsilon);
+    }
+}
+
+impl<T: Float + Debug> Matrix<T> {
+    fn reconstruction_error(&self, w: &Matrix<T>, h: &Matrix<T>) -> T {
+        let product = w * h;
+        self.data.iter()
+            .zip(product.data.iter())
+            .fold(T::zero(), |total, (&a, &b)| total + (a - b) * (a - b))
+            .sqrt()
+    }
+
+    /// Nonnegative matrix factorization by Lee and Seung's multiplicative updates.
+    ///
+    /// Iterates until the reconstruction error improves by less than `tol`
+    /// relative to its previous value, or for `max_iter` iterations. The factors
+    /// start from a fixed pseudo-random seed, so the result is reproducible. Every
+    /// entry of `self` must be nonnegative.
+    pub fn nmf(&self, rank: usize, max_iter: usize, tol: T) -> Result<Nmf<T>, MatrixError> {
+        if let Some(position) = self.data.iter().position(|val| val.is_nan() || *val < T::zero()) {
+            return Err(MatrixError::NegativeEntry { row: position / self.cols, col: position % self.cols });
+        }
+
+        // Scaling the starting values to the data's mean magnitude avoids a long
+        // first phase spent only rescaling the factors.
+        let mean = self.data.iter().fold(T::zero(), |total, &val| total + val) / T::from(self.data.len()).unwrap();
+        let scale = (mean / T::from(rank).unwrap()).sqrt();
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut random = |rows, cols| Matrix {
+            rows,
+            cols,
+            data: (0..rows * cols).map(|_| scale * T::from(rng.gen::<f64>()).unwrap()).collect()
+        };
+        let mut w = random(self.rows, rank);
+        let mut h = random(rank, self.cols);
+
+        let mut error = self.reconstruction_error(&w, &h);
+        let mut iterations = 0;
+        while iterations < max_iter {
+            let numerator = transpose_mul(&w, self);
+            let denominator = transpose_mul(&w, &w) * &h;
+            multiplicative_update(&mut h, &numerator, &denominator);
+
+            let numerator = mul_transpose(self, &h);
+            let denominator = &w * mul_transpose(&h, &h);
+            multiplicative_update(&mut w, &numerator, &denominator);
+
+            iterations += 1;
+            let previous = error;
+            error = self.reconstruction_error(&w, &h);
+            if previous - error <= tol * previous {
+                break;
+            }
+        }
+
+        Ok(Nmf {
+            w,
+            h,
+            error,
+            iterations
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalize::Norm;
+
+    #[test]
+    fn nmf_recovers_exact_factorization() {
+        let w = Matrix::from(vec![
+            vec![1.0, 0.0],
+            vec![2.0, 1.0],
+            vec![0.0, 3.0],
+            vec![1.0, 1.0]
+        ]);
+        let h = Matrix::from(vec![
+            vec![1.0, 2.0, 0.0, 1.0],
+            vec![0.0, 1.0, 2.0, 3.0]
+        ]);
+        let v = w * h;
+
+        let nmf = v.nmf(2, 5000, 1e-12).unwrap();
+
+        assert!(nmf.w.data.iter().chain(nmf.h.data.iter()).all(|&val| val >= 0.0));
+        assert!(nmf.error < 1e-3 * Norm::L2.of(v.data.iter().cloned()));
+        assert!((nmf.error - v.reconstruction_error(&nmf.w, &nmf.h)).abs() < 1e-12);
+        assert!(nmf.iterations <= 5000);
+    }
+
+    #[test]
+    fn nmf_error_decreases_with_rank() {
+        let v = Matrix::from(vec![
+            vec![5.0, 3.0, 0.0, 1.0],
+            vec![4.0, 0.0, 0.0, 1.0],
+            vec![1.0, 1.0, 0.0, 5.0],
+            vec![1.0, 0.0, 0.0, 4.0],
+            vec![0.0, 1.0, 5.0, 4.0]
+        ]);
+
+        let errors: Vec<f64> = (1..=3).map(|rank| v.nmf(rank, 2000, 1e-9).unwrap().error).collect();
+        assert!(errors[0] > errors[1] && errors[1] > errors[2]);
+        assert_eq!(v.nmf(2, 100, 1e-9).unwrap(), v.nmf(2, 100, 1e-9).unwrap());
+    }
+
+    #[test]
+    fn nmf_rejects_negative_entries() {
+        let v = Matrix::from(vec![
+            vec![1.0, -1.0]
+        ]);
+        assert_eq!(v.nmf(1, 10, 1e-6), Err(MatrixError::NegativeEntry { row: 0, col: 1 }));
+    }
+}
@@ -0,0 +1,280 @@
+use num::{Integer, Signed};
+
+use crate::Matrix;
+use crate::element::MatrixElement;
+
+/// The Hermite normal form `h = u * a` of an integer matrix `a`, with `u` unimodular.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HermiteNormalForm<T> {
+    pub h: Matrix<T>,
+    pub u: Matrix<T>
+}
+
+/// The Smith normal form `d = u * a * v` of an integer matrix `a`, with `u` and `v` unimodular.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmithNormalForm<T> {
+    pub d: Matrix<T>,
+    pub u: Matrix<T>,
+    pub v: Matrix<T>
+}
+
+fn swap_cols<T>(matrix: &mut Matrix<T>, a: usize, b: usize) {
+    for i in 0..matrix.rows {
+        matrix.data.swap(i * matrix.cols + a, i * matrix.cols + b);
+    }
+}
+
+/// `row[target] -= factor * row[source]`
+fn subtract_row<T: MatrixElement>(matrix: &mut Matrix<T>, target: usize, source: usize, factor: &T) {
+    for j in 0..matrix.cols {
+        let val = matrix.get(target, j).clone() - factor.clone() * matrix.get(source, j).clone();
+        matrix.set(target, j, val);
+    }
+}
+
+/// `col[target] -= factor * col[source]`
+fn subtract_col<T: MatrixElement>(matrix: &mut Matrix<T>, target: usize, source: usize, factor: &T) {
+    for i in 0..matrix.rows {
+        let val = matrix.get(i, target).clone() - factor.clone() * matrix.get(i, source).clone();
+        matrix.set(i, target, val);
+    }
+}
+
+fn negate_row<T: MatrixElement>(matrix: &mut Matrix<T>, row: usize) {
+    for j in 0..matrix.cols {
+        let val = T::zero() - matrix.get(row, j).clone();
+        matrix.set(row, j, val);
+    }
+}
+
+impl<T: MatrixElement + Integer + Signed> Matrix<T> {
+    /// The row-style Hermite normal form.
+    ///
+    /// `h` is in row echelon form, each pivot is positive, and the entries above a
+    /// pivot lie in `0..pivot`. The transform `u` satisfies `h = u * self` and has
+    /// determinant `±1`.
+    pub fn hermite_normal_form(&self) -> HermiteNormalForm<T> {
+        let mut h = self.clone();
+        let mut u = Matrix::identity(self.rows);
+
+        let mut row = 0;
+        for col in 0..h.cols {
+            if row == h.rows {
+                break;
+            }
+
+            // Euclid's algorithm down the column leaves the gcd in the pivot position.
+            loop {
+                let smallest = (row..h.rows)
+                    .filter(|&i| !h.get(i, col).is_zero())
+                    .min_by(|&a, &b| h.get(a, col).abs().cmp(&h.get(b, col).abs()));
+                let pivot_row = match smallest {
+                    Some(pivot_row) => pivot_row,
+                    None => break
+                };
+                h.swap_rows(row, pivot_row);
+                u.swap_rows(row, pivot_row);
+
+                let pivot = h.get(row, col).clone();
+                let mut reduced = true;
+                for i in row + 1..h.rows {
+                    let q = h.get(i, col).div_floor(&pivot);
+                    subtract_row(&mut h, i, row, &q);
+                    subtract_row(&mut u, i, row, &q);
+                    reduced &= h.get(i, col).is_zero();
+                }
+                if reduced {
+                    break;
+                }
+            }
+
+            if h.get(row, col).is_zero() {
+                continue;
+            }
+            if h.get(row, col).is_negative() {
+                negate_row(&mut h, row);
+                negate_row(&mut u, row);
+            }
+
+            let pivot = h.get(row, col).clone();
+            for i in 0..row {
+                let q = h.get(i, col).div_floor(&pivot);
+                subtract_row(&mut h, i, row, &q);
+                subtract_row(&mut u, i, row, &q);
+            }
+            row += 1;
+        }
+
+        HermiteNormalForm {
+            h,
+            u
+        }
+    }
+
+    /// The Smith normal form.
+    ///
+    /// `d` is diagonal with non-negative entries where each diagonal entry divides
+    /// the next; they are the invariant factors of the matrix. The transforms
+    /// satisfy `d = u * self * v`.
+    pub fn smith_normal_form(&self) -> SmithNormalForm<T> {
+        let mut d = self.clone();
+        let mut u = Matrix::identity(self.rows);
+        let mut v = Matrix::identity(self.cols);
+
+        for t in 0..self.rows.min(self.cols) {
+            loop {
+                let mut smallest: Option<(usize, usize)> = None;
+                for i in t..d.rows {
+                    for j in t..d.cols {
+                        let val = d.get(i, j);
+                        let better = match smallest {
+                            Some((a, b)) => val.abs() < d.get(a, b).abs(),
+                            None => true
+                        };
+                        if !val.is_zero() && better {
+                            smallest = Some((i, j));
+                        }
+                    }
+                }
+                let (pivot_row, pivot_col) = match smallest {
+                    Some(position) => position,
+                    None => return SmithNormalForm { d, u, v }
+                };
+                d.swap_rows(t, pivot_row);
+                u.swap_rows(t, pivot_row);
+                swap_cols(&mut d, t, pivot_col);
+                swap_cols(&mut v, t, pivot_col);
+
+                let pivot = d.get(t, t).clone();
+                let mut reduced = true;
+                for i in t + 1..d.rows {
+                    let q = d.get(i, t).div_floor(&pivot);
+                    subtract_row(&mut d, i, t, &q);
+                    subtract_row(&mut u, i, t, &q);
+                    reduced &= d.get(i, t).is_zero();
+                }
+                for j in t + 1..d.cols {
+                    let q = d.get(t, j).div_floor(&pivot);
+                    subtract_col(&mut d, j, t, &q);
+                    subtract_col(&mut v, j, t, &q);
+                    reduced &= d.get(t, j).is_zero();
+                }
+                if !reduced {
+                    continue;
+                }
+
+                // The pivot must divide the rest of the matrix; if not, pull in an offending
+                // row so the next pass reduces the pivot to a smaller gcd.
+                let offending = (t + 1..d.rows)
+                    .find(|&i| (t + 1..d.cols).any(|j| !d.get(i, j).is_multiple_of(&pivot)));
+                match offending {
+                    Some(i) => {
+                        let minus_one = T::zero() - T::one();
+                        subtract_row(&mut d, t, i, &minus_one);
+                        subtract_row(&mut u, t, i, &minus_one);
+                    }
+                    None => break
+                }
+            }
+
+            if d.get(t, t).is_negative() {
+                negate_row(&mut d, t);
+                negate_row(&mut u, t);
+            }
+        }
+
+        SmithNormalForm {
+            d,
+            u,
+            v
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hermite_normal_form_of_square_matrix() {
+        let matrix: Matrix<i64> = Matrix::from(vec![
+            vec![2, 3, 6],
+            vec![4, 1, 5],
+            vec![6, 8, 9]
+        ]);
+
+        let hnf = matrix.hermite_normal_form();
+
+        assert_eq!(hnf.u.clone() * matrix.clone(), hnf.h);
+        assert_eq!(hnf.u.determinant().abs(), 1);
+        for i in 0..3 {
+            for j in 0..i {
+                assert_eq!(*hnf.h.get(i, j), 0);
+            }
+            let pivot = *hnf.h.get(i, i);
+            assert!(pivot > 0);
+            for k in 0..i {
+                assert!((0..pivot).contains(hnf.h.get(k, i)));
+            }
+        }
+        // The product of the pivots is |det|.
+        assert_eq!(hnf.h.get(0, 0) * hnf.h.get(1, 1) * hnf.h.get(2, 2), matrix.determinant().abs());
+    }
+
+    #[test]
+    fn hermite_normal_form_rank_deficient() {
+        // The second row is twice the first, so the rank is 2.
+        let matrix: Matrix<i64> = Matrix::from(vec![
+            vec![2, 3, 6],
+            vec![4, 6, 12],
+            vec![1, 0, 1]
+        ]);
+        assert_eq!(matrix.determinant(), 0);
+
+        let hnf = matrix.hermite_normal_form();
+
+        assert_eq!(hnf.u.clone() * matrix, hnf.h);
+        assert_eq!(hnf.u.determinant().abs(), 1);
+        assert_eq!(hnf.h.get_row(2), vec![0, 0, 0]);
+        assert_eq!(hnf.h, Matrix::from(vec![
+            vec![1, 0, 1],
+            vec![0, 3, 4],
+            vec![0, 0, 0]
+        ]));
+    }
+
+    #[test]
+    fn smith_normal_form_invariant_factors() {
+        let matrix: Matrix<i64> = Matrix::from(vec![
+            vec![2, 4, 4],
+            vec![-6, 6, 12],
+            vec![10, -4, -16]
+        ]);
+
+        let snf = matrix.smith_normal_form();
+
+        assert_eq!(snf.u.clone() * matrix * snf.v.clone(), snf.d);
+        assert_eq!(snf.d, Matrix::from(vec![
+            vec![2, 0, 0],
+            vec![0, 6, 0],
+            vec![0, 0, 12]
+        ]));
+        assert_eq!(snf.u.determinant().abs(), 1);
+        assert_eq!(snf.v.determinant().abs(), 1);
+    }
+
+    #[test]
+    fn smith_normal_form_rectangular() {
+        let matrix: Matrix<i64> = Matrix::from(vec![
+            vec![6, 4],
+            vec![4, 6],
+            vec![2, 2]
+        ]);
+
+        let snf = matrix.smith_normal_form();
+
+        assert_eq!(snf.u.clone() * matrix * snf.v.clone(), snf.d);
+        // d1 = gcd of the entries, d1 * d2 = gcd of the 2x2 minors (20, 4, -4).
+        assert_eq!(snf.d.data, vec![2, 0, 0, 2, 0, 0]);
+    }
+}
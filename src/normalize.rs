@@ -0,0 +1,119 @@
+use num::Float;
+
+use crate::{Axis, Matrix};
+
+/// The vector norm used when normalizing rows or columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Norm {
+    /// Sum of absolute values.
+    L1,
+    /// Euclidean length.
+    L2,
+    /// Largest absolute value.
+    Max
+}
+
+impl Norm {
+    pub fn of<T: Float>(&self, values: impl Iterator<Item = T>) -> T {
+        match self {
+            Norm::L1 => values.fold(T::zero(), |total, val| total + val.abs()),
+            Norm::L2 => values.fold(T::zero(), |total, val| total + val * val).sqrt(),
+            Norm::Max => values.fold(T::zero(), |max, val| max.max(val.abs()))
+        }
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// Scales every row to unit `norm`, returning the factor each row was multiplied by.
+    ///
+    /// Rows with a zero norm are left untouched and report a factor of one.
+    pub fn normalize_rows(&mut self, norm: Norm) -> Vec<T> {
+        let mut factors = vec![];
+        for i in 0..self.rows {
+            let length = norm.of((0..self.cols).map(|j| *self.get(i, j)));
+            let factor = if length == T::zero() { T::one() } else { T::one() / length };
+            for j in 0..self.cols {
+                let val = *self.get(i, j);
+                self.set(i, j, val * factor);
+            }
+            factors.push(factor);
+        }
+        factors
+    }
+
+    /// Scales every column to unit `norm`, returning the factor each column was multiplied by.
+    ///
+    /// Columns with a zero norm are left untouched and report a factor of one.
+    pub fn normalize_columns(&mut self, norm: Norm) -> Vec<T> {
+        let mut factors = vec![];
+        for j in 0..self.cols {
+            let length = norm.of((0..self.rows).map(|i| *self.get(i, j)));
+            let factor = if length == T::zero() { T::one() } else { T::one() / length };
+            for i in 0..self.rows {
+                let val = *self.get(i, j);
+                self.set(i, j, val * factor);
+            }
+            factors.push(factor);
+        }
+        factors
+    }
+
+    /// [`Matrix::normalize_rows`] for `Axis::Rows` or [`Matrix::normalize_columns`] for `Axis::Cols`.
+    pub fn normalize(&mut self, axis: Axis, norm: Norm) -> Vec<T> {
+        match axis {
+            Axis::Rows => self.normalize_rows(norm),
+            Axis::Cols => self.normalize_columns(norm)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_rows_l2() {
+        let mut matrix = Matrix::from(vec![
+            vec![3.0, 4.0],
+            vec![0.0, 0.0]
+        ]);
+
+        let factors = matrix.normalize_rows(Norm::L2);
+
+        assert!((factors[0] - 0.2).abs() < 1e-12);
+        assert_eq!(factors[1], 1.0);
+        assert!((matrix.get(0, 0) - 0.6).abs() < 1e-12);
+        assert!((matrix.get(0, 1) - 0.8).abs() < 1e-12);
+        assert_eq!(matrix.get_row(1), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn normalize_columns_l1_and_max() {
+        let mut matrix = Matrix::from(vec![
+            vec![1.0, -2.0],
+            vec![3.0, 8.0]
+        ]);
+
+        let factors = matrix.normalize_columns(Norm::L1);
+        assert_eq!(factors, vec![0.25, 0.1]);
+        assert_eq!(matrix, Matrix::from(vec![
+            vec![0.25, -0.2],
+            vec![0.75, 0.8]
+        ]));
+
+        let factors = matrix.normalize_columns(Norm::Max);
+        assert_eq!(factors, vec![1.0 / 0.75, 1.0 / 0.8]);
+    }
+
+    #[test]
+    fn normalize_along_axis() {
+        let mut matrix = Matrix::from(vec![
+            vec![2.0, 2.0],
+            vec![4.0, 4.0]
+        ]);
+
+        assert_eq!(matrix.normalize(Axis::Rows, Norm::Max), vec![0.5, 0.25]);
+        assert_eq!(matrix.normalize(Axis::Cols, Norm::L1), vec![0.5, 0.5]);
+        assert_eq!(matrix, Matrix::new(2, 2, 0.5));
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{Axis, Matrix};
+
+impl<T: Clone> Matrix<T> {
+    /// Rotates the matrix by `k` quarter turns counter-clockwise.
+    ///
+    /// Negative `k` rotates clockwise. Odd `k` swaps the number of rows and columns.
+    pub fn rot90(&self, k: i32) -> Matrix<T> {
+        let (rows, cols) = if k.rem_euclid(2) == 0 {
+            (self.rows, self.cols)
+        } else {
+            (self.cols, self.rows)
+        };
+
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                let (row, col) = match k.rem_euclid(4) {
+                    0 => (i, j),
+                    1 => (j, self.cols - 1 - i),
+                    2 => (self.rows - 1 - i, self.cols - 1 - j),
+                    _ => (self.rows - 1 - j, i)
+                };
+                data.push(self.get(row, col).clone());
+            }
+        }
+
+        Matrix {
+            rows,
+            cols,
+            data
+        }
+    }
+
+    /// Swaps rows and columns, so element `(i, j)` moves to `(j, i)`.
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut data = Vec::with_capacity(self.data.len());
+        for j in 0..self.cols {
+            for i in 0..self.rows {
+                data.push(self.get(i, j).clone());
+            }
+        }
+
+        Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            data
+        }
+    }
+
+    /// Transposes in place.
+    ///
+    /// Square matrices are transposed by swapping elements across the diagonal
+    /// without allocating; other shapes are rebuilt through [`Matrix::transpose`].
+    pub fn transpose_mut(&mut self) {
+        if self.rows != self.cols {
+            *self = self.transpose();
+            return;
+        }
+        for i in 0..self.rows {
+            for j in i + 1..self.cols {
+                self.data.swap(i * self.cols + j, j * self.cols + i);
+            }
+        }
+    }
+
+    /// Mirrors the matrix left to right, reversing the order of the columns.
+    pub fn flip_horizontal(&self) -> Matrix<T> {
+        let mut flipped = self.clone();
+        flipped.flip_horizontal_mut();
+        flipped
+    }
+
+    /// Mirrors the matrix top to bottom, reversing the order of the rows.
+    pub fn flip_vertical(&self) -> Matrix<T> {
+        let mut flipped = self.clone();
+        flipped.flip_vertical_mut();
+        flipped
+    }
+
+    pub fn flip_horizontal_mut(&mut self) {
+        if self.cols == 0 {
+            return;
+        }
+        for row in self.data.chunks_mut(self.cols) {
+            row.reverse();
+        }
+    }
+
+    pub fn flip_vertical_mut(&mut self) {
+        for i in 0..self.rows / 2 {
+            let opposite = self.rows - 1 - i;
+            for j in 0..self.cols {
+                self.data.swap(i * self.cols + j, opposite * self.cols + j);
+            }
+        }
+    }
+
+    /// Cyclically shifts the elements by `offset` positions, as NumPy's `roll`.
+    ///
+    /// With `Axis::Rows` each row is shifted along its columns, and with `Axis::Cols`
+    /// each column is shifted down its rows. Positive offsets move elements towards
+    /// higher indices, and elements pushed off the end reappear at the start.
+    pub fn roll(&self, axis: Axis, offset: isize) -> Matrix<T> {
+        let mut data = Vec::with_capacity(self.data.len());
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let (row, col) = match axis {
+                    Axis::Rows => (i, (j as isize - offset).rem_euclid(self.cols as isize) as usize),
+                    Axis::Cols => ((i as isize - offset).rem_euclid(self.rows as isize) as usize, j)
+                };
+                data.push(self.get(row, col).clone());
+            }
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Matrix<i32> {
+        Matrix::from(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6]
+        ])
+    }
+
+    #[test]
+    fn rotate_quarter_turns() {
+        let rotated = example().rot90(1);
+        let expected_matrix = Matrix::from(vec![
+            vec![3, 6],
+            vec![2, 5],
+            vec![1, 4]
+        ]);
+        assert_eq!(rotated, expected_matrix);
+        assert_eq!((rotated.rows, rotated.cols), (3, 2));
+
+        let expected_matrix = Matrix::from(vec![
+            vec![4, 1],
+            vec![5, 2],
+            vec![6, 3]
+        ]);
+        assert_eq!(example().rot90(-1), expected_matrix);
+        assert_eq!(example().rot90(3), expected_matrix);
+
+        assert_eq!(example().rot90(2).data, vec![6, 5, 4, 3, 2, 1]);
+        assert_eq!(example().rot90(4), example());
+    }
+
+    #[test]
+    fn transpose_matrix() {
+        let matrix = Matrix::from(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6]
+        ]);
+
+        let transposed = matrix.transpose();
+        assert_eq!((transposed.rows, transposed.cols), (3, 2));
+        assert_eq!(transposed, Matrix::from(vec![
+            vec![1, 4],
+            vec![2, 5],
+            vec![3, 6]
+        ]));
+        assert_eq!(transposed.transpose(), matrix);
+
+        let mut rectangular = matrix.clone();
+        rectangular.transpose_mut();
+        assert_eq!((rectangular.rows, rectangular.cols), (3, 2));
+        assert_eq!(rectangular, transposed);
+
+        let mut square = Matrix::from(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+            vec![7, 8, 9]
+        ]);
+        square.transpose_mut();
+        assert_eq!(square, Matrix::from(vec![
+            vec![1, 4, 7],
+            vec![2, 5, 8],
+            vec![3, 6, 9]
+        ]));
+    }
+
+    #[test]
+    fn multiply_by_transpose() {
+        let matrix = Matrix::from(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6]
+        ]);
+
+        let outer = matrix.clone() * matrix.transpose();
+        assert_eq!((outer.rows, outer.cols), (2, 2));
+        assert_eq!(outer, Matrix::from(vec![
+            vec![14, 32],
+            vec![32, 77]
+        ]));
+
+        let gram = matrix.transpose() * matrix;
+        assert_eq!((gram.rows, gram.cols), (3, 3));
+        assert_eq!(gram, gram.transpose());
+        assert_eq!(gram.get_row(0), vec![17, 22, 27]);
+    }
+
+    #[test]
+    fn flip_matrix() {
+        assert_eq!(example().flip_horizontal().data, vec![3, 2, 1, 6, 5, 4]);
+        assert_eq!(example().flip_vertical().data, vec![4, 5, 6, 1, 2, 3]);
+
+        // Flipping both ways is a half turn.
+        assert_eq!(example().flip_horizontal().flip_vertical(), example().rot90(2));
+    }
+
+    #[test]
+    fn roll_along_axis() {
+        assert_eq!(example().roll(Axis::Rows, 1).data, vec![3, 1, 2, 6, 4, 5]);
+        assert_eq!(example().roll(Axis::Rows, -4).data, vec![2, 3, 1, 5, 6, 4]);
+        assert_eq!(example().roll(Axis::Cols, 1), example().flip_vertical());
+        assert_eq!(example().roll(Axis::Cols, 6), example());
+    }
+}
@@ -0,0 +1,124 @@
+use num::Zero;
+use num::traits::{SaturatingAdd, SaturatingMul, SaturatingSub, WrappingAdd, WrappingMul, WrappingSub};
+
+use crate::Matrix;
+use crate::error::MatrixError;
+
+/// Integer arithmetic with explicit overflow behaviour.
+///
+/// The plain operators follow Rust's defaults, panicking on overflow in debug
+/// builds and wrapping in release builds. The `wrapping_*` methods always wrap
+/// modulo `2^bits`, as hashing and fixed-point DSP code expects, and the
+/// `saturating_*` methods clamp each intermediate result to the type's range.
+impl<T: Clone> Matrix<T> {
+    pub fn wrapping_add(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: WrappingAdd
+    {
+        self.zip_map(rhs, |a, b| a.wrapping_add(b))
+    }
+
+    pub fn wrapping_sub(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: WrappingSub
+    {
+        self.zip_map(rhs, |a, b| a.wrapping_sub(b))
+    }
+
+    /// The matrix product with every multiplication and addition wrapping.
+    ///
+    /// Wrapping arithmetic is a ring, so the result equals the exact product
+    /// reduced modulo `2^bits`.
+    pub fn wrapping_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: Zero + WrappingAdd + WrappingMul
+    {
+        self.product_with(rhs, |total, a, b| total.wrapping_add(&a.wrapping_mul(b)))
+    }
+
+    pub fn saturating_add(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: SaturatingAdd
+    {
+        self.zip_map(rhs, |a, b| a.saturating_add(b))
+    }
+
+    pub fn saturating_sub(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: SaturatingSub
+    {
+        self.zip_map(rhs, |a, b| a.saturating_sub(b))
+    }
+
+    /// The matrix product with every multiplication and addition saturating.
+    ///
+    /// Unlike wrapping, saturation depends on the order of the additions: each
+    /// element accumulates its products in increasing index order, so a sum
+    /// that overflows and then comes back into range stays clamped.
+    pub fn saturating_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: Zero + SaturatingAdd + SaturatingMul
+    {
+        self.product_with(rhs, |total, a, b| total.saturating_add(&a.saturating_mul(b)))
+    }
+
+    /// The matrix product with `accumulate(total, a, b)` combining each pair
+    /// of factors into the running total.
+    fn product_with<F>(&self, rhs: &Matrix<T>, accumulate: F) -> Result<Matrix<T>, MatrixError>
+        where T: Zero,
+              F: Fn(&T, &T, &T) -> T
+    {
+        let shape = self.shape().matmul_with(rhs.shape())?;
+        let mut data = Vec::with_capacity(shape.rows * shape.cols);
+        for i in 0..shape.rows {
+            for j in 0..shape.cols {
+                data.push((0..self.cols).fold(T::zero(), |total, k| accumulate(&total, self.get(i, k), rhs.get(k, j))));
+            }
+        }
+
+        Ok(Matrix {
+            rows: shape.rows,
+            cols: shape.cols,
+            data
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping_arithmetic() {
+        let a: Matrix<u8> = Matrix::from(vec![
+            vec![250, 3],
+            vec![0, 128]
+        ]);
+        let b: Matrix<u8> = Matrix::from(vec![
+            vec![10, 4],
+            vec![1, 2]
+        ]);
+
+        assert_eq!(a.wrapping_add(&b).unwrap(), Matrix::from(vec![vec![4, 7], vec![1, 130]]));
+        assert_eq!(b.wrapping_sub(&a).unwrap(), Matrix::from(vec![vec![16, 1], vec![1, 130]]));
+        // [250 * 10 + 3 * 1, 250 * 4 + 3 * 2] = [2503, 1006], reduced mod 256.
+        let product = a.wrapping_mul(&b).unwrap();
+        assert_eq!(product.get_row(0), vec![(2503 % 256) as u8, (1006 % 256) as u8]);
+        assert_eq!(product.get_row(1), vec![128, 0]);
+        assert!(a.wrapping_mul(&Matrix::new(3, 1, 0)).is_err());
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        let a: Matrix<i8> = Matrix::from(vec![
+            vec![100, -100],
+            vec![1, 2]
+        ]);
+        let b: Matrix<i8> = Matrix::from(vec![
+            vec![100, 100],
+            vec![-1, 3]
+        ]);
+
+        assert_eq!(a.saturating_add(&b).unwrap(), Matrix::from(vec![vec![127, 0], vec![0, 5]]));
+        assert_eq!(a.saturating_sub(&b).unwrap(), Matrix::from(vec![vec![0, -128], vec![2, -1]]));
+        // The exact (0, 1) entry is 100 * 100 - 100 * 3 = 9700, but the first
+        // product clamps to 127 and the second to -128 before they are added.
+        assert_eq!(a.saturating_mul(&b).unwrap().get_row(0), vec![127, -1]);
+        assert_eq!(a.saturating_mul(&b).unwrap().get_row(1), vec![98, 106]);
+        assert!(a.saturating_add(&Matrix::new(1, 2, 0)).is_err());
+    }
+}
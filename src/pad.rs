@@ -0,0 +1,120 @@
+use crate::Matrix;
+
+/// How [`Matrix::pad`] fills the new border elements.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode<T> {
+    /// Every border element takes the given value.
+    Constant(T),
+    /// Repeats the nearest edge element.
+    Edge,
+    /// Mirrors the matrix about its edge, without repeating the edge element.
+    Reflect,
+    /// Continues periodically from the opposite side.
+    Wrap
+}
+
+/// Maps a possibly out-of-range index onto `0..len` for the non-constant modes.
+fn source_index<T>(index: isize, len: usize, mode: &PadMode<T>) -> usize {
+    let len = len as isize;
+    let mapped = match mode {
+        PadMode::Constant(_) => index,
+        PadMode::Edge => index.max(0).min(len - 1),
+        PadMode::Wrap => index.rem_euclid(len),
+        PadMode::Reflect => {
+            if len == 1 {
+                0
+            } else {
+                let period = 2 * (len - 1);
+                let offset = index.rem_euclid(period);
+                if offset < len { offset } else { period - offset }
+            }
+        }
+    };
+    mapped as usize
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Adds `top` and `bottom` rows and `left` and `right` columns around the matrix,
+    /// filling them according to `mode`.
+    ///
+    /// Padding wider than the matrix itself is allowed; `Reflect` and `Wrap` keep
+    /// mirroring or repeating as far as needed.
+    pub fn pad(&self, top: usize, bottom: usize, left: usize, right: usize, mode: PadMode<T>) -> Matrix<T> {
+        let rows = self.rows + top + bottom;
+        let cols = self.cols + left + right;
+        if !matches!(mode, PadMode::Constant(_)) {
+            assert!(self.rows > 0 && self.cols > 0, "only constant padding can extend an empty matrix");
+        }
+
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                let row = i as isize - top as isize;
+                let col = j as isize - left as isize;
+                let inside = row >= 0 && col >= 0 && (row as usize) < self.rows && (col as usize) < self.cols;
+                match &mode {
+                    PadMode::Constant(value) if !inside => data.push(value.clone()),
+                    _ => {
+                        let row = source_index(row, self.rows, &mode);
+                        let col = source_index(col, self.cols, &mode);
+                        data.push(self.get(row, col).clone());
+                    }
+                }
+            }
+        }
+
+        Matrix {
+            rows,
+            cols,
+            data
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Matrix<i32> {
+        Matrix::from(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6]
+        ])
+    }
+
+    #[test]
+    fn pad_constant() {
+        let expected_matrix = Matrix::from(vec![
+            vec![0, 0, 0, 0],
+            vec![0, 1, 2, 3],
+            vec![0, 4, 5, 6]
+        ]);
+        let padded = example().pad(1, 0, 1, 0, PadMode::Constant(0));
+        assert_eq!(padded, expected_matrix);
+        assert_eq!((padded.rows, padded.cols), (3, 4));
+    }
+
+    #[test]
+    fn pad_edge_reflect_wrap() {
+        let expected_matrix = Matrix::from(vec![
+            vec![1, 1, 2, 3, 3],
+            vec![4, 4, 5, 6, 6],
+            vec![4, 4, 5, 6, 6]
+        ]);
+        assert_eq!(example().pad(0, 1, 1, 1, PadMode::Edge), expected_matrix);
+
+        let expected_matrix = Matrix::from(vec![
+            vec![3, 2, 1, 2, 3, 2, 1],
+            vec![6, 5, 4, 5, 6, 5, 4]
+        ]);
+        assert_eq!(example().pad(0, 0, 2, 2, PadMode::Reflect), expected_matrix);
+
+        let expected_matrix = Matrix::from(vec![
+            vec![6, 4, 5, 6, 4],
+            vec![3, 1, 2, 3, 1],
+            vec![6, 4, 5, 6, 4],
+            vec![3, 1, 2, 3, 1]
+        ]);
+        assert_eq!(example().pad(1, 1, 1, 1, PadMode::Wrap), expected_matrix);
+    }
+}
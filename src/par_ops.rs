@@ -0,0 +1,89 @@
+use std::thread;
+
+use crate::Matrix;
+use crate::element::MatrixElement;
+use crate::error::MatrixError;
+use crate::matmul::{blocked_rows, pack_transposed};
+use crate::parallel::thread_count;
+
+/// Rows per worker so that the available cores each get one contiguous band.
+fn rows_per_thread(rows: usize) -> usize {
+    rows.div_ceil(thread_count()).max(1)
+}
+
+impl<T: MatrixElement + Send + Sync> Matrix<T> {
+    /// The product `self * rhs`, with the rows of the result split between
+    /// threads, or an error if the inner dimensions differ.
+    ///
+    /// Each thread runs the same packed and tiled kernel as `*` on its own band
+    /// of output rows, so the result is identical to the sequential product.
+    pub fn par_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let shape = self.shape().matmul_with(rhs.shape())?;
+        let packed = pack_transposed(rhs);
+
+        let mut output = Matrix::new(shape.rows, shape.cols, T::zero());
+        thread::scope(|scope| {
+            for mut chunk in output.par_chunks_mut(rows_per_thread(shape.rows)) {
+                let packed = &packed;
+                scope.spawn(move || {
+                    let rows = chunk.start_row()..chunk.start_row() + chunk.rows();
+                    blocked_rows(self, packed, shape.cols, rows, chunk.as_mut_slice());
+                });
+            }
+        });
+        Ok(output)
+    }
+}
+
+impl<T: Sync> Matrix<T> {
+    /// Applies `f` to every element, with bands of rows mapped on separate threads.
+    pub fn par_map<U, F>(&self, f: F) -> Matrix<U>
+        where U: Send, F: Fn(&T) -> U + Sync
+    {
+        let chunk_len = (rows_per_thread(self.rows) * self.cols).max(1);
+        let data = thread::scope(|scope| {
+            let handles: Vec<_> = self.data.chunks(chunk_len)
+                .map(|chunk| {
+                    let f = &f;
+                    scope.spawn(move || chunk.iter().map(f).collect::<Vec<U>>())
+                })
+                .collect();
+            handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect()
+        });
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Shape;
+
+    #[test]
+    fn par_mul_matches_sequential_product() {
+        let a = Matrix::from((0..97).map(|i| (0..70).map(|j| ((i * 31 + j * 17) % 23) as f64 - 11.0).collect()).collect());
+        let b = Matrix::from((0..70).map(|i| (0..45).map(|j| ((i * 13 + j * 7) % 19) as f64 / 3.0).collect()).collect());
+
+        let product = a.par_mul(&b).unwrap();
+        assert_eq!(product.shape(), Shape::new(97, 45));
+        assert_eq!(product, &a * &b);
+
+        assert_eq!(a.par_mul(&a), Err(MatrixError::DimensionMismatch { left: a.shape(), right: a.shape() }));
+    }
+
+    #[test]
+    fn par_map_preserves_order_and_shape() {
+        let matrix = Matrix::from((0..9).map(|i| (0..4).map(|j| i * 4 + j).collect()).collect());
+
+        let mapped = matrix.par_map(|val| val * 2 + 1);
+
+        assert_eq!(mapped.shape(), matrix.shape());
+        assert_eq!(mapped.data, (0..36).map(|val| val * 2 + 1).collect::<Vec<_>>());
+        assert_eq!(Matrix::<i32>::new(0, 3, 0).par_map(|val| *val).data, vec![]);
+    }
+}
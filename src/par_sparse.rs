@@ -0,0 +1,264 @@
+use std::thread;
+
+use crate::Matrix;
+use crate::element::MatrixElement;
+use crate::error::MatrixError;
+use crate::parallel::thread_count;
+use crate::sparse::CsrMatrix;
+
+/// Splits `0..rows` into one contiguous band per thread, each holding about
+/// the same number of stored entries, returned as the band boundaries.
+fn balanced_bands(row_offsets: &[usize], threads: usize) -> Vec<usize> {
+    let rows = row_offsets.len() - 1;
+    let nnz = row_offsets[rows];
+    let mut bounds = vec![0];
+    for band in 1..threads {
+        let target = nnz * band / threads;
+        let start = *bounds.last().unwrap();
+        let end = start + row_offsets[start..rows].partition_point(|&offset| offset < target);
+        bounds.push(end);
+    }
+    bounds.push(rows);
+    bounds.dedup();
+    bounds
+}
+
+/// Assigns each element a color such that no two elements of the same color
+/// share a degree of freedom, by greedily giving each element the smallest
+/// color its neighbours have not taken.
+///
+/// Elements of one color touch disjoint rows of the assembled matrix, so they
+/// can be scattered into it concurrently without locks.
+pub(crate) fn color_elements(elements: &[Vec<usize>], dofs: usize) -> Vec<usize> {
+    // The colors already used by elements touching each degree of freedom.
+    let mut used: Vec<Vec<usize>> = vec![vec![]; dofs];
+    let mut colors = Vec::with_capacity(elements.len());
+    for element in elements {
+        let mut taken: Vec<usize> = element.iter().flat_map(|&dof| used[dof].iter().copied()).collect();
+        taken.sort_unstable();
+        taken.dedup();
+        let color = taken.iter().enumerate().find(|&(index, &color)| index != color).map_or(taken.len(), |(index, _)| index);
+        for &dof in element {
+            used[dof].push(color);
+        }
+        colors.push(color);
+    }
+    colors
+}
+
+/// The values array of a matrix under assembly, written from several threads
+/// at positions the coloring guarantees are disjoint.
+struct SharedValues<T> {
+    ptr: *mut T,
+    len: usize
+}
+
+unsafe impl<T: Send> Sync for SharedValues<T> {}
+
+impl<T: MatrixElement> SharedValues<T> {
+    /// Adds `val` to the value at `index`.
+    ///
+    /// # Safety
+    ///
+    /// No other thread may access `index` while this runs.
+    unsafe fn add(&self, index: usize, val: T) {
+        assert!(index < self.len);
+        let slot = &mut *self.ptr.add(index);
+        *slot = slot.clone() + val;
+    }
+}
+
+impl<T: MatrixElement + Send + Sync> CsrMatrix<T> {
+    /// The matrix-vector product `self * x`, with the rows split into one band
+    /// per thread so that each band holds about the same number of entries.
+    ///
+    /// Each output entry is computed exactly as by [`MatrixOps::matvec`], so the
+    /// result is identical.
+    ///
+    /// [`MatrixOps::matvec`]: crate::MatrixOps::matvec
+    pub fn par_matvec(&self, x: &[T]) -> Vec<T> {
+        assert_eq!(x.len(), self.cols);
+
+        let bounds = balanced_bands(&self.row_offsets, thread_count());
+        let mut output = vec![T::zero(); self.rows];
+        thread::scope(|scope| {
+            let mut rest = &mut output[..];
+            for band in bounds.windows(2) {
+                let (chunk, remaining) = rest.split_at_mut(band[1] - band[0]);
+                rest = remaining;
+                let start = band[0];
+                scope.spawn(move || {
+                    for (offset, out) in chunk.iter_mut().enumerate() {
+                        let (cols, values) = self.row(start + offset);
+                        *out = cols.iter().zip(values.iter()).fold(T::zero(), |total, (&j, val)| total + val.clone() * x[j].clone());
+                    }
+                });
+            }
+        });
+        output
+    }
+
+    /// Assembles a square `dofs x dofs` matrix from finite-element style
+    /// contributions in parallel.
+    ///
+    /// Element `e` couples the degrees of freedom `elements[e]`, and `local(e)`
+    /// gives its dense `k x k` contribution, added at the rows and columns
+    /// `elements[e]`. The sparsity pattern is built first; the elements are
+    /// then colored so that those of one color share no rows, and each color
+    /// is computed and scattered with its elements split between threads. The
+    /// result equals assembling the same entries with
+    /// [`CsrMatrix::from_triplets`], up to the order floating-point
+    /// contributions are summed in.
+    pub fn par_assemble<F>(dofs: usize, elements: &[Vec<usize>], local: F) -> Result<CsrMatrix<T>, MatrixError>
+        where F: Fn(usize) -> Matrix<T> + Sync
+    {
+        if let Some(&dof) = elements.iter().flatten().find(|&&dof| dof >= dofs) {
+            return Err(MatrixError::IndexOutOfBounds { row: dof, col: dof, rows: dofs, cols: dofs });
+        }
+
+        let mut pattern: Vec<Vec<usize>> = vec![vec![]; dofs];
+        for element in elements {
+            for &row in element {
+                pattern[row].extend_from_slice(element);
+            }
+        }
+        let mut row_offsets = Vec::with_capacity(dofs + 1);
+        row_offsets.push(0);
+        let mut col_indices = vec![];
+        for row in pattern.iter_mut() {
+            row.sort_unstable();
+            row.dedup();
+            col_indices.extend_from_slice(row);
+            row_offsets.push(col_indices.len());
+        }
+        let mut values = vec![T::zero(); col_indices.len()];
+
+        let colors = color_elements(elements, dofs);
+        let color_count = colors.iter().max().map_or(0, |&max| max + 1);
+        let threads = thread_count();
+        let shared = SharedValues { ptr: values.as_mut_ptr(), len: values.len() };
+        for color in 0..color_count {
+            let members: Vec<usize> = (0..elements.len()).filter(|&e| colors[e] == color).collect();
+            let results: Vec<Result<(), MatrixError>> = thread::scope(|scope| {
+                let handles: Vec<_> = members.chunks(members.len().div_ceil(threads).max(1))
+                    .map(|chunk| {
+                        let (shared, local, row_offsets, col_indices) = (&shared, &local, &row_offsets, &col_indices);
+                        scope.spawn(move || {
+                            for &e in chunk {
+                                let dofs = &elements[e];
+                                let matrix = local(e);
+                                if matrix.shape() != (dofs.len(), dofs.len()).into() {
+                                    return Err(MatrixError::DimensionMismatch {
+                                        left: (dofs.len(), dofs.len()).into(),
+                                        right: matrix.shape()
+                                    });
+                                }
+                                for (a, &row) in dofs.iter().enumerate() {
+                                    let start = row_offsets[row];
+                                    let cols = &col_indices[start..row_offsets[row + 1]];
+                                    for (b, &col) in dofs.iter().enumerate() {
+                                        let index = start + cols.binary_search(&col).unwrap();
+                                        // Elements of one color share no rows, so no other thread
+                                        // writes to this row while the color is assembled.
+                                        unsafe { shared.add(index, matrix.get(a, b).clone()) };
+                                    }
+                                }
+                            }
+                            Ok(())
+                        })
+                    })
+                    .collect();
+                handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+            });
+            results.into_iter().collect::<Result<(), MatrixError>>()?;
+        }
+
+        Ok(CsrMatrix {
+            rows: dofs,
+            cols: dofs,
+            row_offsets,
+            col_indices,
+            values
+        }.without_zeros())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::MatrixOps;
+
+    /// Linear elements on a 1D mesh of `n` nodes, joined in a ring when `closed`.
+    fn mesh(n: usize, closed: bool) -> Vec<Vec<usize>> {
+        let count = if closed { n } else { n - 1 };
+        (0..count).map(|e| vec![e, (e + 1) % n]).collect()
+    }
+
+    #[test]
+    fn coloring_separates_shared_dofs() {
+        let elements = mesh(9, true);
+        let colors = color_elements(&elements, 9);
+        for a in 0..elements.len() {
+            for b in a + 1..elements.len() {
+                if elements[a].iter().any(|dof| elements[b].contains(dof)) {
+                    assert_ne!(colors[a], colors[b]);
+                }
+            }
+        }
+        // A ring of odd length needs a third color.
+        assert_eq!(colors.iter().max(), Some(&2));
+        assert_eq!(color_elements(&mesh(10, false), 10).iter().max(), Some(&1));
+    }
+
+    #[test]
+    fn par_assemble_matches_triplet_assembly() {
+        let n = 200;
+        let elements = mesh(n, false);
+        let stiffness = |e: usize| {
+            let k = (e % 7 + 1) as f64;
+            Matrix::from(vec![vec![k, -k], vec![-k, k]])
+        };
+
+        let assembled = CsrMatrix::par_assemble(n, &elements, stiffness).unwrap();
+
+        let mut triplets = vec![];
+        for (e, dofs) in elements.iter().enumerate() {
+            let local = stiffness(e);
+            for a in 0..2 {
+                for b in 0..2 {
+                    triplets.push((dofs[a], dofs[b], *local.get(a, b)));
+                }
+            }
+        }
+        assert_eq!(assembled, CsrMatrix::from_triplets(n, n, &triplets).unwrap());
+        assert_eq!(assembled.nnz(), 3 * n - 2);
+
+        assert!(matches!(
+            CsrMatrix::par_assemble(n, &elements, |_| Matrix::new(3, 3, 1.0)),
+            Err(MatrixError::DimensionMismatch { .. })
+        ));
+        assert!(matches!(
+            CsrMatrix::par_assemble(4, &elements, stiffness),
+            Err(MatrixError::IndexOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn par_matvec_matches_matvec() {
+        // Rows of very different lengths exercise the balancing.
+        let mut triplets = vec![];
+        for i in 0..300 {
+            for j in (0..300).step_by(if i % 50 == 0 { 1 } else { 37 }) {
+                triplets.push((i, j, ((i * 7 + j * 3) % 11) as f64 - 5.0));
+            }
+        }
+        let matrix = CsrMatrix::from_triplets(300, 300, &triplets).unwrap();
+        let x: Vec<f64> = (0..300).map(|i| (i % 13) as f64 / 4.0).collect();
+
+        assert_eq!(matrix.par_matvec(&x), matrix.matvec(&x));
+
+        let bounds = balanced_bands(&matrix.row_offsets, 4);
+        assert_eq!((bounds[0], *bounds.last().unwrap()), (0, 300));
+        assert!(bounds.windows(2).all(|band| band[0] < band[1]));
+    }
+}
@@ -0,0 +1,138 @@
+use std::slice::ChunksMut;
+use std::thread;
+
+use crate::Matrix;
+
+/// The number of threads the parallel operations split their work between.
+pub(crate) fn thread_count() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+/// A mutable block of consecutive rows borrowed from a [`Matrix`].
+///
+/// Blocks from [`Matrix::par_chunks_mut`] never overlap, so each can be handed
+/// to a different thread.
+#[derive(Debug)]
+pub struct RowChunkMut<'a, T> {
+    /// Index of the first row of the block within the whole matrix.
+    start_row: usize,
+    cols: usize,
+    data: &'a mut [T]
+}
+
+impl<T> RowChunkMut<'_, T> {
+    pub fn start_row(&self) -> usize {
+        self.start_row
+    }
+
+    pub fn rows(&self) -> usize {
+        self.data.len() / self.cols
+    }
+
+    /// The element at `row` of the block (not of the whole matrix) and `col`.
+    pub fn get(&self, row: usize, col: usize) -> &T {
+        &self.data[row * self.cols + col]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        self.data[row * self.cols + col] = value;
+    }
+
+    /// The elements of the block in row-major order.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.data
+    }
+}
+
+/// Iterator returned by [`Matrix::par_chunks_mut`].
+pub struct RowChunksMut<'a, T> {
+    chunks: ChunksMut<'a, T>,
+    next_row: usize,
+    cols: usize
+}
+
+impl<'a, T> Iterator for RowChunksMut<'a, T> {
+    type Item = RowChunkMut<'a, T>;
+
+    fn next(&mut self) -> Option<RowChunkMut<'a, T>> {
+        let data = self.chunks.next()?;
+        let start_row = self.next_row;
+        self.next_row += data.len() / self.cols;
+        Some(RowChunkMut {
+            start_row,
+            cols: self.cols,
+            data
+        })
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Splits the matrix into disjoint mutable blocks of `rows_per_chunk` rows
+    /// (the last may be shorter) for filling or updating on worker threads.
+    ///
+    /// `Matrix<T>` is `Send` and `Sync` whenever `T` is, and each block is `Send`
+    /// when `T` is, so the blocks can be moved into `std::thread::scope` threads.
+    pub fn par_chunks_mut(&mut self, rows_per_chunk: usize) -> RowChunksMut<'_, T> {
+        assert!(rows_per_chunk > 0, "chunks must contain at least one row");
+        // A matrix without columns has no elements to hand out.
+        let chunk_len = (rows_per_chunk * self.cols).max(1);
+
+        RowChunksMut {
+            chunks: self.data.chunks_mut(chunk_len),
+            next_row: 0,
+            cols: self.cols
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn matrices_are_send_and_sync() {
+        assert_send_sync::<Matrix<f64>>();
+        assert_send_sync::<Matrix<num::BigInt>>();
+        assert_send_sync::<RowChunkMut<'_, f64>>();
+        #[cfg(unix)]
+        assert_send_sync::<crate::mmap::MappedMatrix>();
+    }
+
+    #[test]
+    fn parallel_fill_by_row_chunks() {
+        let mut matrix = Matrix::new(7, 3, 0);
+
+        thread::scope(|scope| {
+            for mut chunk in matrix.par_chunks_mut(2) {
+                scope.spawn(move || {
+                    for i in 0..chunk.rows() {
+                        for j in 0..3 {
+                            chunk.set(i, j, 10 * (chunk.start_row() + i) + j);
+                        }
+                    }
+                });
+            }
+        });
+
+        for i in 0..7 {
+            assert_eq!(matrix.get_row(i), vec![10 * i, 10 * i + 1, 10 * i + 2]);
+        }
+    }
+
+    #[test]
+    fn row_chunk_shapes() {
+        let mut matrix = Matrix::new(5, 2, 1);
+        let shapes: Vec<(usize, usize)> = matrix.par_chunks_mut(2).map(|chunk| (chunk.start_row(), chunk.rows())).collect();
+        assert_eq!(shapes, vec![(0, 2), (2, 2), (4, 1)]);
+
+        for mut chunk in matrix.par_chunks_mut(3) {
+            chunk.as_mut_slice().iter_mut().for_each(|val| *val += 1);
+            assert_eq!(*chunk.get(0, 1), 2);
+        }
+        assert_eq!(matrix, Matrix::new(5, 2, 2));
+    }
+}
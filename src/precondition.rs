@@ -0,0 +1,357 @@
+use std::fmt::Debug;
+
+use num::Float;
+
+use crate::error::MatrixError;
+use crate::traits::MatrixOps;
+
+/// An approximation `M` of a matrix `A` that is cheap to invert, used to speed
+/// up the Krylov solvers [`conjugate_gradient`](crate::conjugate_gradient) and
+/// [`gmres`](crate::gmres).
+///
+/// The closer `M⁻¹ A` is to the identity, the fewer iterations a solver needs.
+pub trait Preconditioner<T> {
+    /// Solves `M * z = r` for `z`.
+    fn apply(&self, r: &[T]) -> Vec<T>;
+}
+
+/// The trivial preconditioner `M = I`, which leaves a solver unpreconditioned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Identity;
+
+impl<T: Clone> Preconditioner<T> for Identity {
+    fn apply(&self, r: &[T]) -> Vec<T> {
+        r.to_vec()
+    }
+}
+
+/// Jacobi (diagonal) preconditioning, `M = diag(A)`.
+///
+/// Cheap to build and apply, and effective when `A` is strongly diagonally
+/// dominant or its rows are badly scaled relative to each other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Jacobi<T> {
+    inverse_diagonal: Vec<T>
+}
+
+/// Symmetric successive over-relaxation,
+/// `M = (D + ωL) D⁻¹ (D + ωU) / (ω (2 - ω))` for `A = L + D + U`.
+///
+/// `M` is symmetric positive definite whenever `A` is, so it can precondition
+/// the conjugate gradient method.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ssor<T> {
+    rows: Vec<Vec<(usize, T)>>,
+    diagonal: Vec<T>,
+    omega: T
+}
+
+/// Zero fill-in incomplete Cholesky, `M = L * Lᵀ` with `L` restricted to the
+/// nonzero pattern of the lower triangle of `A`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncompleteCholesky<T> {
+    /// Row `i` of `L` as sorted `(column, value)` pairs ending with the diagonal.
+    lower: Vec<Vec<(usize, T)>>
+}
+
+/// Zero fill-in incomplete LU, `M = L * U` with `L` unit lower triangular and
+/// both factors restricted to the nonzero pattern of `A`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ilu0<T> {
+    /// Row `i` of `L` below the diagonal and `U` on and above it, as sorted `(column, value)` pairs.
+    factors: Vec<Vec<(usize, T)>>,
+    /// Position of the diagonal within each row of `factors`.
+    diagonal: Vec<usize>
+}
+
+/// The nonzero entries of every row of a square matrix.
+///
+/// Explicitly stored zeros are dropped, so a dense matrix with a sparse
+/// structure gets the same incomplete factorizations as a sparse one.
+fn nonzero_rows<T, M>(matrix: &M) -> Result<Vec<Vec<(usize, T)>>, MatrixError>
+    where T: Float + Debug, M: MatrixOps<T>
+{
+    let shape = matrix.shape();
+    if !shape.is_square() {
+        return Err(MatrixError::NotSquare { rows: shape.rows, cols: shape.cols });
+    }
+    Ok((0..shape.rows).map(|i| matrix.row_entries(i).filter(|(_, val)| !val.is_zero()).collect()).collect())
+}
+
+fn diagonal_of<T: Float>(rows: &[Vec<(usize, T)>]) -> Result<Vec<T>, MatrixError> {
+    rows.iter()
+        .enumerate()
+        .map(|(i, row)| row.iter().find(|&&(j, _)| j == i).map(|&(_, val)| val).ok_or(MatrixError::Singular))
+        .collect()
+}
+
+/// The sum of `a[k] * b[k]` over the columns `k < end` stored in both sorted rows.
+fn sparse_dot<T: Float>(a: &[(usize, T)], b: &[(usize, T)], end: usize) -> T {
+    let (mut p, mut q) = (0, 0);
+    let mut total = T::zero();
+    while p < a.len() && q < b.len() && a[p].0 < end && b[q].0 < end {
+        if a[p].0 == b[q].0 {
+            total = total + a[p].1 * b[q].1;
+            p += 1;
+            q += 1;
+        } else if a[p].0 < b[q].0 {
+            p += 1;
+        } else {
+            q += 1;
+        }
+    }
+    total
+}
+
+impl<T: Float + Debug> Jacobi<T> {
+    /// Returns [`MatrixError::Singular`] if a diagonal entry is zero.
+    pub fn new<M: MatrixOps<T>>(matrix: &M) -> Result<Jacobi<T>, MatrixError> {
+        let diagonal = diagonal_of(&nonzero_rows(matrix)?)?;
+        Ok(Jacobi {
+            inverse_diagonal: diagonal.into_iter().map(T::recip).collect()
+        })
+    }
+}
+
+impl<T: Float> Preconditioner<T> for Jacobi<T> {
+    fn apply(&self, r: &[T]) -> Vec<T> {
+        r.iter().zip(self.inverse_diagonal.iter()).map(|(&val, &inverse)| val * inverse).collect()
+    }
+}
+
+impl<T: Float + Debug> Ssor<T> {
+    /// Builds the preconditioner with relaxation factor `omega`, which must lie
+    /// in `(0, 2)`. `omega = 1` gives symmetric Gauss-Seidel.
+    ///
+    /// Returns [`MatrixError::InvalidParameter`] for any other `omega` and
+    /// [`MatrixError::Singular`] if a diagonal entry is zero.
+    pub fn new<M: MatrixOps<T>>(matrix: &M, omega: T) -> Result<Ssor<T>, MatrixError> {
+        if !(omega > T::zero() && omega < T::from(2.0).unwrap()) {
+            return Err(MatrixError::InvalidParameter { name: "omega", reason: "SSOR relaxation factor must lie in (0, 2)" });
+        }
+
+        let rows = nonzero_rows(matrix)?;
+        let diagonal = diagonal_of(&rows)?;
+        Ok(Ssor {
+            rows,
+            diagonal,
+            omega
+        })
+    }
+}
+
+impl<T: Float> Preconditioner<T> for Ssor<T> {
+    fn apply(&self, r: &[T]) -> Vec<T> {
+        let n = r.len();
+        let omega = self.omega;
+
+        // (D + ωL) y = r
+        let mut y = r.to_vec();
+        for i in 0..n {
+            let below = self.rows[i].iter().filter(|&&(j, _)| j < i).fold(T::zero(), |total, &(j, val)| total + val * y[j]);
+            y[i] = (y[i] - omega * below) / self.diagonal[i];
+        }
+        // (D + ωU) z = D y
+        let mut z: Vec<T> = y.iter().zip(self.diagonal.iter()).map(|(&val, &d)| val * d).collect();
+        for i in (0..n).rev() {
+            let above = self.rows[i].iter().filter(|&&(j, _)| j > i).fold(T::zero(), |total, &(j, val)| total + val * z[j]);
+            z[i] = (z[i] - omega * above) / self.diagonal[i];
+        }
+
+        let scale = omega * (T::from(2.0).unwrap() - omega);
+        z.into_iter().map(|val| val * scale).collect()
+    }
+}
+
+impl<T: Float + Debug> IncompleteCholesky<T> {
+    /// Factorizes a symmetric positive definite matrix, reading only its lower triangle.
+    ///
+    /// Dropping fill-in can break the factorization even for a positive definite
+    /// matrix, which is reported as [`MatrixError::NotPositiveDefinite`]; it
+    /// cannot happen for an M-matrix, such as a discretized Laplacian.
+    pub fn new<M: MatrixOps<T>>(matrix: &M) -> Result<IncompleteCholesky<T>, MatrixError> {
+        let rows = nonzero_rows(matrix)?;
+
+        let mut lower: Vec<Vec<(usize, T)>> = Vec::with_capacity(rows.len());
+        for (i, row) in rows.iter().enumerate() {
+            let mut factor_row = vec![];
+            let mut diagonal = T::zero();
+            for &(j, val) in row.iter().filter(|&&(j, _)| j <= i) {
+                if j == i {
+                    diagonal = val;
+                    continue;
+                }
+                let above = &lower[j];
+                let pivot = above[above.len() - 1].1;
+                factor_row.push((j, (val - sparse_dot(&factor_row, above, j)) / pivot));
+            }
+
+            let squared = diagonal - factor_row.iter().fold(T::zero(), |total, &(_, val)| total + val * val);
+            if squared.is_nan() || squared <= T::zero() {
+                return Err(MatrixError::NotPositiveDefinite);
+            }
+            factor_row.push((i, squared.sqrt()));
+            lower.push(factor_row);
+        }
+        Ok(IncompleteCholesky {
+            lower
+        })
+    }
+}
+
+impl<T: Float> Preconditioner<T> for IncompleteCholesky<T> {
+    fn apply(&self, r: &[T]) -> Vec<T> {
+        // L y = r, then Lᵀ z = y, scattering each solved entry back up its column.
+        let mut z = r.to_vec();
+        for (i, row) in self.lower.iter().enumerate() {
+            let (last, below) = row.split_last().unwrap();
+            let total = below.iter().fold(z[i], |total, &(j, val)| total - val * z[j]);
+            z[i] = total / last.1;
+        }
+        for (i, row) in self.lower.iter().enumerate().rev() {
+            let (last, below) = row.split_last().unwrap();
+            z[i] = z[i] / last.1;
+            for &(j, val) in below {
+                z[j] = z[j] - val * z[i];
+            }
+        }
+        z
+    }
+}
+
+impl<T: Float + Debug> Ilu0<T> {
+    /// Factorizes a square matrix without pivoting.
+    ///
+    /// Returns [`MatrixError::Singular`] if a pivot is zero, which includes a
+    /// zero on the diagonal of `A` itself.
+    pub fn new<M: MatrixOps<T>>(matrix: &M) -> Result<Ilu0<T>, MatrixError> {
+        let mut factors = nonzero_rows(matrix)?;
+        let n = factors.len();
+        let diagonal = (0..n)
+            .map(|i| factors[i].iter().position(|&(j, _)| j == i).ok_or(MatrixError::Singular))
+            .collect::<Result<Vec<usize>, MatrixError>>()?;
+
+        // Position of each column within the row being eliminated, if it is in the pattern.
+        let mut position: Vec<Option<usize>> = vec![None; n];
+        for i in 0..n {
+            for (p, &(j, _)) in factors[i].iter().enumerate() {
+                position[j] = Some(p);
+            }
+            for p in 0..diagonal[i] {
+                let k = factors[i][p].0;
+                let pivot = factors[k][diagonal[k]].1;
+                if pivot.is_zero() {
+                    return Err(MatrixError::Singular);
+                }
+                let multiplier = factors[i][p].1 / pivot;
+                factors[i][p].1 = multiplier;
+                for q in diagonal[k] + 1..factors[k].len() {
+                    let (j, val) = factors[k][q];
+                    if let Some(target) = position[j] {
+                        factors[i][target].1 = factors[i][target].1 - multiplier * val;
+                    }
+                }
+            }
+            for &(j, _) in factors[i].iter() {
+                position[j] = None;
+            }
+        }
+        if (0..n).any(|i| factors[i][diagonal[i]].1.is_zero()) {
+            return Err(MatrixError::Singular);
+        }
+
+        Ok(Ilu0 {
+            factors,
+            diagonal
+        })
+    }
+}
+
+impl<T: Float> Preconditioner<T> for Ilu0<T> {
+    fn apply(&self, r: &[T]) -> Vec<T> {
+        let mut z = r.to_vec();
+        for (i, row) in self.factors.iter().enumerate() {
+            z[i] = row[..self.diagonal[i]].iter().fold(z[i], |total, &(j, val)| total - val * z[j]);
+        }
+        for (i, row) in self.factors.iter().enumerate().rev() {
+            let d = self.diagonal[i];
+            let total = row[d + 1..].iter().fold(z[i], |total, &(j, val)| total - val * z[j]);
+            z[i] = total / row[d].1;
+        }
+        z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Matrix;
+
+    fn tridiagonal(n: usize) -> Matrix<f64> {
+        let mut matrix = Matrix::new(n, n, 0.0);
+        for i in 0..n {
+            matrix.set(i, i, 4.0);
+            if i > 0 {
+                matrix.set(i, i - 1, -1.0);
+                matrix.set(i - 1, i, -1.0);
+            }
+        }
+        matrix
+    }
+
+    fn assert_solves(preconditioner: &impl Preconditioner<f64>, matrix: &Matrix<f64>) {
+        let x = vec![1.0, -2.0, 0.5, 3.0];
+        let z = preconditioner.apply(&matrix.matvec(&x));
+        assert!(z.iter().zip(x.iter()).all(|(a, b)| (a - b).abs() < 1e-12), "{:?}", z);
+    }
+
+    #[test]
+    fn incomplete_factorizations_are_exact_without_fill() {
+        // A tridiagonal matrix has no fill-in, so the incomplete factors are the complete ones.
+        let matrix = tridiagonal(4);
+        assert_solves(&IncompleteCholesky::new(&matrix).unwrap(), &matrix);
+        assert_solves(&Ilu0::new(&matrix).unwrap(), &matrix);
+
+        let mut asymmetric = matrix.clone();
+        asymmetric.set(0, 1, 2.0);
+        asymmetric.set(3, 2, -3.0);
+        assert_solves(&Ilu0::new(&asymmetric).unwrap(), &asymmetric);
+    }
+
+    #[test]
+    fn jacobi_and_ssor() {
+        let diagonal = Matrix::from(vec![
+            vec![2.0, 0.0, 0.0, 0.0],
+            vec![0.0, -4.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.5, 0.0],
+            vec![0.0, 0.0, 0.0, 1.0]
+        ]);
+        assert_solves(&Jacobi::new(&diagonal).unwrap(), &diagonal);
+        // With omega = 1 and a diagonal matrix, SSOR is exact as well.
+        assert_solves(&Ssor::new(&diagonal, 1.0).unwrap(), &diagonal);
+
+        // On a coupled matrix SSOR only approximates the inverse.
+        let matrix = tridiagonal(4);
+        let ssor = Ssor::new(&matrix, 1.2).unwrap();
+        let r = vec![1.0, 0.0, 0.0, 1.0];
+        let (a, b) = (ssor.apply(&r), matrix.solve(&Matrix::from(r.iter().map(|&val| vec![val]).collect())).unwrap());
+        assert!(a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 0.1));
+    }
+
+    #[test]
+    fn preconditioner_errors() {
+        let zero_diagonal = Matrix::from(vec![vec![0.0, 1.0], vec![1.0, 0.0]]);
+        assert_eq!(Jacobi::new(&zero_diagonal), Err(MatrixError::Singular));
+        assert_eq!(Ilu0::new(&zero_diagonal), Err(MatrixError::Singular));
+        assert_eq!(Jacobi::new(&Matrix::new(2, 3, 1.0)), Err(MatrixError::NotSquare { rows: 2, cols: 3 }));
+        for omega in [0.0, 2.0, f64::NAN] {
+            assert_eq!(
+                Ssor::new(&Matrix::identity(2), omega),
+                Err(MatrixError::InvalidParameter { name: "omega", reason: "SSOR relaxation factor must lie in (0, 2)" })
+            );
+        }
+
+        let indefinite = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 1.0]]);
+        assert_eq!(IncompleteCholesky::new(&indefinite), Err(MatrixError::NotPositiveDefinite));
+    }
+}
@@ -0,0 +1,174 @@
+use std::ops::Deref;
+
+use num::Float;
+
+use crate::Matrix;
+use crate::element::MatrixElement;
+use crate::error::MatrixError;
+use crate::normalize::Norm;
+
+/// A row-stochastic [`Matrix`]: every entry is non-negative and every row sums to one.
+///
+/// Each row is a probability distribution, as in the transition matrix of a
+/// Markov chain. The invariant is checked when the value is constructed, and all
+/// read-only `Matrix` methods are available through `Deref`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProbabilityMatrix<T> {
+    inner: Matrix<T>
+}
+
+fn check_non_negative<T: Float>(matrix: &Matrix<T>) -> Result<(), MatrixError> {
+    for i in 0..matrix.rows {
+        for j in 0..matrix.cols {
+            let val = *matrix.get(i, j);
+            if val.is_nan() || val < T::zero() {
+                return Err(MatrixError::NegativeEntry { row: i, col: j });
+            }
+        }
+    }
+    Ok(())
+}
+
+impl<T: Float> Matrix<T> {
+    pub fn try_into_probability(self, tolerance: T) -> Result<ProbabilityMatrix<T>, MatrixError> {
+        ProbabilityMatrix::new(self, tolerance)
+    }
+}
+
+impl<T: Float> ProbabilityMatrix<T> {
+    /// Checks that `matrix` is row-stochastic, allowing each row sum to differ
+    /// from one by at most `tolerance`.
+    pub fn new(matrix: Matrix<T>, tolerance: T) -> Result<ProbabilityMatrix<T>, MatrixError> {
+        check_non_negative(&matrix)?;
+        for i in 0..matrix.rows {
+            let sum = (0..matrix.cols).fold(T::zero(), |total, j| total + *matrix.get(i, j));
+            if (sum - T::one()).abs() > tolerance {
+                return Err(MatrixError::UnnormalizedRow { row: i });
+            }
+        }
+        Ok(ProbabilityMatrix {
+            inner: matrix
+        })
+    }
+
+    /// Turns non-negative weights into probabilities by dividing each row by its sum.
+    ///
+    /// A row of all zeros has no distribution and is reported as unnormalized.
+    pub fn from_weights(mut matrix: Matrix<T>) -> Result<ProbabilityMatrix<T>, MatrixError> {
+        check_non_negative(&matrix)?;
+        if let Some(row) = (0..matrix.rows).find(|&i| matrix.get_row(i).iter().all(|val| val.is_zero())) {
+            return Err(MatrixError::UnnormalizedRow { row });
+        }
+        matrix.normalize_rows(Norm::L1);
+        Ok(ProbabilityMatrix {
+            inner: matrix
+        })
+    }
+
+    /// Rescales every row to sum to exactly one again, removing the rounding
+    /// drift that builds up over repeated products.
+    pub fn renormalize(&mut self) {
+        self.inner.normalize_rows(Norm::L1);
+    }
+
+    pub fn into_inner(self) -> Matrix<T> {
+        self.inner
+    }
+
+    /// The product of two stochastic matrices, which is itself stochastic.
+    ///
+    /// In Markov chain terms, `a.then(&b)` takes a step of `a` followed by a step of `b`.
+    pub fn then(&self, other: &ProbabilityMatrix<T>) -> Result<ProbabilityMatrix<T>, MatrixError>
+        where T: MatrixElement
+    {
+        self.shape().matmul_with(other.shape())?;
+        let mut product = ProbabilityMatrix {
+            inner: &self.inner * &other.inner
+        };
+        product.renormalize();
+        Ok(product)
+    }
+
+    /// Advances a distribution over the rows by one step: `distribution * self`.
+    pub fn propagate(&self, distribution: &[T]) -> Vec<T> {
+        assert_eq!(distribution.len(), self.inner.rows);
+
+        (0..self.inner.cols)
+            .map(|j| (0..self.inner.rows).fold(T::zero(), |total, i| total + distribution[i] * *self.inner.get(i, j)))
+            .collect()
+    }
+}
+
+impl<T> Deref for ProbabilityMatrix<T> {
+    type Target = Matrix<T>;
+
+    fn deref(&self) -> &Matrix<T> {
+        &self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn probability_matrix_validation() {
+        let matrix = Matrix::from(vec![
+            vec![0.5, 0.5],
+            vec![0.1, 0.9]
+        ]);
+        assert!(matrix.clone().try_into_probability(1e-12).is_ok());
+
+        let negative = Matrix::from(vec![
+            vec![1.5, -0.5],
+            vec![0.1, 0.9]
+        ]);
+        assert_eq!(ProbabilityMatrix::new(negative, 1e-12), Err(MatrixError::NegativeEntry { row: 0, col: 1 }));
+
+        let unnormalized = Matrix::from(vec![
+            vec![0.5, 0.5],
+            vec![0.1, 0.8]
+        ]);
+        assert_eq!(ProbabilityMatrix::new(unnormalized.clone(), 1e-3), Err(MatrixError::UnnormalizedRow { row: 1 }));
+        assert!(ProbabilityMatrix::new(unnormalized, 0.2).is_ok());
+    }
+
+    #[test]
+    fn probability_matrix_from_weights() {
+        let weights = Matrix::from(vec![
+            vec![1.0, 3.0],
+            vec![2.0, 0.0]
+        ]);
+        let probabilities = ProbabilityMatrix::from_weights(weights).unwrap();
+        assert_eq!(probabilities.get_row(0), vec![0.25, 0.75]);
+        assert_eq!(probabilities.get_row(1), vec![1.0, 0.0]);
+
+        let empty_row = Matrix::from(vec![
+            vec![1.0, 1.0],
+            vec![0.0, 0.0]
+        ]);
+        assert_eq!(ProbabilityMatrix::from_weights(empty_row), Err(MatrixError::UnnormalizedRow { row: 1 }));
+    }
+
+    #[test]
+    fn markov_chain_steps() {
+        let transition = ProbabilityMatrix::new(Matrix::from(vec![
+            vec![0.9, 0.1],
+            vec![0.5, 0.5]
+        ]), 1e-12).unwrap();
+
+        let distribution = transition.propagate(&[1.0, 0.0]);
+        assert_eq!(distribution, vec![0.9, 0.1]);
+
+        let mut chain = transition.clone();
+        for _ in 0..50 {
+            chain = chain.then(&transition).unwrap();
+        }
+        // Both rows converge to the stationary distribution (5/6, 1/6).
+        for i in 0..2 {
+            assert!((chain.get(i, 0) - 5.0 / 6.0).abs() < 1e-9);
+            let sum: f64 = chain.get_row(i).iter().sum();
+            assert!((sum - 1.0).abs() < 1e-15);
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use crate::Matrix;
+use crate::element::MatrixElement;
+use crate::error::MatrixError;
+use crate::shape::Shape;
+
+/// A matrix produced by a [`Program`], either an input or the result of an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slot(usize);
+
+#[derive(Debug, Clone, Copy)]
+enum Instruction<T> {
+    Input,
+    MatMul(Slot, Slot),
+    Add(Slot, Slot),
+    Sub(Slot, Slot),
+    Hadamard(Slot, Slot),
+    Scale(Slot, T),
+    Map(Slot, fn(T) -> T)
+}
+
+/// Records a sequence of matrix operations, checking shapes as it goes.
+///
+/// Finish with [`ProgramBuilder::build`] to get a [`Program`] that can be run on
+/// any inputs of the recorded shapes.
+#[derive(Debug, Clone)]
+pub struct ProgramBuilder<T> {
+    shapes: Vec<Shape>,
+    instructions: Vec<Instruction<T>>
+}
+
+/// A recorded sequence of matrix operations with a buffer preallocated for every
+/// intermediate result.
+///
+/// Running the program again writes into the same buffers, so a per-frame
+/// pipeline pays for shape checking and allocation only once.
+#[derive(Debug, Clone)]
+pub struct Program<T> {
+    instructions: Vec<Instruction<T>>,
+    buffers: Vec<Matrix<T>>,
+    inputs: Vec<Slot>
+}
+
+impl<T: MatrixElement> ProgramBuilder<T> {
+    pub fn new() -> ProgramBuilder<T> {
+        ProgramBuilder {
+            shapes: vec![],
+            instructions: vec![]
+        }
+    }
+
+    fn push(&mut self, shape: Shape, instruction: Instruction<T>) -> Slot {
+        self.shapes.push(shape);
+        self.instructions.push(instruction);
+        Slot(self.instructions.len() - 1)
+    }
+
+    /// Declares the next input, which must be `rows x cols` on every run.
+    pub fn input(&mut self, rows: usize, cols: usize) -> Slot {
+        self.push(Shape::new(rows, cols), Instruction::Input)
+    }
+
+    pub fn matmul(&mut self, a: Slot, b: Slot) -> Result<Slot, MatrixError> {
+        let shape = self.shapes[a.0].matmul_with(self.shapes[b.0])?;
+        Ok(self.push(shape, Instruction::MatMul(a, b)))
+    }
+
+    fn elementwise(&mut self, a: Slot, b: Slot, instruction: Instruction<T>) -> Result<Slot, MatrixError> {
+        let shape = self.shapes[a.0].elementwise_with(self.shapes[b.0])?;
+        Ok(self.push(shape, instruction))
+    }
+
+    pub fn add(&mut self, a: Slot, b: Slot) -> Result<Slot, MatrixError> {
+        self.elementwise(a, b, Instruction::Add(a, b))
+    }
+
+    pub fn sub(&mut self, a: Slot, b: Slot) -> Result<Slot, MatrixError> {
+        self.elementwise(a, b, Instruction::Sub(a, b))
+    }
+
+    pub fn hadamard(&mut self, a: Slot, b: Slot) -> Result<Slot, MatrixError> {
+        self.elementwise(a, b, Instruction::Hadamard(a, b))
+    }
+
+    pub fn scale(&mut self, a: Slot, factor: T) -> Slot {
+        self.push(self.shapes[a.0], Instruction::Scale(a, factor))
+    }
+
+    /// Applies `f` to every element of `a`.
+    pub fn map(&mut self, a: Slot, f: fn(T) -> T) -> Slot {
+        self.push(self.shapes[a.0], Instruction::Map(a, f))
+    }
+
+    /// Allocates every buffer the program needs.
+    pub fn build(self) -> Program<T> {
+        let inputs = self.instructions.iter()
+            .enumerate()
+            .filter(|(_, instruction)| matches!(instruction, Instruction::Input))
+            .map(|(index, _)| Slot(index))
+            .collect();
+
+        Program {
+            buffers: self.shapes.iter().map(|shape| Matrix::new(shape.rows, shape.cols, T::zero())).collect(),
+            instructions: self.instructions,
+            inputs
+        }
+    }
+}
+
+impl<T: MatrixElement> Default for ProgramBuilder<T> {
+    fn default() -> Self {
+        ProgramBuilder::new()
+    }
+}
+
+/// Writes `f(a[i], b[i])` into `out` for every element.
+fn zip_into<T: Clone>(out: &mut Matrix<T>, a: &Matrix<T>, b: &Matrix<T>, f: impl Fn(T, T) -> T) {
+    for (val, (x, y)) in out.data.iter_mut().zip(a.data.iter().zip(b.data.iter())) {
+        *val = f(x.clone(), y.clone());
+    }
+}
+
+impl<T: MatrixElement> Program<T> {
+    /// Runs the program on `inputs`, given in the order they were declared.
+    pub fn run(&mut self, inputs: &[&Matrix<T>]) -> Result<(), MatrixError> {
+        assert_eq!(inputs.len(), self.inputs.len(), "expected {} inputs", self.inputs.len());
+        for (slot, input) in self.inputs.iter().zip(inputs.iter()) {
+            let buffer = &mut self.buffers[slot.0];
+            buffer.shape().elementwise_with(input.shape())?;
+            buffer.data.clone_from_slice(&input.data);
+        }
+
+        for index in 0..self.instructions.len() {
+            // Operands always come before their result, so splitting here lets the
+            // result be written while the operands are read.
+            let (done, rest) = self.buffers.split_at_mut(index);
+            let out = &mut rest[0];
+            match self.instructions[index] {
+                Instruction::Input => {}
+                Instruction::MatMul(a, b) => {
+                    let (a, b) = (&done[a.0], &done[b.0]);
+                    for i in 0..out.rows {
+                        for j in 0..out.cols {
+                            let mut total = T::zero();
+                            for k in 0..a.cols {
+                                total = total + a.get(i, k).clone() * b.get(k, j).clone();
+                            }
+                            out.set(i, j, total);
+                        }
+                    }
+                }
+                Instruction::Add(a, b) => zip_into(out, &done[a.0], &done[b.0], |x, y| x + y),
+                Instruction::Sub(a, b) => zip_into(out, &done[a.0], &done[b.0], |x, y| x - y),
+                Instruction::Hadamard(a, b) => zip_into(out, &done[a.0], &done[b.0], |x, y| x * y),
+                Instruction::Scale(a, ref factor) => {
+                    for (val, x) in out.data.iter_mut().zip(done[a.0].data.iter()) {
+                        *val = x.clone() * factor.clone();
+                    }
+                }
+                Instruction::Map(a, f) => {
+                    for (val, x) in out.data.iter_mut().zip(done[a.0].data.iter()) {
+                        *val = f(x.clone());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The value of `slot` from the most recent run.
+    pub fn output(&self, slot: Slot) -> &Matrix<T> {
+        &self.buffers[slot.0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replay_program_on_new_inputs() {
+        // y = relu(W * x + b) * 2
+        let mut builder = ProgramBuilder::new();
+        let w = builder.input(2, 3);
+        let x = builder.input(3, 1);
+        let b = builder.input(2, 1);
+        let wx = builder.matmul(w, x).unwrap();
+        let z = builder.add(wx, b).unwrap();
+        let relu = builder.map(z, |val: f64| val.max(0.0));
+        let y = builder.scale(relu, 2.0);
+        let mut program = builder.build();
+
+        let weights = Matrix::from(vec![
+            vec![1.0, 0.0, -1.0],
+            vec![0.5, 0.5, 0.5]
+        ]);
+        let bias = Matrix::from(vec![vec![0.0], vec![-1.0]]);
+
+        program.run(&[&weights, &Matrix::from(vec![vec![1.0], vec![2.0], vec![3.0]]), &bias]).unwrap();
+        assert_eq!(program.output(y).data, vec![0.0, 4.0]);
+
+        program.run(&[&weights, &Matrix::from(vec![vec![4.0], vec![0.0], vec![1.0]]), &bias]).unwrap();
+        assert_eq!(program.output(y).data, vec![6.0, 3.0]);
+        assert_eq!(program.output(wx).data, vec![3.0, 2.5]);
+    }
+
+    #[test]
+    fn program_shape_errors() {
+        let mut builder: ProgramBuilder<i32> = ProgramBuilder::new();
+        let a = builder.input(2, 3);
+        let b = builder.input(2, 3);
+        assert_eq!(builder.matmul(a, b), Err(MatrixError::DimensionMismatch { left: Shape::new(2, 3), right: Shape::new(2, 3) }));
+
+        let sum = builder.sub(a, b).unwrap();
+        let product = builder.hadamard(sum, a).unwrap();
+        let mut program = builder.build();
+
+        let wrong_shape = Matrix::new(3, 2, 1);
+        assert!(program.run(&[&wrong_shape, &wrong_shape]).is_err());
+
+        program.run(&[&Matrix::new(2, 3, 5), &Matrix::new(2, 3, 2)]).unwrap();
+        assert_eq!(program.output(product), &Matrix::new(2, 3, 15));
+    }
+}
@@ -0,0 +1,126 @@
+use std::ops::ControlFlow;
+
+use crate::error::MatrixError;
+
+/// A snapshot of an iterative algorithm, passed to the progress callback after each step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Iteration {
+    /// Zero-based index of the step that just finished.
+    pub index: usize,
+    /// The algorithm's own measure of its distance from convergence.
+    pub residual: f64
+}
+
+/// Limits and hooks shared by the crate's iterative algorithms.
+///
+/// The progress callback runs after every iteration; returning
+/// `ControlFlow::Break(())` aborts the computation, which then reports
+/// [`MatrixError::Cancelled`](crate::error::MatrixError::Cancelled).
+pub struct SolverOptions<'a> {
+    pub(crate) max_iterations: usize,
+    pub(crate) tolerance: f64,
+    progress: Option<Box<dyn FnMut(Iteration) -> ControlFlow<()> + 'a>>
+}
+
+impl<'a> SolverOptions<'a> {
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn progress<F>(mut self, callback: F) -> Self
+        where F: FnMut(Iteration) -> ControlFlow<()> + 'a
+    {
+        self.progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Invokes the progress callback, if any, for a finished iteration.
+    pub(crate) fn report(&mut self, index: usize, residual: f64) -> ControlFlow<()> {
+        match self.progress.as_mut() {
+            Some(callback) => callback(Iteration { index, residual }),
+            None => ControlFlow::Continue(())
+        }
+    }
+}
+
+/// The result of an iterative algorithm run with [`SolverOptions`], with a
+/// record of whether it met the tolerance.
+///
+/// When `converged` is false the iteration limit ran out first and `value` is
+/// the best effort after the last iteration, which `residual` lets the caller
+/// judge for themselves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution<R> {
+    pub value: R,
+    pub converged: bool,
+    pub iterations: usize,
+    /// The algorithm's measure of its distance from convergence when it stopped.
+    pub residual: f64
+}
+
+impl<R> Solution<R> {
+    /// The value if the algorithm converged, or [`MatrixError::NoConvergence`].
+    pub fn into_result(self) -> Result<R, MatrixError> {
+        if self.converged {
+            Ok(self.value)
+        } else {
+            Err(MatrixError::NoConvergence { iterations: self.iterations })
+        }
+    }
+}
+
+impl Default for SolverOptions<'_> {
+    fn default() -> Self {
+        SolverOptions {
+            max_iterations: 1000,
+            tolerance: 1e-10,
+            progress: None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_calls_progress() {
+        let mut seen = vec![];
+        {
+            let mut options = SolverOptions::default().progress(|iteration| {
+                seen.push(iteration.index);
+                if iteration.index == 1 {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            });
+
+            assert_eq!(options.report(0, 1.0), ControlFlow::Continue(()));
+            assert_eq!(options.report(1, 0.5), ControlFlow::Break(()));
+        }
+        assert_eq!(seen, vec![0, 1]);
+    }
+
+    #[test]
+    fn solution_into_result() {
+        let solution = Solution { value: 3, converged: true, iterations: 4, residual: 0.0 };
+        assert_eq!(solution.into_result(), Ok(3));
+
+        let best_effort = Solution { value: 3, converged: false, iterations: 4, residual: 0.5 };
+        assert_eq!(best_effort.into_result(), Err(MatrixError::NoConvergence { iterations: 4 }));
+    }
+
+    #[test]
+    fn report_without_callback_continues() {
+        let mut options = SolverOptions::default().max_iterations(5).tolerance(1e-3);
+        assert_eq!(options.max_iterations, 5);
+        assert_eq!(options.report(0, 1.0), ControlFlow::Continue(()));
+    }
+}
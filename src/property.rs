@@ -0,0 +1,300 @@
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use proptest::arbitrary::{any_with, Arbitrary as ProptestArbitrary};
+use proptest::collection::vec;
+use proptest::strategy::{BoxedStrategy, Strategy};
+use quickcheck::{Arbitrary as QuickcheckArbitrary, Gen};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+use crate::Matrix;
+
+/// The largest number of rows or columns in an arbitrary matrix, which keeps
+/// cubic operations fast enough to run hundreds of cases.
+const MAX_DIMENSION: usize = 8;
+
+/// A `proptest` strategy for matrices whose row and column counts are drawn
+/// from `rows` and `cols` and whose elements are drawn from `element`.
+///
+/// Failing cases shrink towards simpler elements and towards the smallest
+/// shape in the ranges.
+pub fn matrices<S>(rows: RangeInclusive<usize>, cols: RangeInclusive<usize>, element: S) -> impl Strategy<Value = Matrix<S::Value>>
+    where S: Strategy
+{
+    let element = Arc::new(element);
+    (rows, cols).prop_flat_map(move |(rows, cols)| {
+        vec(Arc::clone(&element), rows * cols).prop_map(move |data| Matrix {
+            rows,
+            cols,
+            data
+        })
+    })
+}
+
+/// Matrices of 1 to 8 rows and columns, for `any::<Matrix<T>>()` and
+/// `proptest!` arguments; the parameters are passed on to `T`'s strategy.
+impl<T> ProptestArbitrary for Matrix<T>
+    where T: ProptestArbitrary + 'static,
+          T::Strategy: 'static
+{
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Matrix<T>>;
+
+    fn arbitrary_with(parameters: Self::Parameters) -> Self::Strategy {
+        matrices(1..=MAX_DIMENSION, 1..=MAX_DIMENSION, any_with::<T>(parameters)).boxed()
+    }
+}
+
+/// Matrices of 1 to 8 rows and columns, fewer when the generator's size is
+/// smaller. Shrinking drops the last row or column before shrinking elements.
+impl<T: QuickcheckArbitrary> QuickcheckArbitrary for Matrix<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let max = g.size().clamp(1, MAX_DIMENSION);
+        let rows = usize::arbitrary(g) % max + 1;
+        let cols = usize::arbitrary(g) % max + 1;
+        let data = (0..rows * cols).map(|_| T::arbitrary(g)).collect();
+
+        Matrix {
+            rows,
+            cols,
+            data
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let mut smaller_shapes = vec![];
+        if self.rows > 1 {
+            smaller_shapes.push(self.permute_rows(&(0..self.rows - 1).collect::<Vec<_>>()));
+        }
+        if self.cols > 1 {
+            smaller_shapes.push(self.permute_columns(&(0..self.cols - 1).collect::<Vec<_>>()));
+        }
+
+        let matrix = self.clone();
+        let smaller_elements = (0..self.data.len()).flat_map(move |index| {
+            let matrix = matrix.clone();
+            let shrunk = matrix.data[index].shrink();
+            shrunk.map(move |val| {
+                let mut smaller = matrix.clone();
+                smaller.data[index] = val;
+                smaller
+            })
+        });
+        Box::new(smaller_shapes.into_iter().chain(smaller_elements))
+    }
+}
+
+/// Generates random matrices for property tests, with a small built-in
+/// runner, [`MatrixStrategy::check`].
+///
+/// Shapes are drawn uniformly from the configured row and column ranges and
+/// every element is produced by the `values` closure. Failing cases are shrunk
+/// by removing rows and columns before they are reported.
+///
+/// This is a self-contained generator and shrinker for tests that don't use
+/// either framework. With them, use `proptest`'s and `quickcheck`'s
+/// `Arbitrary` implementations for [`Matrix`] or [`matrices`] instead; all
+/// three come with the `property-testing` feature.
+pub struct MatrixStrategy<F> {
+    rows: RangeInclusive<usize>,
+    cols: RangeInclusive<usize>,
+    square: bool,
+    values: F
+}
+
+impl<T, F> MatrixStrategy<F>
+    where F: Fn(&mut StdRng) -> T
+{
+    /// A strategy for matrices of 1 to 8 rows and columns with elements from `values`.
+    pub fn new(values: F) -> Self {
+        MatrixStrategy {
+            rows: 1..=8,
+            cols: 1..=8,
+            square: false,
+            values
+        }
+    }
+
+    pub fn rows(mut self, rows: RangeInclusive<usize>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    pub fn cols(mut self, cols: RangeInclusive<usize>) -> Self {
+        self.cols = cols;
+        self
+    }
+
+    /// Only generates square matrices, sized from the row range.
+    pub fn square(mut self) -> Self {
+        self.square = true;
+        self
+    }
+
+    pub fn generate(&self, rng: &mut StdRng) -> Matrix<T> {
+        let rows = rng.gen_range(self.rows.clone());
+        let cols = if self.square { rows } else { rng.gen_range(self.cols.clone()) };
+        let data = (0..rows * cols).map(|_| (self.values)(rng)).collect();
+
+        Matrix {
+            rows,
+            cols,
+            data
+        }
+    }
+
+    /// Smaller matrices derived from `matrix` that still respect the shape ranges.
+    fn shrink(&self, matrix: &Matrix<T>) -> Vec<Matrix<T>>
+        where T: Clone
+    {
+        let mut candidates = vec![];
+        let can_drop_row = matrix.rows > *self.rows.start();
+        let can_drop_col = matrix.cols > *self.cols.start();
+        if self.square {
+            if can_drop_row {
+                for k in 0..matrix.rows {
+                    let keep: Vec<usize> = (0..matrix.rows).filter(|&i| i != k).collect();
+                    candidates.push(matrix.permute_rows(&keep).permute_columns(&keep));
+                }
+            }
+            return candidates;
+        }
+        if can_drop_row {
+            for k in 0..matrix.rows {
+                let keep: Vec<usize> = (0..matrix.rows).filter(|&i| i != k).collect();
+                candidates.push(matrix.permute_rows(&keep));
+            }
+        }
+        if can_drop_col {
+            for k in 0..matrix.cols {
+                let keep: Vec<usize> = (0..matrix.cols).filter(|&j| j != k).collect();
+                candidates.push(matrix.permute_columns(&keep));
+            }
+        }
+        candidates
+    }
+
+    /// Runs `property` on `cases` matrices generated from `seed`.
+    ///
+    /// On failure the counterexample is shrunk as far as the property keeps failing
+    /// and the test panics with it, along with the seed that reproduces the run.
+    pub fn check<P>(&self, cases: usize, seed: u64, property: P)
+        where T: Clone + Debug, P: Fn(&Matrix<T>) -> bool
+    {
+        let mut rng = StdRng::seed_from_u64(seed);
+        for case in 0..cases {
+            let mut failing = self.generate(&mut rng);
+            if property(&failing) {
+                continue;
+            }
+
+            while let Some(smaller) = self.shrink(&failing).into_iter().find(|candidate| !property(candidate)) {
+                failing = smaller;
+            }
+            panic!("property failed on case {} (seed {}) with {:?}", case, seed, failing);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::arbitrary::any;
+    use proptest::test_runner::{TestError, TestRunner};
+    use proptest::{prop_assert, prop_assert_eq};
+    use quickcheck::QuickCheck;
+
+    use super::*;
+
+    fn small_integers() -> MatrixStrategy<impl Fn(&mut StdRng) -> i32> {
+        MatrixStrategy::new(|rng: &mut StdRng| rng.gen_range(-10..=10))
+    }
+
+    #[test]
+    fn generated_shapes_respect_ranges() {
+        let strategy = small_integers().rows(2..=3).cols(4..=4);
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..20 {
+            let matrix = strategy.generate(&mut rng);
+            assert!((2..=3).contains(&matrix.rows));
+            assert_eq!(matrix.cols, 4);
+            assert!(matrix.data.iter().all(|val| (-10..=10).contains(val)));
+        }
+
+        let square = small_integers().rows(1..=5).square().generate(&mut rng);
+        assert_eq!(square.rows, square.cols);
+    }
+
+    #[test]
+    fn multiplication_properties() {
+        small_integers().check(100, 1, |matrix| matrix.clone() * 1 == *matrix);
+        small_integers().square().check(100, 2, |matrix| {
+            let reversed: Vec<usize> = (0..matrix.rows).rev().collect();
+            let mut exchange = Matrix::new(matrix.rows, matrix.rows, 0);
+            for (i, &j) in reversed.iter().enumerate() {
+                exchange.set(i, j, 1);
+            }
+            exchange * matrix.clone() == matrix.permute_rows(&reversed)
+        });
+    }
+
+    #[test]
+    fn proptest_strategy_respects_shapes() {
+        let mut runner = TestRunner::default();
+        let strategy = matrices(2..=3, 4..=4, -10..=10i32);
+        runner.run(&strategy, |matrix| {
+            prop_assert!((2..=3).contains(&matrix.rows) && matrix.cols == 4);
+            prop_assert!(matrix.data.iter().all(|val| (-10..=10).contains(val)));
+            Ok(())
+        }).unwrap();
+
+        runner.run(&any::<Matrix<i8>>(), |matrix| {
+            prop_assert_eq!(matrix.data.len(), matrix.rows * matrix.cols);
+            prop_assert_eq!(matrix.transpose().transpose(), matrix);
+            Ok(())
+        }).unwrap();
+    }
+
+    #[test]
+    fn proptest_shrinks_shape_first() {
+        let mut runner = TestRunner::default();
+        let result = runner.run(&matrices(1..=6, 1..=6, 0..10i32), |matrix| {
+            prop_assert!(matrix.rows < 3);
+            Ok(())
+        });
+
+        match result {
+            Err(TestError::Fail(_, matrix)) => assert_eq!((matrix.rows, matrix.cols), (3, 1)),
+            other => panic!("expected a failing case, found {:?}", other)
+        }
+    }
+
+    #[test]
+    fn quickcheck_matrices() {
+        fn double_transpose(matrix: Matrix<i32>) -> bool {
+            matrix.data.len() == matrix.rows * matrix.cols && matrix.transpose().transpose() == matrix
+        }
+        QuickCheck::new().quickcheck(double_transpose as fn(Matrix<i32>) -> bool);
+
+        let matrix = Matrix::from(vec![
+            vec![1, 2],
+            vec![3, 4]
+        ]);
+        let shrunk: Vec<Matrix<i32>> = matrix.shrink().collect();
+        assert_eq!(shrunk[0], Matrix::from(vec![vec![1, 2]]));
+        assert_eq!(shrunk[1], Matrix::from(vec![vec![1], vec![3]]));
+        assert!(shrunk[2..].iter().all(|smaller| smaller.rows == 2 && smaller.cols == 2));
+    }
+
+    #[test]
+    fn failing_property_is_shrunk() {
+        let result = std::panic::catch_unwind(|| {
+            small_integers().rows(1..=6).cols(1..=6).check(100, 3, |matrix| matrix.rows < 3);
+        });
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+
+        // Columns are removed down to the minimum, and rows down to the smallest failing count.
+        assert!(message.contains("rows: 3, cols: 1"), "{}", message);
+    }
+}
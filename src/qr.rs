@@ -0,0 +1,437 @@
+use num::Float;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+use crate::reflectors::{householder_vector, Householder};
+use crate::sort::nan_first_cmp;
+
+/// A thin QR factorization `a = q * r`, with `q` of shape `rows x min(rows, cols)`
+/// having orthonormal columns and `r` upper triangular.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Qr<T> {
+    pub q: Matrix<T>,
+    pub r: Matrix<T>
+}
+
+/// A QR factorization with column pivoting, `a * p = q * r`.
+///
+/// `q` has orthonormal columns, `r` is upper triangular with diagonal entries of
+/// non-increasing magnitude, and column `j` of `a * p` is column `permutation[j]`
+/// of `a`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PivotedQr<T> {
+    /// `rows x min(rows, cols)`.
+    pub q: Matrix<T>,
+    /// `min(rows, cols) x cols`.
+    pub r: Matrix<T>,
+    pub permutation: Vec<usize>,
+    /// The number of diagonal entries of `r` above the rank tolerance.
+    pub rank: usize
+}
+
+impl<T: Float> PivotedQr<T> {
+    /// The numerical rank counting only diagonal entries of `r` larger than
+    /// `tolerance` in magnitude.
+    pub fn rank_with_tolerance(&self, tolerance: T) -> usize {
+        (0..self.r.rows.min(self.r.cols))
+            .take_while(|&i| self.r.get(i, i).abs() > tolerance)
+            .count()
+    }
+}
+
+/// Accumulates the thin `q` from the reflectors of a Householder QR.
+fn accumulate_q<T: Float>(rows: usize, reflectors: &[Householder<T>]) -> Matrix<T> {
+    let steps = reflectors.len();
+    let mut q = Matrix::new(rows, steps, T::zero());
+    for k in 0..steps {
+        q.set(k, k, T::one());
+    }
+    for (k, reflector) in reflectors.iter().enumerate().rev() {
+        reflector.apply_left(&mut q, k);
+    }
+    q
+}
+
+impl<T: Float> Matrix<T> {
+    /// The thin QR factorization by Householder reflections.
+    ///
+    /// Each step reflects the remainder of a column onto the diagonal, so `q` is
+    /// orthogonal to working precision even when the columns are nearly
+    /// dependent, unlike Gram-Schmidt.
+    pub fn qr(&self) -> Qr<T> {
+        let steps = self.rows.min(self.cols);
+        let mut r = self.clone();
+        let mut reflectors = vec![];
+        for k in 0..steps {
+            let reflector = householder_vector(&r.get_column(k)[k..]);
+            reflector.apply_left(&mut r, k);
+            for i in k + 1..self.rows {
+                r.set(i, k, T::zero());
+            }
+            reflectors.push(reflector);
+        }
+
+        r.data.truncate(steps * self.cols);
+        r.rows = steps;
+        Qr {
+            q: accumulate_q(self.rows, &reflectors),
+            r
+        }
+    }
+
+    /// The least-squares solution of `self * x = b`, for every column of `b`.
+    ///
+    /// A tall or square system minimizes `‖self * x - b‖` by solving
+    /// `r * x = qᵀ * b`; a wide one returns the solution of minimum norm, from
+    /// the QR factorization of `selfᵀ`. The matrix must have full rank, or
+    /// [`MatrixError::Singular`] is returned; see [`Matrix::qr_pivoted`] for
+    /// rank-deficient problems.
+    pub fn lstsq(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if b.rows != self.rows {
+            return Err(MatrixError::DimensionMismatch {
+                left: self.shape(),
+                right: b.shape()
+            });
+        }
+
+        // A diagonal entry of r this small relative to the largest means the
+        // columns are dependent to working precision.
+        let check_rank = |r: &Matrix<T>| {
+            let tolerance = T::from(self.rows.max(self.cols)).unwrap() * T::epsilon()
+                * (0..r.rows).fold(T::zero(), |max, i| max.max(r.get(i, i).abs()));
+            if (0..r.rows).any(|i| r.get(i, i).abs() <= tolerance) {
+                return Err(MatrixError::Singular);
+            }
+            Ok(())
+        };
+
+        if self.rows >= self.cols {
+            let Qr { q, r } = self.qr();
+            check_rank(&r)?;
+            let projected = transpose_mul(&q, b);
+            return r.solve_upper_triangular(&projected);
+        }
+
+        // self = rᵀ * qᵀ, so x = q * z with rᵀ * z = b is the solution in the row space.
+        let Qr { q, r } = self.transpose().qr();
+        check_rank(&r)?;
+        let z = r.transpose().solve_lower_triangular(b)?;
+        let mut x = Matrix::new(self.cols, b.cols, T::zero());
+        for i in 0..self.cols {
+            for j in 0..b.cols {
+                let val = (0..q.cols).fold(T::zero(), |total, k| total + *q.get(i, k) * *z.get(k, j));
+                x.set(i, j, val);
+            }
+        }
+        Ok(x)
+    }
+
+    /// The weighted least-squares solution, minimizing `Σ weights[i] * (self * x - b)[i]²`
+    /// for every column of `b`.
+    ///
+    /// Each row is scaled by the square root of its weight and the result passed
+    /// to [`Matrix::lstsq`], so a zero weight drops an observation entirely.
+    /// Negative or NaN weights give [`MatrixError::NotPositiveDefinite`].
+    pub fn lstsq_weighted(&self, b: &Matrix<T>, weights: &[T]) -> Result<Matrix<T>, MatrixError> {
+        if weights.len() != self.rows {
+            return Err(MatrixError::DimensionMismatch {
+                left: self.shape(),
+                right: (weights.len(), 1).into()
+            });
+        }
+        if weights.iter().any(|weight| weight.is_nan() || *weight < T::zero()) {
+            return Err(MatrixError::NotPositiveDefinite);
+        }
+
+        let factors: Vec<T> = weights.iter().map(|weight| weight.sqrt()).collect();
+        scale_rows(self, &factors).lstsq(&scale_rows(b, &factors))
+    }
+
+    /// The generalized least-squares solution for observations `b` whose errors
+    /// have the symmetric positive definite `covariance`, minimizing
+    /// `(self * x - b)ᵀ covariance⁻¹ (self * x - b)` for every column of `b`.
+    ///
+    /// With `covariance = l * lᵀ` the system is whitened to `l⁻¹ * self` and
+    /// `l⁻¹ * b`, whose errors are uncorrelated with unit variance, and then
+    /// solved by [`Matrix::lstsq`]. The inverse covariance is never formed.
+    pub fn lstsq_generalized(&self, b: &Matrix<T>, covariance: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if covariance.rows != self.rows {
+            return Err(MatrixError::DimensionMismatch {
+                left: self.shape(),
+                right: covariance.shape()
+            });
+        }
+
+        let l = covariance.cholesky()?.into_l();
+        let whitened = l.solve_lower_triangular(self)?;
+        whitened.lstsq(&l.solve_lower_triangular(b)?)
+    }
+
+    /// Householder QR with column pivoting (Businger-Golub).
+    ///
+    /// At each step the remaining column with the largest norm is moved into
+    /// the pivot position, so the magnitudes on the diagonal of `r` decrease and
+    /// reveal the numerical rank. The rank uses the tolerance
+    /// `max(rows, cols) * epsilon * |r[0][0]|`; see
+    /// [`PivotedQr::rank_with_tolerance`] to choose another.
+    pub fn qr_pivoted(&self) -> PivotedQr<T> {
+        let (m, n) = (self.rows, self.cols);
+        let steps = m.min(n);
+        let mut r = self.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut reflectors = vec![];
+
+        for k in 0..steps {
+            let norm = |r: &Matrix<T>, j: usize| (k..m).fold(T::zero(), |total, i| total + *r.get(i, j) * *r.get(i, j));
+            let pivot = (k..n)
+                .max_by(|&a, &b| nan_first_cmp(&norm(&r, a), &norm(&r, b)))
+                .unwrap();
+            if pivot != k {
+                for i in 0..m {
+                    r.data.swap(i * n + k, i * n + pivot);
+                }
+                permutation.swap(k, pivot);
+            }
+
+            let reflector = householder_vector(&r.get_column(k)[k..]);
+            reflector.apply_left(&mut r, k);
+            for i in k + 1..m {
+                r.set(i, k, T::zero());
+            }
+            reflectors.push(reflector);
+        }
+
+        let q = accumulate_q(m, &reflectors);
+        r.data.truncate(steps * n);
+        r.rows = steps;
+
+        let mut qr = PivotedQr {
+            q,
+            r,
+            permutation,
+            rank: 0
+        };
+        if steps > 0 {
+            let tolerance = T::from(m.max(n)).unwrap() * T::epsilon() * qr.r.get(0, 0).abs();
+            qr.rank = qr.rank_with_tolerance(tolerance);
+        }
+        qr
+    }
+}
+
+/// `aᵀ * b`
+fn transpose_mul<T: Float>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    let mut output = Matrix::new(a.cols, b.cols, T::zero());
+    for i in 0..a.cols {
+        for j in 0..b.cols {
+            let val = (0..a.rows).fold(T::zero(), |total, k| total + *a.get(k, i) * *b.get(k, j));
+            output.set(i, j, val);
+        }
+    }
+    output
+}
+
+/// `matrix` with row `i` multiplied by `factors[i]`.
+fn scale_rows<T: Float>(matrix: &Matrix<T>, factors: &[T]) -> Matrix<T> {
+    let mut output = matrix.clone();
+    for (row, &factor) in output.data.chunks_mut(matrix.cols.max(1)).zip(factors.iter()) {
+        for val in row.iter_mut() {
+            *val = *val * factor;
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_factorization(matrix: &Matrix<f64>, qr: &PivotedQr<f64>) {
+        let product = &qr.q * &qr.r;
+        for i in 0..matrix.rows {
+            for j in 0..matrix.cols {
+                assert!((product.get(i, j) - matrix.get(i, qr.permutation[j])).abs() < 1e-12);
+            }
+        }
+        let gram = qr.q.transpose() * &qr.q;
+        for i in 0..gram.rows {
+            for j in 0..gram.cols {
+                assert!((gram.get(i, j) - if i == j { 1.0 } else { 0.0 }).abs() < 1e-12);
+            }
+        }
+        for i in 0..qr.r.rows {
+            for j in 0..i {
+                assert_eq!(*qr.r.get(i, j), 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn householder_qr() {
+        let matrix = Matrix::from(vec![
+            vec![12.0, -51.0, 4.0],
+            vec![6.0, 167.0, -68.0],
+            vec![-4.0, 24.0, -41.0],
+            vec![1.0, 1.0, 1.0]
+        ]);
+
+        let qr = matrix.qr();
+
+        assert_eq!((qr.q.rows, qr.q.cols, qr.r.rows, qr.r.cols), (4, 3, 3, 3));
+        assert_factorization(&matrix, &PivotedQr { q: qr.q, r: qr.r, permutation: vec![0, 1, 2], rank: 3 });
+    }
+
+    #[test]
+    fn least_squares_line_fit() {
+        // y = 1 + 2x with symmetric noise that cancels in the normal equations.
+        let design = Matrix::from(vec![
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![1.0, 2.0],
+            vec![1.0, 3.0]
+        ]);
+        let observations = Matrix::from(vec![vec![1.1], vec![2.9], vec![5.1], vec![6.9]]);
+
+        let coefficients = design.lstsq(&observations).unwrap();
+
+        assert!((coefficients.get(0, 0) - 1.06).abs() < 1e-12);
+        assert!((coefficients.get(1, 0) - 1.96).abs() < 1e-12);
+        // The residual is orthogonal to the columns of the design matrix.
+        let residual = &observations - &(&design * &coefficients);
+        let normal = design.transpose() * &residual;
+        assert!(normal.iter().all(|val| val.abs() < 1e-12));
+    }
+
+    #[test]
+    fn least_squares_edge_cases() {
+        let wide = Matrix::from(vec![vec![1.0, 1.0]]);
+        let minimum_norm = wide.lstsq(&Matrix::from(vec![vec![2.0]])).unwrap();
+        assert!((minimum_norm.get(0, 0) - 1.0).abs() < 1e-12 && (minimum_norm.get(1, 0) - 1.0).abs() < 1e-12);
+
+        let deficient = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]);
+        assert_eq!(deficient.lstsq(&Matrix::new(3, 1, 1.0)), Err(MatrixError::Singular));
+        assert!(matches!(deficient.lstsq(&Matrix::new(2, 1, 1.0)), Err(MatrixError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn weighted_least_squares() {
+        let design = Matrix::from(vec![
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![1.0, 2.0],
+            vec![1.0, 3.0],
+            vec![1.0, 4.0]
+        ]);
+        // y = 1 + 2x exactly, apart from an outlier in the last observation.
+        let observations = Matrix::from(vec![vec![1.0], vec![3.0], vec![5.0], vec![7.0], vec![30.0]]);
+
+        let ignored = design.lstsq_weighted(&observations, &[1.0, 1.0, 1.0, 1.0, 0.0]).unwrap();
+        assert!((ignored.get(0, 0) - 1.0).abs() < 1e-12 && (ignored.get(1, 0) - 2.0).abs() < 1e-12);
+
+        // The weighted normal equations Xᵀ W (y - X β) = 0 hold.
+        let weights = [1.0, 2.0, 0.5, 4.0, 0.1];
+        let coefficients = design.lstsq_weighted(&observations, &weights).unwrap();
+        let residual = &observations - &(&design * &coefficients);
+        for j in 0..2 {
+            let total: f64 = (0..5).map(|i| design.get(i, j) * weights[i] * residual.get(i, 0)).sum();
+            assert!(total.abs() < 1e-10);
+        }
+
+        // A diagonal covariance is the same as weighting by the inverse variances.
+        let covariance = Matrix::from_diagonal(&weights.iter().map(|weight| 1.0 / weight).collect::<Vec<_>>());
+        let generalized = design.lstsq_generalized(&observations, &covariance).unwrap();
+        assert!(generalized.iter().zip(coefficients.iter()).all(|(a, b)| (a - b).abs() < 1e-10));
+
+        assert_eq!(design.lstsq_weighted(&observations, &[1.0, 1.0, -1.0, 1.0, 1.0]), Err(MatrixError::NotPositiveDefinite));
+        assert!(matches!(design.lstsq_weighted(&observations, &[1.0; 3]), Err(MatrixError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn generalized_least_squares_with_correlated_errors() {
+        let design = Matrix::from(vec![
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![1.0, 2.0],
+            vec![1.0, 3.0]
+        ]);
+        let observations = Matrix::from(vec![vec![0.8], vec![3.3], vec![4.9], vec![7.2]]);
+        // AR(1) errors with correlation 0.6 between neighbouring observations.
+        let covariance = Matrix::from_fn(4, 4, |i, j| 0.6f64.powi((i as i32 - j as i32).abs()));
+
+        let coefficients = design.lstsq_generalized(&observations, &covariance).unwrap();
+
+        // β = (Xᵀ Σ⁻¹ X)⁻¹ Xᵀ Σ⁻¹ y
+        let precision = covariance.inverse().unwrap();
+        let xt_precision = design.transpose() * &precision;
+        let expected = (&xt_precision * &design).inverse().unwrap() * &(&xt_precision * &observations);
+        assert!(coefficients.iter().zip(expected.iter()).all(|(a, b)| (a - b).abs() < 1e-10));
+
+        let indefinite = Matrix::from_fn(4, 4, |i, j| if i == j { 1.0 } else { 2.0 });
+        assert_eq!(design.lstsq_generalized(&observations, &indefinite), Err(MatrixError::NotPositiveDefinite));
+        assert!(matches!(design.lstsq_generalized(&observations, &Matrix::identity(3)), Err(MatrixError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn pivoted_qr_of_full_rank_matrix() {
+        let matrix = Matrix::from(vec![
+            vec![1.0, 10.0, 2.0],
+            vec![2.0, 1.0, 0.0],
+            vec![0.0, 3.0, 1.0],
+            vec![1.0, 0.0, 4.0]
+        ]);
+
+        let qr = matrix.qr_pivoted();
+
+        assert_factorization(&matrix, &qr);
+        assert_eq!(qr.permutation[0], 1);
+        assert_eq!(qr.rank, 3);
+        for i in 1..3 {
+            assert!(qr.r.get(i, i).abs() <= qr.r.get(i - 1, i - 1).abs());
+        }
+    }
+
+    #[test]
+    fn pivoted_qr_reveals_rank() {
+        // The third column is the sum of the first two and the fourth is a multiple of the first.
+        let matrix = Matrix::from(vec![
+            vec![1.0, 2.0, 3.0, 2.0],
+            vec![0.0, 1.0, 1.0, 0.0],
+            vec![1.0, 0.0, 1.0, 2.0],
+            vec![2.0, 1.0, 3.0, 4.0],
+            vec![1.0, 1.0, 2.0, 2.0]
+        ]);
+
+        let qr = matrix.qr_pivoted();
+
+        assert_factorization(&matrix, &qr);
+        assert_eq!(qr.rank, 2);
+        assert!(qr.r.get(2, 2).abs() < 1e-12);
+        assert_eq!(qr.rank_with_tolerance(qr.r.get(1, 1).abs()), 1);
+    }
+
+    #[test]
+    fn pivoted_qr_of_wide_matrix() {
+        let matrix = Matrix::from(vec![
+            vec![1.0, 2.0, 0.0, 5.0],
+            vec![3.0, 1.0, 1.0, 0.0]
+        ]);
+
+        let qr = matrix.qr_pivoted();
+
+        assert_eq!((qr.q.rows, qr.q.cols, qr.r.rows, qr.r.cols), (2, 2, 2, 4));
+        assert_factorization(&matrix, &qr);
+        assert_eq!(qr.rank, 2);
+    }
+
+    #[test]
+    fn pivoted_qr_with_nan_does_not_panic() {
+        let matrix = Matrix::from(vec![
+            vec![f64::NAN, 1.0],
+            vec![0.0, 2.0]
+        ]);
+
+        let qr = matrix.qr_pivoted();
+        // The column holding NaN has a NaN norm and is pivoted last.
+        assert_eq!(qr.permutation, vec![1, 0]);
+    }
+}
@@ -0,0 +1,98 @@
+use num::Float;
+use rand::{Rng, SeedableRng};
+use rand::distributions::Distribution;
+use rand::rngs::StdRng;
+
+use crate::Matrix;
+
+/// The normal distribution with the given mean and standard deviation, for
+/// any floating-point element type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal<T> {
+    mean: T,
+    std_dev: T
+}
+
+impl<T: Float> Normal<T> {
+    pub fn new(mean: T, std_dev: T) -> Normal<T> {
+        assert!(std_dev >= T::zero() && std_dev.is_finite(), "standard deviation must be finite and non-negative");
+        Normal {
+            mean,
+            std_dev
+        }
+    }
+
+    /// The standard normal distribution, with mean 0 and standard deviation 1.
+    pub fn standard() -> Normal<T> {
+        Normal::new(T::zero(), T::one())
+    }
+}
+
+impl<T: Float> Distribution<T> for Normal<T> {
+    /// Draws a sample by the Box-Muller transform.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        let u1: f64 = 1.0 - rng.gen::<f64>();
+        let u2: f64 = rng.gen();
+        let standard = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+        self.mean + self.std_dev * T::from(standard).unwrap()
+    }
+}
+
+impl<T> Matrix<T> {
+    /// A matrix of independent samples from `distribution`, drawn in row-major order.
+    ///
+    /// Any [`rand`] distribution works, e.g. `Uniform::new(0, 10)` for integers
+    /// or `Uniform::new(-1.0, 1.0)` for floats, as does [`Normal`].
+    pub fn random<R, D>(rows: usize, cols: usize, rng: &mut R, distribution: D) -> Matrix<T>
+        where R: Rng + ?Sized, D: Distribution<T>
+    {
+        Matrix {
+            rows,
+            cols,
+            data: distribution.sample_iter(rng).take(rows * cols).collect()
+        }
+    }
+
+    /// [`Matrix::random`] with a generator seeded from `seed`, so the same seed
+    /// always gives the same matrix.
+    pub fn random_seeded<D: Distribution<T>>(rows: usize, cols: usize, seed: u64, distribution: D) -> Matrix<T> {
+        Matrix::random(rows, cols, &mut StdRng::seed_from_u64(seed), distribution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::distributions::Uniform;
+
+    use super::*;
+
+    #[test]
+    fn random_uniform_matrix() {
+        let matrix = Matrix::random(5, 4, &mut rand::thread_rng(), Uniform::new(0, 10));
+
+        assert_eq!(matrix.shape(), (5, 4).into());
+        assert_eq!(matrix.data.len(), 20);
+        assert!(matrix.iter().all(|val| (0..10).contains(val)));
+    }
+
+    #[test]
+    fn seeded_matrices_are_reproducible() {
+        let a = Matrix::random_seeded(3, 3, 42, Uniform::new(-1.0, 1.0));
+        let b = Matrix::random_seeded(3, 3, 42, Uniform::new(-1.0, 1.0));
+        let c = Matrix::random_seeded(3, 3, 43, Uniform::new(-1.0, 1.0));
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn normal_samples_have_requested_moments() {
+        let matrix: Matrix<f64> = Matrix::random_seeded(100, 100, 7, Normal::new(3.0, 2.0));
+
+        let n = matrix.data.len() as f64;
+        let mean = matrix.iter().sum::<f64>() / n;
+        let variance = matrix.iter().map(|val| (val - mean) * (val - mean)).sum::<f64>() / n;
+        assert!((mean - 3.0).abs() < 0.05, "{}", mean);
+        assert!((variance.sqrt() - 2.0).abs() < 0.05, "{}", variance);
+    }
+}
@@ -0,0 +1,157 @@
+use std::cmp::Ordering;
+
+use num::Float;
+
+use crate::{Axis, Matrix};
+use crate::element::MatrixElement;
+
+/// The index of the first element `wanted` compared to every other, skipping
+/// elements that aren't comparable to themselves, such as NaN.
+fn arg_extreme<'a, T, I>(values: I, wanted: Ordering) -> Option<usize>
+    where T: PartialOrd + 'a,
+          I: Iterator<Item = &'a T>
+{
+    let mut best: Option<(usize, &T)> = None;
+    for (index, val) in values.enumerate().filter(|(_, val)| val.partial_cmp(val).is_some()) {
+        match best {
+            Some((_, current)) if val.partial_cmp(current) != Some(wanted) => {},
+            _ => best = Some((index, val))
+        }
+    }
+    best.map(|(index, _)| index)
+}
+
+impl<T: MatrixElement> Matrix<T> {
+    /// The sum of every element, or zero for an empty matrix.
+    pub fn sum(&self) -> T {
+        self.data.iter().fold(T::zero(), |total, val| total + val.clone())
+    }
+
+    /// The sum of the main diagonal, which for a rectangular matrix stops at
+    /// the shorter dimension.
+    pub fn trace(&self) -> T {
+        (0..self.rows.min(self.cols)).fold(T::zero(), |total, i| total + self.get(i, i).clone())
+    }
+
+    /// The sum of each row (`Axis::Rows`) or column (`Axis::Cols`).
+    pub fn sum_axis(&self, axis: Axis) -> Vec<T> {
+        (0..self.lane_count(axis))
+            .map(|index| self.lane(axis, index).into_iter().fold(T::zero(), |total, val| total + val))
+            .collect()
+    }
+
+    /// The sum of each row, one entry per row.
+    pub fn sum_rows(&self) -> Vec<T> {
+        self.sum_axis(Axis::Rows)
+    }
+
+    /// The sum of each column, one entry per column.
+    pub fn sum_columns(&self) -> Vec<T> {
+        self.sum_axis(Axis::Cols)
+    }
+}
+
+/// Extremes and their positions. Elements that aren't comparable to
+/// themselves, such as NaN, are skipped, and ties go to the first element in
+/// row-major order.
+impl<T: Clone + PartialOrd> Matrix<T> {
+    /// The position `(row, col)` of the smallest element, or `None` if there
+    /// is nothing to compare.
+    pub fn argmin(&self) -> Option<(usize, usize)> {
+        arg_extreme(self.data.iter(), Ordering::Less).map(|index| (index / self.cols, index % self.cols))
+    }
+
+    /// The position `(row, col)` of the largest element, or `None` if there
+    /// is nothing to compare.
+    pub fn argmax(&self) -> Option<(usize, usize)> {
+        arg_extreme(self.data.iter(), Ordering::Greater).map(|index| (index / self.cols, index % self.cols))
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.argmin().map(|(row, col)| self.get(row, col).clone())
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.argmax().map(|(row, col)| self.get(row, col).clone())
+    }
+
+    /// The index of the smallest element within each row (`Axis::Rows`) or
+    /// column (`Axis::Cols`).
+    pub fn argmin_axis(&self, axis: Axis) -> Vec<Option<usize>> {
+        (0..self.lane_count(axis)).map(|index| arg_extreme(self.lane(axis, index).iter(), Ordering::Less)).collect()
+    }
+
+    /// The index of the largest element within each row or column, e.g. the
+    /// predicted class of each row of classifier scores.
+    pub fn argmax_axis(&self, axis: Axis) -> Vec<Option<usize>> {
+        (0..self.lane_count(axis)).map(|index| arg_extreme(self.lane(axis, index).iter(), Ordering::Greater)).collect()
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// The mean of every element, or NaN for an empty matrix.
+    pub fn mean(&self) -> T {
+        self.data.iter().fold(T::zero(), |total, &val| total + val) / T::from(self.data.len()).unwrap()
+    }
+
+    /// The mean of each row, one entry per row; shorthand for `mean_axis(Axis::Rows)`.
+    pub fn mean_rows(&self) -> Vec<T> {
+        self.mean_axis(Axis::Rows)
+    }
+
+    /// The mean of each column, one entry per column; shorthand for `mean_axis(Axis::Cols)`.
+    pub fn mean_columns(&self) -> Vec<T> {
+        self.mean_axis(Axis::Cols)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sums_and_trace() {
+        let matrix = Matrix::from(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6]
+        ]);
+
+        assert_eq!(matrix.sum(), 21);
+        assert_eq!(matrix.trace(), 6);
+        assert_eq!(matrix.sum_rows(), vec![6, 15]);
+        assert_eq!(matrix.sum_columns(), vec![5, 7, 9]);
+        assert_eq!(Matrix::<i32>::new(0, 3, 0).sum(), 0);
+        assert_eq!(Matrix::<i32>::new(0, 3, 0).sum_columns(), vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn extremes_and_positions() {
+        let matrix = Matrix::from(vec![
+            vec![3.0, f64::NAN, -1.0],
+            vec![7.0, -1.0, 7.0]
+        ]);
+
+        assert_eq!(matrix.argmax(), Some((1, 0)));
+        assert_eq!(matrix.argmin(), Some((0, 2)));
+        assert_eq!((matrix.min(), matrix.max()), (Some(-1.0), Some(7.0)));
+        assert_eq!(matrix.argmax_axis(Axis::Rows), vec![Some(0), Some(0)]);
+        assert_eq!(matrix.argmin_axis(Axis::Cols), vec![Some(0), Some(1), Some(0)]);
+
+        let nan = Matrix::new(2, 1, f64::NAN);
+        assert_eq!(nan.max(), None);
+        assert_eq!(nan.argmax_axis(Axis::Cols), vec![None]);
+        assert_eq!(Matrix::<i32>::new(0, 0, 0).argmin(), None);
+    }
+
+    #[test]
+    fn means() {
+        let matrix: Matrix<f64> = Matrix::from(vec![
+            vec![1.0, 2.0],
+            vec![3.0, 6.0]
+        ]);
+
+        assert_eq!(matrix.mean(), 3.0);
+        assert_eq!(matrix.mean_rows(), vec![1.5, 4.5]);
+        assert_eq!(matrix.mean_columns(), vec![2.0, 4.0]);
+    }
+}
@@ -0,0 +1,191 @@
+use num::Float;
+
+use crate::Matrix;
+
+/// An elementary reflector `H = I - beta * v * v^T` with `v[0] == 1`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Householder<T> {
+    pub v: Vec<T>,
+    pub beta: T,
+    /// The value `H * x` leaves in the first position; every other entry becomes zero.
+    pub alpha: T
+}
+
+/// Computes the reflector that maps `x` onto a multiple of the first unit vector.
+///
+/// Follows Golub & Van Loan (Algorithm 5.1.1), choosing the sign of `v[0]` to
+/// avoid cancellation. When `x` is already a non-negative multiple of `e1` the
+/// reflector is the identity (`beta == 0`).
+pub fn householder_vector<T: Float>(x: &[T]) -> Householder<T> {
+    assert!(!x.is_empty());
+
+    let mut v = x.to_vec();
+    v[0] = T::one();
+    let sigma = x[1..].iter().fold(T::zero(), |total, val| total + *val * *val);
+
+    if sigma == T::zero() {
+        return if x[0] >= T::zero() {
+            Householder { v, beta: T::zero(), alpha: x[0] }
+        } else {
+            Householder { v, beta: T::one() + T::one(), alpha: -x[0] }
+        };
+    }
+
+    let mu = (x[0] * x[0] + sigma).sqrt();
+    let v0 = if x[0] <= T::zero() { x[0] - mu } else { -sigma / (x[0] + mu) };
+    let beta = (T::one() + T::one()) * v0 * v0 / (sigma + v0 * v0);
+    for val in v[1..].iter_mut() {
+        *val = *val / v0;
+    }
+
+    Householder {
+        v,
+        beta,
+        alpha: mu
+    }
+}
+
+impl<T: Float> Householder<T> {
+    /// Replaces `matrix` with `H * matrix`, where `H` acts on rows `offset..offset + v.len()`.
+    pub fn apply_left(&self, matrix: &mut Matrix<T>, offset: usize) {
+        assert!(offset + self.v.len() <= matrix.rows);
+
+        for j in 0..matrix.cols {
+            let mut dot = T::zero();
+            for (k, v_k) in self.v.iter().enumerate() {
+                dot = dot + *v_k * *matrix.get(offset + k, j);
+            }
+            let scale = self.beta * dot;
+            for (k, v_k) in self.v.iter().enumerate() {
+                let val = *matrix.get(offset + k, j);
+                matrix.set(offset + k, j, val - scale * *v_k);
+            }
+        }
+    }
+
+    /// Replaces `matrix` with `matrix * H`, where `H` acts on columns `offset..offset + v.len()`.
+    pub fn apply_right(&self, matrix: &mut Matrix<T>, offset: usize) {
+        assert!(offset + self.v.len() <= matrix.cols);
+
+        for i in 0..matrix.rows {
+            let mut dot = T::zero();
+            for (k, v_k) in self.v.iter().enumerate() {
+                dot = dot + *matrix.get(i, offset + k) * *v_k;
+            }
+            let scale = self.beta * dot;
+            for (k, v_k) in self.v.iter().enumerate() {
+                let val = *matrix.get(i, offset + k);
+                matrix.set(i, offset + k, val - scale * *v_k);
+            }
+        }
+    }
+}
+
+/// A plane rotation `G = [c s; -s c]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Givens<T> {
+    pub c: T,
+    pub s: T,
+    /// The value `G * [a; b]` leaves in the first position.
+    pub r: T
+}
+
+/// Computes the rotation with `G * [a; b] = [r; 0]`.
+pub fn givens<T: Float>(a: T, b: T) -> Givens<T> {
+    if b == T::zero() {
+        return Givens { c: T::one(), s: T::zero(), r: a };
+    }
+
+    let r = a.hypot(b);
+    Givens {
+        c: a / r,
+        s: b / r,
+        r
+    }
+}
+
+impl<T: Float> Givens<T> {
+    /// Replaces rows `i` and `k` of `matrix` with `G` applied to them.
+    pub fn apply_left(&self, matrix: &mut Matrix<T>, i: usize, k: usize) {
+        for j in 0..matrix.cols {
+            let x = *matrix.get(i, j);
+            let y = *matrix.get(k, j);
+            matrix.set(i, j, self.c * x + self.s * y);
+            matrix.set(k, j, self.c * y - self.s * x);
+        }
+    }
+
+    /// Replaces columns `i` and `k` of `matrix` with `matrix * G^T`.
+    ///
+    /// Together with [`Givens::apply_left`] this performs the similarity `G * A * G^T`.
+    pub fn apply_right(&self, matrix: &mut Matrix<T>, i: usize, k: usize) {
+        for row in 0..matrix.rows {
+            let x = *matrix.get(row, i);
+            let y = *matrix.get(row, k);
+            matrix.set(row, i, self.c * x + self.s * y);
+            matrix.set(row, k, self.c * y - self.s * x);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-10, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn householder_zeroes_column() {
+        let x = [3.0, 1.0, 5.0, 1.0];
+        let reflector = householder_vector(&x);
+
+        let mut matrix = Matrix::from(vec![
+            vec![3.0],
+            vec![1.0],
+            vec![5.0],
+            vec![1.0]
+        ]);
+        reflector.apply_left(&mut matrix, 0);
+
+        assert_close(*matrix.get(0, 0), 6.0);
+        assert_close(reflector.alpha, 6.0);
+        for i in 1..4 {
+            assert_close(*matrix.get(i, 0), 0.0);
+        }
+    }
+
+    #[test]
+    fn householder_is_orthogonal() {
+        let reflector = householder_vector(&[-2.0, 1.0, 2.0]);
+
+        let identity = Matrix::identity(3);
+        let mut h = identity.clone();
+        reflector.apply_left(&mut h, 0);
+        reflector.apply_right(&mut h, 0);
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_close(*h.get(i, j), *identity.get(i, j));
+            }
+        }
+    }
+
+    #[test]
+    fn givens_zeroes_entry() {
+        let rotation = givens(3.0, 4.0);
+        let mut matrix = Matrix::from(vec![
+            vec![3.0, 1.0],
+            vec![4.0, 2.0]
+        ]);
+
+        rotation.apply_left(&mut matrix, 0, 1);
+
+        assert_close(rotation.r, 5.0);
+        assert_close(*matrix.get(0, 0), 5.0);
+        assert_close(*matrix.get(1, 0), 0.0);
+        assert_close(*matrix.get(0, 1), 2.2);
+        assert_close(*matrix.get(1, 1), 0.4);
+    }
+}
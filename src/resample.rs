@@ -0,0 +1,149 @@
+use num::{Float, NumCast};
+
+use crate::Matrix;
+
+/// How [`Matrix::downsample`] reduces each `factor x factor` block to one element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    Mean,
+    Max,
+    /// Keeps the top-left element of each block.
+    Stride
+}
+
+/// How [`Matrix::upsample`] fills in the new elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interp {
+    Nearest,
+    /// Interpolates between the four nearest elements, treating each element as
+    /// the centre of its cell and clamping at the edges.
+    Bilinear
+}
+
+/// The source coordinate of output index `index` under half-cell alignment,
+/// returned as the two neighbouring source indices and the weight of the second.
+fn bilinear_source<T: Float>(index: usize, factor: usize, len: usize) -> (usize, usize, T) {
+    let position = (index as f64 + 0.5) / factor as f64 - 0.5;
+    let position = position.max(0.0).min((len - 1) as f64);
+    let low = position.floor() as usize;
+    let high = (low + 1).min(len - 1);
+    (low, high, <T as NumCast>::from(position - low as f64).unwrap())
+}
+
+impl<T: Float> Matrix<T> {
+    /// Shrinks the matrix by `factor` along both axes.
+    ///
+    /// Each output element summarises a `factor x factor` block. When the size is
+    /// not a multiple of `factor`, the last blocks are partial and only their
+    /// available elements are used.
+    pub fn downsample(&self, factor: usize, aggregation: Aggregation) -> Matrix<T> {
+        assert!(factor > 0, "downsampling factor must be positive");
+
+        let rows = self.rows.div_ceil(factor);
+        let cols = self.cols.div_ceil(factor);
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                let block_rows = i * factor..((i + 1) * factor).min(self.rows);
+                let block_cols = j * factor..((j + 1) * factor).min(self.cols);
+                let block = block_rows.flat_map(|r| block_cols.clone().map(move |c| (r, c)));
+                let val = match aggregation {
+                    Aggregation::Stride => *self.get(i * factor, j * factor),
+                    Aggregation::Max => block.map(|(r, c)| *self.get(r, c)).fold(T::neg_infinity(), T::max),
+                    Aggregation::Mean => {
+                        let (total, count) = block.fold((T::zero(), 0), |(total, count), (r, c)| {
+                            (total + *self.get(r, c), count + 1)
+                        });
+                        total / T::from(count).unwrap()
+                    }
+                };
+                data.push(val);
+            }
+        }
+
+        Matrix {
+            rows,
+            cols,
+            data
+        }
+    }
+
+    /// Enlarges the matrix by `factor` along both axes.
+    pub fn upsample(&self, factor: usize, interp: Interp) -> Matrix<T> {
+        assert!(factor > 0, "upsampling factor must be positive");
+
+        let rows = self.rows * factor;
+        let cols = self.cols * factor;
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                let val = match interp {
+                    Interp::Nearest => *self.get(i / factor, j / factor),
+                    Interp::Bilinear => {
+                        let (r0, r1, dr) = bilinear_source::<T>(i, factor, self.rows);
+                        let (c0, c1, dc) = bilinear_source::<T>(j, factor, self.cols);
+                        let top = *self.get(r0, c0) * (T::one() - dc) + *self.get(r0, c1) * dc;
+                        let bottom = *self.get(r1, c0) * (T::one() - dc) + *self.get(r1, c1) * dc;
+                        top * (T::one() - dr) + bottom * dr
+                    }
+                };
+                data.push(val);
+            }
+        }
+
+        Matrix {
+            rows,
+            cols,
+            data
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn downsample_aggregations() {
+        let matrix = Matrix::from(vec![
+            vec![1.0, 2.0, 3.0, 4.0, 5.0],
+            vec![5.0, 6.0, 7.0, 8.0, 9.0],
+            vec![9.0, 10.0, 11.0, 12.0, 13.0]
+        ]);
+
+        let mean = matrix.downsample(2, Aggregation::Mean);
+        assert_eq!((mean.rows, mean.cols), (2, 3));
+        assert_eq!(mean.data, vec![3.5, 5.5, 7.0, 9.5, 11.5, 13.0]);
+
+        let max = matrix.downsample(2, Aggregation::Max);
+        assert_eq!(max.data, vec![6.0, 8.0, 9.0, 10.0, 12.0, 13.0]);
+
+        let stride = matrix.downsample(2, Aggregation::Stride);
+        assert_eq!(stride.data, vec![1.0, 3.0, 5.0, 9.0, 11.0, 13.0]);
+    }
+
+    #[test]
+    fn upsample_interpolations() {
+        let matrix = Matrix::from(vec![
+            vec![0.0, 4.0],
+            vec![8.0, 12.0]
+        ]);
+
+        let nearest = matrix.upsample(2, Interp::Nearest);
+        let expected_matrix = Matrix::from(vec![
+            vec![0.0, 0.0, 4.0, 4.0],
+            vec![0.0, 0.0, 4.0, 4.0],
+            vec![8.0, 8.0, 12.0, 12.0],
+            vec![8.0, 8.0, 12.0, 12.0]
+        ]);
+        assert_eq!(nearest, expected_matrix);
+
+        let bilinear = matrix.upsample(2, Interp::Bilinear);
+        assert_eq!(bilinear.get_row(0), vec![0.0, 1.0, 3.0, 4.0]);
+        assert_eq!(bilinear.get_column(0), vec![0.0, 2.0, 6.0, 8.0]);
+        assert_eq!(*bilinear.get(1, 1), 3.0);
+
+        // Downsampling by the mean undoes nearest-neighbour upsampling.
+        assert_eq!(nearest.downsample(2, Aggregation::Mean), matrix);
+    }
+}
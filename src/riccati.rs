@@ -0,0 +1,177 @@
+use std::fmt::Debug;
+
+use num::Float;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+use crate::sign::{one_norm, sign_iteration};
+
+const MAX_DOUBLING_STEPS: usize = 100;
+
+fn symmetrize<T: Float>(matrix: &mut Matrix<T>) {
+    let half = T::from(0.5).unwrap();
+    for i in 0..matrix.rows {
+        for j in 0..i {
+            let mean = (*matrix.get(i, j) + *matrix.get(j, i)) * half;
+            matrix.set(i, j, mean);
+            matrix.set(j, i, mean);
+        }
+    }
+}
+
+/// Checks the shapes of a Riccati equation: `a` and `q` are `n x n`, `b` is
+/// `n x m` and `r` is `m x m`. Returns `g = b * r⁻¹ * bᵀ`.
+fn check_riccati<T: Float + Debug>(a: &Matrix<T>, b: &Matrix<T>, q: &Matrix<T>, r: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+    if a.rows != a.cols {
+        return Err(MatrixError::NotSquare { rows: a.rows, cols: a.cols });
+    }
+    a.shape().elementwise_with(q.shape())?;
+    if b.rows != a.rows {
+        return Err(MatrixError::DimensionMismatch { left: a.shape(), right: b.shape() });
+    }
+    if r.rows != b.cols || r.cols != b.cols {
+        return Err(MatrixError::DimensionMismatch { left: b.shape(), right: r.shape() });
+    }
+    Ok(b * r.solve(&b.transpose())?)
+}
+
+impl<T: Float + Debug> Matrix<T> {
+    /// Solves the continuous algebraic Riccati equation
+    /// `aᵀ * x + x * a - x * b * r⁻¹ * bᵀ * x + q = 0` for its stabilizing solution.
+    ///
+    /// The stable invariant subspace of the Hamiltonian `[[a, -g], [-q, -aᵀ]]`,
+    /// with `g = b * r⁻¹ * bᵀ`, is found through the matrix sign function, and
+    /// `x` solves the resulting overdetermined but consistent system in the
+    /// least-squares sense. The optimal LQR gain is then `r⁻¹ * bᵀ * x`. Requires
+    /// `(a, b)` stabilizable and `(q, a)` detectable, so that the Hamiltonian has
+    /// no eigenvalues on the imaginary axis.
+    pub fn solve_care(a: &Matrix<T>, b: &Matrix<T>, q: &Matrix<T>, r: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let g = check_riccati(a, b, q, r)?;
+        let n = a.rows;
+
+        let mut hamiltonian = Matrix::new(2 * n, 2 * n, T::zero());
+        for i in 0..n {
+            for j in 0..n {
+                hamiltonian.set(i, j, *a.get(i, j));
+                hamiltonian.set(i, n + j, -*g.get(i, j));
+                hamiltonian.set(n + i, j, -*q.get(i, j));
+                hamiltonian.set(n + i, n + j, -*a.get(j, i));
+            }
+        }
+        let sign = sign_iteration(&hamiltonian)?;
+
+        // [w12; w22 + I] * x = -[w11 + I; w21]
+        let mut left = Matrix::new(2 * n, n, T::zero());
+        let mut right = Matrix::new(2 * n, n, T::zero());
+        for i in 0..2 * n {
+            for j in 0..n {
+                let diagonal = if i % n == j { T::one() } else { T::zero() };
+                left.set(i, j, *sign.get(i, n + j) + if i >= n { diagonal } else { T::zero() });
+                right.set(i, j, -(*sign.get(i, j) + if i < n { diagonal } else { T::zero() }));
+            }
+        }
+        let mut x = left.lstsq(&right)?;
+        symmetrize(&mut x);
+        Ok(x)
+    }
+
+    /// Solves the discrete algebraic Riccati equation
+    /// `x = aᵀ * x * a - aᵀ * x * b * (r + bᵀ * x * b)⁻¹ * bᵀ * x * a + q` for its
+    /// stabilizing solution.
+    ///
+    /// Uses the structure-preserving doubling algorithm, which converges
+    /// quadratically; each step doubles the horizon of the underlying Riccati
+    /// recursion. The optimal LQR gain is then `(r + bᵀ * x * b)⁻¹ * bᵀ * x * a`.
+    pub fn solve_dare(a: &Matrix<T>, b: &Matrix<T>, q: &Matrix<T>, r: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let mut g = check_riccati(a, b, q, r)?;
+        let mut a = a.clone();
+        let mut h = q.clone();
+        let identity = Matrix::identity(a.rows);
+
+        for _ in 0..MAX_DOUBLING_STEPS {
+            let w = &identity + &(&g * &h);
+            let lu = w.lu()?;
+            let w_inv_a = lu.solve(&a)?;
+            let w_inv_g = lu.solve(&g)?;
+            let a_transpose = a.transpose();
+
+            let next_h = &h + &(&(&a_transpose * &h) * &w_inv_a);
+            g = &g + &(&(&a * &w_inv_g) * &a_transpose);
+            a = &a * &w_inv_a;
+
+            let change = one_norm(&(&next_h - &h));
+            h = next_h;
+            if change <= T::epsilon() * T::from(10.0).unwrap() * one_norm(&h) {
+                symmetrize(&mut h);
+                return Ok(h);
+            }
+        }
+        Err(MatrixError::NoConvergence { iterations: MAX_DOUBLING_STEPS })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_small(matrix: &Matrix<f64>, tolerance: f64) {
+        assert!(matrix.iter().all(|val| val.abs() < tolerance), "{:?}", matrix);
+    }
+
+    fn system() -> (Matrix<f64>, Matrix<f64>, Matrix<f64>, Matrix<f64>) {
+        // An unstable double integrator with a position penalty.
+        let a = Matrix::from(vec![
+            vec![0.0, 1.0],
+            vec![0.0, 0.0]
+        ]);
+        let b = Matrix::from(vec![vec![0.0], vec![1.0]]);
+        let q = Matrix::from(vec![
+            vec![1.0, 0.0],
+            vec![0.0, 0.0]
+        ]);
+        let r = Matrix::from(vec![vec![1.0]]);
+        (a, b, q, r)
+    }
+
+    #[test]
+    fn continuous_riccati() {
+        let (a, b, q, r) = system();
+
+        let x = Matrix::solve_care(&a, &b, &q, &r).unwrap();
+
+        let gain = &b.transpose() * &x;
+        let residual = &(&(&a.transpose() * &x) + &(&x * &a)) - &(&(&x * &b) * &gain);
+        assert_small(&(&residual + &q), 1e-10);
+        // The known solution is [[√2, 1], [1, √2]].
+        let root2 = 2.0f64.sqrt();
+        assert_small(&(&x - &Matrix::from(vec![vec![root2, 1.0], vec![1.0, root2]])), 1e-10);
+    }
+
+    #[test]
+    fn discrete_riccati() {
+        let (a, _, q, r) = system();
+        // Zero-order hold discretization with a unit step.
+        let a = &Matrix::from(vec![vec![1.0, 0.0], vec![0.0, 1.0]]) + &a;
+        let b = Matrix::from(vec![vec![0.5], vec![1.0]]);
+
+        let x = Matrix::solve_dare(&a, &b, &q, &r).unwrap();
+
+        let bt_x = &b.transpose() * &x;
+        let gain = (&r + &(&bt_x * &b)).solve(&(&bt_x * &a)).unwrap();
+        let expected = &(&(&(&a.transpose() * &x) * &a) - &(&(&a.transpose() * &x) * &(&b * &gain))) + &q;
+        assert_small(&(&expected - &x), 1e-10);
+        assert!(x.cholesky().is_ok());
+
+        // The closed loop a - b * gain is stable.
+        let closed = &a - &(&b * &gain);
+        let (trace, det) = (closed.get(0, 0) + closed.get(1, 1), closed.determinant().unwrap());
+        assert!(det.abs() < 1.0 && trace.abs() < 1.0 + det);
+    }
+
+    #[test]
+    fn riccati_shape_errors() {
+        let (a, b, q, _) = system();
+        assert!(matches!(Matrix::solve_care(&a, &b, &q, &Matrix::new(2, 2, 1.0)), Err(MatrixError::DimensionMismatch { .. })));
+        assert!(matches!(Matrix::solve_dare(&a, &q, &q, &Matrix::new(1, 1, 1.0)), Err(MatrixError::DimensionMismatch { .. })));
+    }
+}
@@ -0,0 +1,118 @@
+use num::Float;
+
+use crate::{Axis, Matrix};
+use crate::element::MatrixElement;
+
+impl<T> Matrix<T> {
+    /// Applies `f` to every full window of `window` consecutive elements along `axis`.
+    ///
+    /// With `Axis::Rows` each row of length `cols` produces `cols - window + 1`
+    /// outputs, so the result has shape `rows x (cols - window + 1)`. `Axis::Cols`
+    /// is the same operation down each column.
+    pub fn rolling_apply<U, F>(&self, window: usize, axis: Axis, f: F) -> Matrix<U>
+        where T: Clone,
+              F: Fn(&[T]) -> U
+    {
+        let length = match axis {
+            Axis::Rows => self.cols,
+            Axis::Cols => self.rows
+        };
+        assert!(window > 0 && window <= length);
+        let outputs = length - window + 1;
+
+        let (rows, cols) = match axis {
+            Axis::Rows => (self.rows, outputs),
+            Axis::Cols => (outputs, self.cols)
+        };
+
+        let mut data = vec![];
+        for i in 0..rows {
+            for j in 0..cols {
+                let values: Vec<T> = match axis {
+                    Axis::Rows => (j..j + window).map(|k| self.get(i, k).clone()).collect(),
+                    Axis::Cols => (i..i + window).map(|k| self.get(k, j).clone()).collect()
+                };
+                data.push(f(&values));
+            }
+        }
+
+        Matrix {
+            rows,
+            cols,
+            data
+        }
+    }
+
+    /// Sum of every full window of `window` elements along `axis`.
+    pub fn rolling_sum(&self, window: usize, axis: Axis) -> Matrix<T>
+        where T: MatrixElement
+    {
+        self.rolling_apply(window, axis, |values| {
+            values.iter().fold(T::zero(), |total, val| total + val.clone())
+        })
+    }
+
+    /// Mean of every full window of `window` elements along `axis`.
+    pub fn rolling_mean(&self, window: usize, axis: Axis) -> Matrix<T>
+        where T: Float
+    {
+        let count = T::from(window).unwrap();
+        self.rolling_apply(window, axis, |values| {
+            values.iter().fold(T::zero(), |total, val| total + *val) / count
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rolling_sum_along_rows() {
+        let matrix = Matrix::from(vec![
+            vec![1, 2, 3, 4],
+            vec![5, 6, 7, 8]
+        ]);
+
+        let expected_matrix = Matrix::from(vec![
+            vec![6, 9],
+            vec![18, 21]
+        ]);
+
+        assert_eq!(matrix.rolling_sum(3, Axis::Rows), expected_matrix);
+    }
+
+    #[test]
+    fn rolling_mean_along_columns() {
+        let matrix = Matrix::from(vec![
+            vec![1.0, 10.0],
+            vec![3.0, 20.0],
+            vec![5.0, 60.0]
+        ]);
+
+        let expected_matrix = Matrix::from(vec![
+            vec![2.0, 15.0],
+            vec![4.0, 40.0]
+        ]);
+
+        assert_eq!(matrix.rolling_mean(2, Axis::Cols), expected_matrix);
+    }
+
+    #[test]
+    fn rolling_apply_max() {
+        let matrix = Matrix::from(vec![
+            vec![3, 1, 4, 1, 5]
+        ]);
+
+        let rolling_max = matrix.rolling_apply(2, Axis::Rows, |values| *values.iter().max().unwrap());
+
+        assert_eq!(rolling_max, Matrix::from(vec![vec![3, 4, 4, 5]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rolling_window_too_large() {
+        let matrix = Matrix::new(2, 2, 0);
+        matrix.rolling_sum(3, Axis::Rows);
+    }
+}
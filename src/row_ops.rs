@@ -0,0 +1,118 @@
+use std::ops::Mul;
+
+use crate::Matrix;
+use crate::element::MatrixElement;
+
+/// The elementary row operations of Gaussian elimination. Each one panics if a
+/// row index is out of bounds.
+impl<T> Matrix<T> {
+    /// Exchanges rows `a` and `b`.
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        self.check_row(a);
+        self.check_row(b);
+        for j in 0..self.cols {
+            self.data.swap(a * self.cols + j, b * self.cols + j);
+        }
+    }
+
+    /// Multiplies every element of `row` by `factor`.
+    pub fn scale_row(&mut self, row: usize, factor: T)
+        where T: Clone + Mul<Output = T>
+    {
+        self.check_row(row);
+        for val in self.data[row * self.cols..(row + 1) * self.cols].iter_mut() {
+            *val = val.clone() * factor.clone();
+        }
+    }
+
+    /// Adds `factor` times row `src` to row `dst`.
+    pub fn add_scaled_row(&mut self, src: usize, dst: usize, factor: T)
+        where T: MatrixElement
+    {
+        self.check_row(src);
+        self.check_row(dst);
+        for j in 0..self.cols {
+            let val = self.get(dst, j).clone() + factor.clone() * self.get(src, j).clone();
+            self.set(dst, j, val);
+        }
+    }
+
+    fn check_row(&self, row: usize) {
+        assert!(row < self.rows, "row {} is out of bounds for a matrix with {} rows", row, self.rows);
+    }
+}
+
+impl<T: MatrixElement> Matrix<T> {
+    /// The reduced row echelon form, along with the pivot columns.
+    ///
+    /// This is Gauss-Jordan elimination as done by hand: the pivot of each
+    /// column is its first nonzero entry at or below the current row, which is
+    /// swapped up, scaled to one and used to clear the rest of its column.
+    /// With exact elements such as `Ratio<i64>` the result is exact; with
+    /// floats, roundoff can leave tiny values where exact arithmetic would
+    /// give zero, and those are then treated as pivots.
+    pub fn rref(&self) -> (Matrix<T>, Vec<usize>) {
+        let (reduced, pivots, _) = self.exact_rref();
+        (reduced, pivots)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num::rational::Ratio;
+
+    #[test]
+    fn elementary_row_operations() {
+        let mut matrix = Matrix::from(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6]
+        ]);
+
+        matrix.swap_rows(0, 1);
+        assert_eq!(matrix, Matrix::from(vec![vec![4, 5, 6], vec![1, 2, 3]]));
+        matrix.scale_row(1, -2);
+        assert_eq!(matrix, Matrix::from(vec![vec![4, 5, 6], vec![-2, -4, -6]]));
+        matrix.add_scaled_row(1, 0, 2);
+        assert_eq!(matrix, Matrix::from(vec![vec![0, -3, -6], vec![-2, -4, -6]]));
+        matrix.swap_rows(1, 1);
+        assert_eq!(matrix.get_row(1), vec![-2, -4, -6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 is out of bounds for a matrix with 2 rows")]
+    fn row_operation_out_of_bounds() {
+        Matrix::new(2, 2, 1).add_scaled_row(2, 0, 1);
+    }
+
+    #[test]
+    fn rref_of_rank_deficient_system() {
+        let r = |n: i64| Ratio::from_integer(n);
+        // The second row is twice the first, so the third column gets no pivot.
+        let augmented = Matrix::from(vec![
+            vec![r(0), r(2), r(4), r(2)],
+            vec![r(1), r(1), r(1), r(4)],
+            vec![r(2), r(2), r(2), r(8)],
+            vec![r(1), r(0), r(-1), r(3)]
+        ]);
+
+        let (reduced, pivots) = augmented.rref();
+        assert_eq!(pivots, vec![0, 1]);
+        assert_eq!(reduced, Matrix::from(vec![
+            vec![r(1), r(0), r(-1), r(3)],
+            vec![r(0), r(1), r(2), r(1)],
+            vec![r(0), r(0), r(0), r(0)],
+            vec![r(0), r(0), r(0), r(0)]
+        ]));
+
+        // Step by step, the same operations reproduce it.
+        let mut steps = augmented.clone();
+        steps.swap_rows(0, 1);
+        steps.add_scaled_row(0, 2, r(-2));
+        steps.add_scaled_row(0, 3, r(-1));
+        steps.scale_row(1, Ratio::new(1, 2));
+        steps.add_scaled_row(1, 0, r(-1));
+        steps.add_scaled_row(1, 3, r(1));
+        assert_eq!(steps, reduced);
+    }
+}
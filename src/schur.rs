@@ -0,0 +1,93 @@
+use std::ops::Range;
+
+use num::Float;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+
+impl<T: Float> Matrix<T> {
+    fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Matrix<T> {
+        let mut data = vec![];
+        for i in rows.clone() {
+            data.extend_from_slice(&self.data[i * self.cols + cols.start..i * self.cols + cols.end]);
+        }
+        Matrix {
+            rows: rows.len(),
+            cols: cols.len(),
+            data
+        }
+    }
+
+    /// The Schur complement `S = D - C * A⁻¹ * B` of the leading block `A`.
+    ///
+    /// The matrix is partitioned as `[[A, B], [C, D]]` with `A` the leading
+    /// `block_partition x block_partition` block, so `S` has the size of `D`.
+    /// `A⁻¹ * B` is found by solving rather than by forming the inverse. Returns
+    /// `Singular` if `A` is singular.
+    pub fn schur_complement(&self, block_partition: usize) -> Result<Matrix<T>, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        let n = self.rows;
+        assert!(block_partition <= n, "block partition {} exceeds matrix size {}", block_partition, n);
+        let k = block_partition;
+
+        let a = self.submatrix(0..k, 0..k);
+        let b = self.submatrix(0..k, k..n);
+        let c = self.submatrix(k..n, 0..k);
+        let mut s = self.submatrix(k..n, k..n);
+        if k == 0 {
+            return Ok(s);
+        }
+
+        let a_inv_b = a.solve(&b)?;
+        for i in 0..s.rows {
+            for j in 0..s.cols {
+                let correction = (0..k).fold(T::zero(), |total, l| total + *c.get(i, l) * *a_inv_b.get(l, j));
+                let val = *s.get(i, j) - correction;
+                s.set(i, j, val);
+            }
+        }
+        Ok(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schur_complement_of_leading_block() {
+        let matrix = Matrix::from(vec![
+            vec![4.0, 2.0, 1.0],
+            vec![2.0, 5.0, 3.0],
+            vec![1.0, 3.0, 6.0]
+        ]);
+
+        let s = matrix.schur_complement(1).unwrap();
+
+        let expected_matrix = Matrix::from(vec![
+            vec![4.0, 2.5],
+            vec![2.5, 5.75]
+        ]);
+        for i in 0..4 {
+            assert!((s.data[i] - expected_matrix.data[i]).abs() < 1e-12);
+        }
+
+        // det(M) = det(A) * det(S)
+        let det_s = s.get(0, 0) * s.get(1, 1) - s.get(0, 1) * s.get(1, 0);
+        assert!((4.0 * det_s - 67.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn schur_complement_errors() {
+        let singular_leading = Matrix::from(vec![
+            vec![0.0, 1.0],
+            vec![1.0, 0.0]
+        ]);
+        assert_eq!(singular_leading.schur_complement(1), Err(MatrixError::Singular));
+
+        let rectangular = Matrix::new(2, 3, 1.0);
+        assert_eq!(rectangular.schur_complement(1), Err(MatrixError::NotSquare { rows: 2, cols: 3 }));
+    }
+}
@@ -0,0 +1,209 @@
+use num::Float;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+use crate::reflectors::householder_vector;
+
+/// A real Schur decomposition `a = q * t * qᵀ`.
+///
+/// `q` is orthogonal and `t` is upper quasi-triangular: upper triangular except
+/// for 2x2 blocks on the diagonal, one for each pair of complex conjugate
+/// eigenvalues. The subdiagonal entries outside those blocks are exactly zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealSchur<T> {
+    pub q: Matrix<T>,
+    pub t: Matrix<T>
+}
+
+impl<T: Float> RealSchur<T> {
+    /// The start index and size (1 or 2) of each diagonal block of `t`, from top to bottom.
+    pub fn blocks(&self) -> Vec<(usize, usize)> {
+        let n = self.t.rows;
+        let mut blocks = vec![];
+        let mut i = 0;
+        while i < n {
+            let size = if i + 1 < n && !self.t.get(i + 1, i).is_zero() { 2 } else { 1 };
+            blocks.push((i, size));
+            i += size;
+        }
+        blocks
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// Reduces `self` to upper Hessenberg form by Householder similarities,
+    /// returning `(h, q)` with `self = q * h * qᵀ`.
+    fn hessenberg(&self) -> (Matrix<T>, Matrix<T>) {
+        let n = self.rows;
+        let mut h = self.clone();
+        let mut q = Matrix::identity(n);
+
+        for k in 0..n.saturating_sub(2) {
+            let reflector = householder_vector(&h.get_column(k)[k + 1..]);
+            reflector.apply_left(&mut h, k + 1);
+            reflector.apply_right(&mut h, k + 1);
+            reflector.apply_right(&mut q, k + 1);
+            for i in k + 2..n {
+                h.set(i, k, T::zero());
+            }
+        }
+        (h, q)
+    }
+
+    /// The real Schur decomposition by the Francis double-shift QR algorithm.
+    ///
+    /// The matrix is first reduced to Hessenberg form, then implicit double-shift
+    /// QR steps drive the subdiagonal to zero, deflating 1x1 and 2x2 blocks from
+    /// the bottom. Returns [`MatrixError::NoConvergence`] if a block takes more
+    /// than 30 steps per row of the matrix in total.
+    pub fn real_schur(&self) -> Result<RealSchur<T>, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+
+        let n = self.rows;
+        let (mut t, mut q) = self.hessenberg();
+        let max_iterations = 30 * n.max(1);
+        let mut iterations = 0;
+        let mut since_deflation = 0;
+
+        let mut hi = n;
+        while hi > 1 {
+            let last = hi - 1;
+            let mut lo = last;
+            while lo > 0 {
+                let scale = t.get(lo - 1, lo - 1).abs() + t.get(lo, lo).abs();
+                if t.get(lo, lo - 1).abs() <= T::epsilon() * scale {
+                    t.set(lo, lo - 1, T::zero());
+                    break;
+                }
+                lo -= 1;
+            }
+
+            if lo == last {
+                hi -= 1;
+                since_deflation = 0;
+                continue;
+            }
+            if lo + 1 == last {
+                hi -= 2;
+                since_deflation = 0;
+                continue;
+            }
+
+            if iterations == max_iterations {
+                return Err(MatrixError::NoConvergence { iterations });
+            }
+            iterations += 1;
+            since_deflation += 1;
+
+            // The shifts are the eigenvalues of the trailing 2x2 block, entering
+            // only through their sum and product; every tenth step uses ad hoc
+            // shifts to break cycles.
+            let (sum, product) = if since_deflation % 10 == 0 {
+                let s = t.get(last, last - 1).abs() + t.get(last - 1, last - 2).abs();
+                (T::from(1.5).unwrap() * s, s * s)
+            } else {
+                let (a, b, c, d) = (*t.get(last - 1, last - 1), *t.get(last - 1, last), *t.get(last, last - 1), *t.get(last, last));
+                (a + d, a * d - b * c)
+            };
+
+            let mut x = *t.get(lo, lo) * *t.get(lo, lo) + *t.get(lo, lo + 1) * *t.get(lo + 1, lo)
+                - sum * *t.get(lo, lo) + product;
+            let mut y = *t.get(lo + 1, lo) * (*t.get(lo, lo) + *t.get(lo + 1, lo + 1) - sum);
+            let mut z = *t.get(lo + 1, lo) * *t.get(lo + 2, lo + 1);
+
+            for k in lo..last - 1 {
+                let reflector = householder_vector(&[x, y, z]);
+                reflector.apply_left(&mut t, k);
+                reflector.apply_right(&mut t, k);
+                reflector.apply_right(&mut q, k);
+                if k > lo {
+                    t.set(k + 1, k - 1, T::zero());
+                    t.set(k + 2, k - 1, T::zero());
+                }
+
+                x = *t.get(k + 1, k);
+                y = *t.get(k + 2, k);
+                if k + 3 <= last {
+                    z = *t.get(k + 3, k);
+                }
+            }
+            let reflector = householder_vector(&[x, y]);
+            reflector.apply_left(&mut t, last - 1);
+            reflector.apply_right(&mut t, last - 1);
+            reflector.apply_right(&mut q, last - 1);
+            t.set(last, last - 2, T::zero());
+        }
+
+        Ok(RealSchur {
+            q,
+            t
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_schur(matrix: &Matrix<f64>, schur: &RealSchur<f64>) {
+        let reconstructed = &(&schur.q * &schur.t) * &schur.q.transpose();
+        assert!(reconstructed.iter().zip(matrix.iter()).all(|(a, b)| (a - b).abs() < 1e-10));
+        let gram = schur.q.transpose() * &schur.q;
+        for i in 0..gram.rows {
+            for j in 0..gram.cols {
+                assert!((gram.get(i, j) - if i == j { 1.0 } else { 0.0 }).abs() < 1e-12);
+            }
+        }
+        let n = matrix.rows;
+        for i in 2..n {
+            for j in 0..i - 1 {
+                assert_eq!(*schur.t.get(i, j), 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn real_schur_with_real_eigenvalues() {
+        let matrix = Matrix::from(vec![
+            vec![4.0, 1.0, 2.0, 0.5],
+            vec![1.0, 3.0, 0.0, 1.0],
+            vec![0.0, 2.0, 1.0, 1.0],
+            vec![1.0, 0.0, 1.0, 2.0]
+        ]);
+
+        let schur = matrix.real_schur().unwrap();
+
+        assert_schur(&matrix, &schur);
+        let trace: f64 = (0..4).map(|i| schur.t.get(i, i)).sum();
+        assert!((trace - 10.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn real_schur_with_complex_eigenvalues() {
+        // A rotation-like block with eigenvalues 1 ± 2i next to a real eigenvalue 3.
+        let matrix = Matrix::from(vec![
+            vec![1.0, -2.0, 0.5],
+            vec![2.0, 1.0, 1.0],
+            vec![0.0, 0.0, 3.0]
+        ]);
+        let mixer: Matrix<f64> = Matrix::from(vec![
+            vec![1.0, 1.0, 0.0],
+            vec![0.0, 1.0, 1.0],
+            vec![1.0, 0.0, 1.0]
+        ]);
+        let similar = &(&mixer * &matrix) * &mixer.inverse().unwrap();
+
+        let schur = similar.real_schur().unwrap();
+
+        assert_schur(&similar, &schur);
+        let blocks = schur.blocks();
+        assert_eq!(blocks.iter().map(|&(_, size)| size).sum::<usize>(), 3);
+        let (start, _) = *blocks.iter().find(|&&(_, size)| size == 2).unwrap();
+        let (a, b, c, d) = (schur.t.get(start, start), schur.t.get(start, start + 1), schur.t.get(start + 1, start), schur.t.get(start + 1, start + 1));
+        // The block's eigenvalues are 1 ± 2i: trace 2 and determinant 5.
+        assert!((a + d - 2.0).abs() < 1e-10);
+        assert!((a * d - b * c - 5.0).abs() < 1e-10);
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::Matrix;
+use crate::error::MatrixError;
+
+/// The dimensions of a matrix, `rows x cols`.
+///
+/// The combining methods encode the shape rules of the crate's operations and
+/// report a [`MatrixError::DimensionMismatch`] naming both shapes when the rule
+/// is broken, so an operation can validate its operands before doing any work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Shape {
+    pub rows: usize,
+    pub cols: usize
+}
+
+impl Shape {
+    pub fn new(rows: usize, cols: usize) -> Shape {
+        Shape { rows, cols }
+    }
+
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn transposed(&self) -> Shape {
+        Shape::new(self.cols, self.rows)
+    }
+
+    fn mismatch(&self, other: Shape) -> MatrixError {
+        MatrixError::DimensionMismatch {
+            left: *self,
+            right: other
+        }
+    }
+
+    /// The shape of an element-wise combination, which requires equal shapes.
+    pub fn elementwise_with(&self, other: Shape) -> std::result::Result<Shape, MatrixError> {
+        if *self != other {
+            return Err(self.mismatch(other));
+        }
+        Ok(*self)
+    }
+
+    /// The shape of the product `self * other`.
+    pub fn matmul_with(&self, other: Shape) -> std::result::Result<Shape, MatrixError> {
+        if self.cols != other.rows {
+            return Err(self.mismatch(other));
+        }
+        Ok(Shape::new(self.rows, other.cols))
+    }
+
+    /// The shape two matrices broadcast to under NumPy's rules: along each axis
+    /// the sizes must match or one of them must be 1.
+    pub fn broadcast_with(&self, other: Shape) -> std::result::Result<Shape, MatrixError> {
+        let broadcast_dim = |left: usize, right: usize| {
+            if left == right || right == 1 {
+                Some(left)
+            } else if left == 1 {
+                Some(right)
+            } else {
+                None
+            }
+        };
+
+        match (broadcast_dim(self.rows, other.rows), broadcast_dim(self.cols, other.cols)) {
+            (Some(rows), Some(cols)) => Ok(Shape::new(rows, cols)),
+            _ => Err(self.mismatch(other))
+        }
+    }
+}
+
+impl From<(usize, usize)> for Shape {
+    fn from((rows, cols): (usize, usize)) -> Shape {
+        Shape::new(rows, cols)
+    }
+}
+
+impl Display for Shape {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}x{}", self.rows, self.cols)
+    }
+}
+
+impl<T> Matrix<T> {
+    pub fn shape(&self) -> Shape {
+        Shape::new(self.rows, self.cols)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shape_algebra() {
+        let shape = Shape::new(2, 3);
+
+        assert_eq!(shape.transposed(), Shape::new(3, 2));
+        assert_eq!(shape.len(), 6);
+        assert!(!shape.is_square());
+        assert_eq!(shape.matmul_with(Shape::new(3, 4)), Ok(Shape::new(2, 4)));
+        assert_eq!(
+            shape.matmul_with(shape),
+            Err(MatrixError::DimensionMismatch { left: shape, right: shape })
+        );
+        assert_eq!(shape.elementwise_with((2, 3).into()), Ok(shape));
+        assert!(shape.elementwise_with(shape.transposed()).is_err());
+    }
+
+    #[test]
+    fn shape_broadcasting() {
+        let shape = Shape::new(4, 3);
+
+        assert_eq!(shape.broadcast_with(Shape::new(1, 3)), Ok(shape));
+        assert_eq!(Shape::new(4, 1).broadcast_with(Shape::new(1, 3)), Ok(shape));
+        assert!(shape.broadcast_with(Shape::new(2, 3)).is_err());
+        assert_eq!(
+            MatrixError::DimensionMismatch { left: shape, right: Shape::new(2, 3) }.to_string(),
+            "incompatible dimensions: 4x3 and 2x3"
+        );
+        assert_eq!(Matrix::new(4, 3, 0).shape(), shape);
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt::Debug;
+
+use num::Float;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+
+const MAX_ITERATIONS: usize = 100;
+
+/// The maximum absolute column sum.
+pub(crate) fn one_norm<T: Float>(matrix: &Matrix<T>) -> T {
+    (0..matrix.cols)
+        .map(|j| (0..matrix.rows).fold(T::zero(), |total, i| total + matrix.get(i, j).abs()))
+        .fold(T::zero(), T::max)
+}
+
+/// The matrix sign function by the scaled Newton iteration
+/// `z = (c * z + (c * z)⁻¹) / 2`, with the determinant scaling
+/// `c = |det z|^(-1/n)` that speeds up the early iterations. `c` is computed
+/// from `log |det z|`, since the determinant itself overflows or underflows
+/// for moderately scaled matrices.
+///
+/// Returns [`MatrixError::Singular`] if an iterate is singular, which happens
+/// when the matrix has an eigenvalue on or very near the imaginary axis, and
+/// [`MatrixError::NoConvergence`] if an iterate stops being finite.
+pub(crate) fn sign_iteration<T: Float + Debug>(matrix: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+    let n = matrix.rows;
+    let identity = Matrix::identity(n);
+    let half = T::from(0.5).unwrap();
+    let tolerance = T::from(n).unwrap().sqrt() * T::epsilon() * T::from(10.0).unwrap();
+
+    let mut z = matrix.clone();
+    for iteration in 0..MAX_ITERATIONS {
+        let lu = z.lu()?;
+        let inverse = lu.solve(&identity)?;
+        let (_, log_det) = lu.sign_and_log_det();
+        let c = (-log_det / T::from(n).unwrap()).exp();
+
+        let next = &(&z * (c * half)) + &(&inverse * (half / c));
+        if !next.data.iter().all(|val| val.is_finite()) {
+            return Err(MatrixError::NoConvergence { iterations: iteration + 1 });
+        }
+        let change = one_norm(&(&next - &z));
+        z = next;
+        if change <= tolerance * one_norm(&z) {
+            return Ok(z);
+        }
+    }
+    Err(MatrixError::NoConvergence { iterations: MAX_ITERATIONS })
+}
+
+/// Complementary projectors onto the invariant subspaces of a matrix whose
+/// eigenvalues lie to the left and to the right of a vertical line in the
+/// complex plane.
+///
+/// `left + right` is the identity, and the trace of each projector is the
+/// number of eigenvalues on its side.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpectralProjectors<T> {
+    pub left: Matrix<T>,
+    pub right: Matrix<T>
+}
+
+impl<T: Float + Debug> Matrix<T> {
+    /// The matrix sign function: the matrix with the same eigenvectors whose
+    /// eigenvalues are `-1` or `1` according to the sign of the real part of
+    /// each eigenvalue of `self`.
+    ///
+    /// Computed by Newton's iteration, without an eigendecomposition. Returns
+    /// [`MatrixError::Singular`] if the matrix has an eigenvalue on or very near
+    /// the imaginary axis, where the sign is undefined.
+    pub fn sign(&self) -> Result<Matrix<T>, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        sign_iteration(self)
+    }
+
+    /// Projectors that split the spectrum at `Re λ = shift`, from the sign of
+    /// `self - shift * I`.
+    ///
+    /// Applying `left` keeps the components of a vector along eigenvectors with
+    /// `Re λ < shift` and discards the rest; `right` does the opposite. A shift of
+    /// zero separates the stable and unstable modes of `dx/dt = self * x`.
+    pub fn spectral_projectors(&self, shift: T) -> Result<SpectralProjectors<T>, MatrixError> {
+        let mut shifted = self.clone();
+        for i in 0..self.rows.min(self.cols) {
+            shifted.set(i, i, *self.get(i, i) - shift);
+        }
+        let sign = shifted.sign()?;
+
+        let half = T::from(0.5).unwrap();
+        let mut left = &sign * -half;
+        let mut right = &sign * half;
+        for i in 0..self.rows {
+            left.set(i, i, *left.get(i, i) + half);
+            right.set(i, i, *right.get(i, i) + half);
+        }
+        Ok(SpectralProjectors {
+            left,
+            right
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_of_diagonalizable_matrix() {
+        let diagonal = Matrix::from(vec![
+            vec![-2.0, 0.0],
+            vec![0.0, 3.0]
+        ]);
+        let mixer = Matrix::from(vec![
+            vec![1.0, 1.0],
+            vec![0.0, 1.0]
+        ]);
+        let mixer_inverse = Matrix::from(vec![
+            vec![1.0, -1.0],
+            vec![0.0, 1.0]
+        ]);
+        let matrix = &(&mixer * &diagonal) * &mixer_inverse;
+
+        let sign = sign_iteration(&matrix).unwrap();
+
+        let expected = &(&mixer * &Matrix::from(vec![vec![-1.0, 0.0], vec![0.0, 1.0]])) * &mixer_inverse;
+        assert!(sign.iter().zip(expected.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+        assert_eq!(one_norm(&expected), 3.0);
+        assert_eq!(matrix.sign().unwrap(), sign);
+    }
+
+    #[test]
+    fn spectral_projectors_split_eigenvalues() {
+        // Upper triangular, so the eigenvalues -1, 2 and 5 sit on the diagonal.
+        let matrix = Matrix::from(vec![
+            vec![-1.0, 2.0, 1.0],
+            vec![0.0, 2.0, 3.0],
+            vec![0.0, 0.0, 5.0]
+        ]);
+
+        let stable = matrix.spectral_projectors(0.0).unwrap();
+        let trace = |projector: &Matrix<f64>| (0..3).map(|i| projector.get(i, i)).sum::<f64>();
+        assert!((trace(&stable.left) - 1.0).abs() < 1e-12);
+        assert!((trace(&stable.right) - 2.0).abs() < 1e-12);
+
+        let squared = &stable.left * &stable.left;
+        assert!(squared.iter().zip(stable.left.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+        // The projectors commute with the matrix, since they project onto invariant subspaces.
+        let commutator = &(&matrix * &stable.right) - &(&stable.right * &matrix);
+        assert!(commutator.iter().all(|val| val.abs() < 1e-10));
+
+        let shifted = matrix.spectral_projectors(3.0).unwrap();
+        assert!((trace(&shifted.left) - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn sign_of_widely_scaled_matrix() {
+        // |det| = 1e400 overflows, so the scaling must come from the log-determinant.
+        let matrix = Matrix::from_fn(100, 100, |i, j| if i != j { 0.0 } else if i % 2 == 0 { 1e4 } else { -1e4 });
+
+        let sign = matrix.sign().unwrap();
+        let expected = Matrix::from_fn(100, 100, |i, j| if i != j { 0.0 } else if i % 2 == 0 { 1.0 } else { -1.0 });
+        assert!(sign.iter().zip(expected.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+    }
+
+    #[test]
+    fn sign_of_rectangular_or_imaginary_spectrum() {
+        assert_eq!(Matrix::new(2, 3, 1.0).sign(), Err(MatrixError::NotSquare { rows: 2, cols: 3 }));
+
+        let rotation = Matrix::from(vec![
+            vec![0.0, -1.0],
+            vec![1.0, 0.0]
+        ]);
+        assert!(rotation.sign().is_err());
+    }
+}
@@ -0,0 +1,117 @@
+use num::Float;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+
+impl<T: Float> Matrix<T> {
+    /// Pairwise cosine similarity between the rows of `self`.
+    ///
+    /// Rows with a zero norm have no direction, so their similarity to every
+    /// row (including themselves) is defined as zero rather than NaN.
+    pub fn cosine_similarity(&self) -> Matrix<T> {
+        self.pairwise_cosine(self)
+    }
+
+    /// Cosine similarity between every row of `self` and every row of `other`.
+    ///
+    /// The result has `self.rows` rows and `other.rows` columns. Zero-norm rows
+    /// are handled as in [`Matrix::cosine_similarity`]. Returns
+    /// [`MatrixError::DimensionMismatch`] unless both have the same number of columns.
+    pub fn cosine_similarity_with(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.cols != other.cols {
+            return Err(MatrixError::DimensionMismatch {
+                left: self.shape(),
+                right: other.shape()
+            });
+        }
+        Ok(self.pairwise_cosine(other))
+    }
+
+    fn pairwise_cosine(&self, other: &Matrix<T>) -> Matrix<T> {
+        let self_norms = self.row_norms();
+        let other_norms = other.row_norms();
+
+        let mut data = vec![];
+        for (i, self_norm) in self_norms.iter().enumerate() {
+            for (j, other_norm) in other_norms.iter().enumerate() {
+                let denominator = *self_norm * *other_norm;
+                if denominator == T::zero() {
+                    data.push(T::zero());
+                    continue;
+                }
+
+                let mut dot = T::zero();
+                for k in 0..self.cols {
+                    dot = dot + *self.get(i, k) * *other.get(j, k);
+                }
+                data.push(dot / denominator);
+            }
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: other.rows,
+            data
+        }
+    }
+
+    fn row_norms(&self) -> Vec<T> {
+        let mut norms = vec![];
+        for i in 0..self.rows {
+            let mut total = T::zero();
+            for j in 0..self.cols {
+                total = total + *self.get(i, j) * *self.get(i, j);
+            }
+            norms.push(total.sqrt());
+        }
+        norms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Shape;
+
+    #[test]
+    fn cosine_similarity_of_rows() {
+        let matrix = Matrix::from(vec![
+            vec![1.0, 0.0],
+            vec![0.0, 2.0],
+            vec![3.0, 3.0]
+        ]);
+
+        let similarity = matrix.cosine_similarity();
+        let diagonal = 1.0 / 2.0_f64.sqrt();
+
+        assert_eq!(similarity.rows, 3);
+        assert_eq!(similarity.cols, 3);
+        assert!((similarity.get(0, 0) - 1.0).abs() < 1e-12);
+        assert!(similarity.get(0, 1).abs() < 1e-12);
+        assert!((similarity.get(0, 2) - diagonal).abs() < 1e-12);
+        assert!((similarity.get(2, 1) - diagonal).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cosine_similarity_with_zero_row() {
+        let matrix = Matrix::from(vec![
+            vec![0.0, 0.0],
+            vec![1.0, 1.0]
+        ]);
+        let other = Matrix::from(vec![
+            vec![2.0, 2.0]
+        ]);
+
+        let similarity = matrix.cosine_similarity_with(&other).unwrap();
+
+        assert_eq!(similarity.rows, 2);
+        assert_eq!(similarity.cols, 1);
+        assert_eq!(*similarity.get(0, 0), 0.0);
+        assert!((similarity.get(1, 0) - 1.0).abs() < 1e-12);
+
+        assert_eq!(
+            matrix.cosine_similarity_with(&Matrix::new(1, 3, 1.0)),
+            Err(MatrixError::DimensionMismatch { left: Shape::new(2, 2), right: Shape::new(1, 3) })
+        );
+    }
+}
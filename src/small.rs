@@ -0,0 +1,144 @@
+use std::ops::Mul;
+
+use crate::Matrix;
+use crate::element::MatrixElement;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Storage<T, const N: usize> {
+    /// The first `len` elements of the array are in use.
+    Inline { data: [T; N], len: usize },
+    Heap(Vec<T>)
+}
+
+/// A dynamically sized matrix that keeps up to `N` elements inline, without
+/// allocating, and only moves to the heap when it grows beyond that.
+///
+/// `SmallMatrix<T, 9>` holds any matrix up to 3x3 on the stack, which removes the
+/// allocator from code that works mostly with many tiny matrices.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmallMatrix<T, const N: usize> {
+    rows: usize,
+    cols: usize,
+    storage: Storage<T, N>
+}
+
+impl<T: Copy + Default, const N: usize> SmallMatrix<T, N> {
+    pub fn new(rows: usize, cols: usize, value: T) -> SmallMatrix<T, N> {
+        let len = rows * cols;
+        let storage = if len <= N {
+            let mut data = [T::default(); N];
+            data[..len].iter_mut().for_each(|val| *val = value);
+            Storage::Inline { data, len }
+        } else {
+            Storage::Heap(vec![value; len])
+        };
+
+        SmallMatrix {
+            rows,
+            cols,
+            storage
+        }
+    }
+
+    pub fn from_slice(rows: usize, cols: usize, elements: &[T]) -> SmallMatrix<T, N> {
+        assert_eq!(elements.len(), rows * cols);
+
+        let mut matrix = SmallMatrix::new(rows, cols, T::default());
+        matrix.as_mut_slice().copy_from_slice(elements);
+        matrix
+    }
+
+    /// Whether the elements are stored inline rather than on the heap.
+    pub fn is_inline(&self) -> bool {
+        matches!(self.storage, Storage::Inline { .. })
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        match &self.storage {
+            Storage::Inline { data, len } => &data[..*len],
+            Storage::Heap(data) => data
+        }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match &mut self.storage {
+            Storage::Inline { data, len } => &mut data[..*len],
+            Storage::Heap(data) => data
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &T {
+        &self.as_slice()[row * self.cols + col]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        let cols = self.cols;
+        self.as_mut_slice()[row * cols + col] = value;
+    }
+
+    pub fn into_matrix(self) -> Matrix<T> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.as_slice().to_vec()
+        }
+    }
+}
+
+impl<T: Copy + Default, const N: usize> From<&Matrix<T>> for SmallMatrix<T, N> {
+    fn from(matrix: &Matrix<T>) -> SmallMatrix<T, N> {
+        SmallMatrix::from_slice(matrix.rows, matrix.cols, &matrix.data)
+    }
+}
+
+impl<T: MatrixElement + Copy + Default, const N: usize> Mul for &SmallMatrix<T, N> {
+    type Output = SmallMatrix<T, N>;
+
+    fn mul(self, rhs: &SmallMatrix<T, N>) -> SmallMatrix<T, N> {
+        assert_eq!(self.cols, rhs.rows);
+
+        let mut output = SmallMatrix::new(self.rows, rhs.cols, T::zero());
+        for i in 0..self.rows {
+            for j in 0..rhs.cols {
+                let mut total = T::zero();
+                for k in 0..self.cols {
+                    total = total + *self.get(i, k) * *rhs.get(k, j);
+                }
+                output.set(i, j, total);
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_matrices_stay_inline() {
+        let a: SmallMatrix<i32, 9> = SmallMatrix::from_slice(2, 2, &[1, 2, 3, 4]);
+        let b = SmallMatrix::from_slice(2, 2, &[0, 1, 1, 0]);
+
+        let product = &a * &b;
+
+        assert!(product.is_inline());
+        assert_eq!(product.as_slice(), &[2, 1, 4, 3]);
+        assert_eq!(product.into_matrix(), Matrix::from(vec![vec![2, 1], vec![4, 3]]));
+    }
+
+    #[test]
+    fn large_matrices_spill_to_heap() {
+        let matrix = Matrix::new(4, 4, 1.5);
+        let mut small: SmallMatrix<f64, 9> = SmallMatrix::from(&matrix);
+
+        assert!(!small.is_inline());
+        small.set(3, 3, 2.0);
+        assert_eq!(*small.get(3, 3), 2.0);
+        assert_eq!(*small.get(0, 0), 1.5);
+
+        let exact: SmallMatrix<f64, 16> = SmallMatrix::from(&matrix);
+        assert!(exact.is_inline());
+        assert_eq!(exact.into_matrix(), matrix);
+    }
+}
@@ -0,0 +1,315 @@
+use crate::Matrix;
+use crate::element::MatrixElement;
+use crate::error::MatrixError;
+use crate::shape::Shape;
+use crate::sparse::CsrMatrix;
+use crate::traits::MatrixOps;
+
+/// A matrix that picks between dense and CSR storage from its density, for
+/// data whose sparsity isn't known in advance.
+///
+/// Every constructor and operation re-examines the density of its result:
+/// dense results below [`SmartMatrix::SPARSE_BELOW`] are compressed, and
+/// sparse results above [`SmartMatrix::DENSE_ABOVE`] are expanded. The gap
+/// between the two thresholds keeps a matrix that hovers around one of them
+/// from being converted back and forth on every operation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SmartMatrix<T> {
+    Dense(Matrix<T>),
+    Sparse(CsrMatrix<T>)
+}
+
+impl<T: MatrixElement> SmartMatrix<T> {
+    /// Dense matrices with a smaller fraction of nonzeros are stored as CSR.
+    pub const SPARSE_BELOW: f64 = 0.1;
+
+    /// Sparse matrices with a larger fraction of nonzeros are stored densely.
+    ///
+    /// CSR keeps a column index next to every value, so past about a third
+    /// of the entries it uses more memory, and its products run slower, than
+    /// the dense layout.
+    pub const DENSE_ABOVE: f64 = 0.3;
+
+    /// Wraps a dense matrix, compressing it if it is sparse enough.
+    pub fn new(matrix: Matrix<T>) -> SmartMatrix<T> {
+        let mut smart = SmartMatrix::Dense(matrix);
+        smart.rebalance();
+        smart
+    }
+
+    /// Wraps a sparse matrix, expanding it if it is dense enough.
+    pub fn from_csr(matrix: CsrMatrix<T>) -> SmartMatrix<T> {
+        let mut smart = SmartMatrix::Sparse(matrix);
+        smart.rebalance();
+        smart
+    }
+
+    /// The number of nonzero entries.
+    ///
+    /// This is a stored count for the sparse format but a full scan for the
+    /// dense one.
+    pub fn nnz(&self) -> usize {
+        match self {
+            SmartMatrix::Dense(matrix) => matrix.iter().filter(|val| !val.is_zero()).count(),
+            SmartMatrix::Sparse(matrix) => matrix.nnz()
+        }
+    }
+
+    /// The fraction of entries that are nonzero, or zero for an empty matrix.
+    pub fn density(&self) -> f64 {
+        let shape = MatrixOps::shape(self);
+        match shape.rows * shape.cols {
+            0 => 0.0,
+            len => self.nnz() as f64 / len as f64
+        }
+    }
+
+    pub fn is_sparse(&self) -> bool {
+        matches!(self, SmartMatrix::Sparse(_))
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        match self {
+            SmartMatrix::Dense(matrix) => matrix.clone(),
+            SmartMatrix::Sparse(matrix) => matrix.to_dense()
+        }
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        match self {
+            SmartMatrix::Dense(matrix) => CsrMatrix::from_dense(matrix),
+            SmartMatrix::Sparse(matrix) => matrix.clone()
+        }
+    }
+
+    /// Sets the element at `(row, col)`, switching format if the change in
+    /// density calls for it.
+    ///
+    /// Inserting into the sparse format shifts every later entry, so filling
+    /// a sparse matrix one element at a time costs `O(nnz)` per element until
+    /// it becomes dense enough to convert.
+    pub fn set(&mut self, row: usize, col: usize, val: T) {
+        let shape = MatrixOps::shape(self);
+        if row >= shape.rows || col >= shape.cols {
+            panic!("{}", MatrixError::IndexOutOfBounds { row, col, rows: shape.rows, cols: shape.cols });
+        }
+
+        match self {
+            SmartMatrix::Dense(matrix) => matrix.set(row, col, val),
+            SmartMatrix::Sparse(matrix) => {
+                let start = matrix.row_offsets[row];
+                let (cols, _) = matrix.row(row);
+                match (cols.binary_search(&col), val.is_zero()) {
+                    (Ok(k), false) => matrix.values[start + k] = val,
+                    (Ok(k), true) => {
+                        matrix.col_indices.remove(start + k);
+                        matrix.values.remove(start + k);
+                        matrix.row_offsets[row + 1..].iter_mut().for_each(|offset| *offset -= 1);
+                    },
+                    (Err(k), false) => {
+                        matrix.col_indices.insert(start + k, col);
+                        matrix.values.insert(start + k, val);
+                        matrix.row_offsets[row + 1..].iter_mut().for_each(|offset| *offset += 1);
+                    },
+                    (Err(_), true) => {}
+                }
+            }
+        }
+        self.rebalance();
+    }
+
+    pub fn transpose(&self) -> SmartMatrix<T> {
+        match self {
+            SmartMatrix::Dense(matrix) => SmartMatrix::Dense(matrix.transpose()),
+            SmartMatrix::Sparse(matrix) => SmartMatrix::Sparse(matrix.transpose())
+        }
+    }
+
+    /// The sum `self + rhs`, kept sparse only when both operands are.
+    pub fn checked_add(&self, rhs: &SmartMatrix<T>) -> Result<SmartMatrix<T>, MatrixError> {
+        let mut sum = match (self, rhs) {
+            (SmartMatrix::Sparse(a), SmartMatrix::Sparse(b)) => SmartMatrix::Sparse(a.checked_add(b)?),
+            (SmartMatrix::Dense(a), SmartMatrix::Dense(b)) => SmartMatrix::Dense(a.checked_add(b)?),
+            (SmartMatrix::Dense(dense), SmartMatrix::Sparse(sparse)) | (SmartMatrix::Sparse(sparse), SmartMatrix::Dense(dense)) => {
+                dense.shape().elementwise_with(sparse.shape())?;
+                let mut sum = dense.clone();
+                for i in 0..sparse.rows {
+                    let (cols, values) = sparse.row(i);
+                    for (&j, val) in cols.iter().zip(values.iter()) {
+                        sum.set(i, j, sum.get(i, j).clone() + val.clone());
+                    }
+                }
+                SmartMatrix::Dense(sum)
+            }
+        };
+        sum.rebalance();
+        Ok(sum)
+    }
+
+    /// The product `self * rhs`.
+    ///
+    /// Two sparse operands multiply without expanding either; a sparse
+    /// operand times a dense one only visits the stored entries of the sparse
+    /// side and gives a dense result.
+    pub fn checked_mul(&self, rhs: &SmartMatrix<T>) -> Result<SmartMatrix<T>, MatrixError> {
+        let shape = MatrixOps::shape(self).matmul_with(MatrixOps::shape(rhs))?;
+        let mut product = match (self, rhs) {
+            (SmartMatrix::Sparse(a), SmartMatrix::Sparse(b)) => SmartMatrix::Sparse(a.checked_mul(b)?),
+            (SmartMatrix::Dense(a), SmartMatrix::Dense(b)) => SmartMatrix::Dense(a.checked_mul(b)?),
+            (SmartMatrix::Sparse(a), SmartMatrix::Dense(b)) => {
+                let mut product = Matrix::new(shape.rows, shape.cols, T::zero());
+                for i in 0..a.rows {
+                    let (cols, values) = a.row(i);
+                    for (&k, val) in cols.iter().zip(values.iter()) {
+                        for j in 0..shape.cols {
+                            product.set(i, j, product.get(i, j).clone() + val.clone() * b.get(k, j).clone());
+                        }
+                    }
+                }
+                SmartMatrix::Dense(product)
+            },
+            (SmartMatrix::Dense(a), SmartMatrix::Sparse(b)) => {
+                let mut product = Matrix::new(shape.rows, shape.cols, T::zero());
+                for i in 0..shape.rows {
+                    for k in 0..b.rows {
+                        let scale = a.get(i, k);
+                        if scale.is_zero() {
+                            continue;
+                        }
+                        let (cols, values) = b.row(k);
+                        for (&j, val) in cols.iter().zip(values.iter()) {
+                            product.set(i, j, product.get(i, j).clone() + scale.clone() * val.clone());
+                        }
+                    }
+                }
+                SmartMatrix::Dense(product)
+            }
+        };
+        product.rebalance();
+        Ok(product)
+    }
+
+    /// Converts to whichever format the current density calls for.
+    fn rebalance(&mut self) {
+        let density = self.density();
+        *self = match self {
+            SmartMatrix::Dense(matrix) if density < Self::SPARSE_BELOW => SmartMatrix::Sparse(CsrMatrix::from_dense(matrix)),
+            SmartMatrix::Sparse(matrix) if density > Self::DENSE_ABOVE => SmartMatrix::Dense(matrix.to_dense()),
+            _ => return
+        };
+    }
+}
+
+impl<T: MatrixElement> MatrixOps<T> for SmartMatrix<T> {
+    fn shape(&self) -> Shape {
+        match self {
+            SmartMatrix::Dense(matrix) => MatrixOps::shape(matrix),
+            SmartMatrix::Sparse(matrix) => MatrixOps::shape(matrix)
+        }
+    }
+
+    fn element(&self, row: usize, col: usize) -> T {
+        match self {
+            SmartMatrix::Dense(matrix) => matrix.element(row, col),
+            SmartMatrix::Sparse(matrix) => matrix.element(row, col)
+        }
+    }
+
+    fn row_entries(&self, row: usize) -> Box<dyn Iterator<Item = (usize, T)> + '_> {
+        match self {
+            SmartMatrix::Dense(matrix) => matrix.row_entries(row),
+            SmartMatrix::Sparse(matrix) => matrix.row_entries(row)
+        }
+    }
+
+    fn matvec(&self, x: &[T]) -> Vec<T> {
+        match self {
+            SmartMatrix::Dense(matrix) => matrix.matvec(x),
+            SmartMatrix::Sparse(matrix) => matrix.matvec(x)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An `n x n` tridiagonal matrix, about `3 / n` dense.
+    fn tridiagonal(n: usize) -> Matrix<i64> {
+        Matrix::from_fn(n, n, |i, j| match i as i64 - j as i64 {
+            0 => 2,
+            1 | -1 => -1,
+            _ => 0
+        })
+    }
+
+    #[test]
+    fn smart_matrix_picks_format_by_density() {
+        let sparse = SmartMatrix::new(tridiagonal(40));
+        assert!(sparse.is_sparse());
+        assert_eq!(sparse.nnz(), 118);
+        assert_eq!(sparse.to_dense(), tridiagonal(40));
+
+        let dense = SmartMatrix::from_csr(CsrMatrix::from_dense(&tridiagonal(4)));
+        assert!(!dense.is_sparse());
+        assert_eq!(dense.density(), 10.0 / 16.0);
+
+        // Between the thresholds the given format is kept.
+        let middle = Matrix::from_fn(10, 10, |i, j| if (i + j) % 5 == 0 { 1 } else { 0 });
+        assert!(!SmartMatrix::new(middle.clone()).is_sparse());
+        assert!(SmartMatrix::from_csr(CsrMatrix::from_dense(&middle)).is_sparse());
+    }
+
+    #[test]
+    fn smart_matrix_operations_switch_format() {
+        let n = 30;
+        let a = SmartMatrix::new(tridiagonal(n));
+        let x: Vec<i64> = (0..n as i64).collect();
+        assert_eq!(a.matvec(&x), tridiagonal(n).matvec(&x));
+
+        // Squaring widens the band to five diagonals, still sparse; the sixth
+        // power has thirteen, past the threshold.
+        let square = a.checked_mul(&a).unwrap();
+        assert!(square.is_sparse());
+        let sixth = square.checked_mul(&square).unwrap().checked_mul(&square).unwrap();
+        assert!(!sixth.is_sparse());
+        let dense = tridiagonal(n);
+        let expected = &(&(&dense * &dense) * &(&dense * &dense)) * &(&dense * &dense);
+        assert_eq!(sixth.to_dense(), expected);
+
+        // Mixed operands agree with the dense computation.
+        assert_eq!(sixth.checked_mul(&a).unwrap().to_dense(), &expected * &dense);
+        assert_eq!(a.checked_mul(&sixth).unwrap().to_dense(), &dense * &expected);
+        assert_eq!(a.checked_add(&sixth).unwrap().to_dense(), &dense + &expected);
+        assert_eq!(a.transpose(), a);
+
+        // Cancelling everything leaves an empty sparse matrix.
+        let negated = SmartMatrix::new(&dense * -1);
+        let zero = negated.checked_add(&a).unwrap();
+        assert!(zero.is_sparse());
+        assert_eq!(zero.nnz(), 0);
+
+        assert!(a.checked_mul(&SmartMatrix::new(Matrix::new(3, 3, 1))).is_err());
+        assert!(a.checked_add(&SmartMatrix::new(Matrix::new(3, 3, 1))).is_err());
+    }
+
+    #[test]
+    fn smart_matrix_set_converts_when_filled() {
+        let mut matrix = SmartMatrix::new(Matrix::new(10, 10, 0.0));
+        assert!(matrix.is_sparse());
+        for k in 0..30 {
+            matrix.set(k / 10, k % 10, 1.0);
+            assert!(matrix.is_sparse());
+        }
+        matrix.set(9, 9, 2.0);
+        assert!(!matrix.is_sparse());
+        assert_eq!(matrix.element(9, 9), 2.0);
+
+        let mut sparse = SmartMatrix::new(Matrix::new(10, 10, 0.0));
+        sparse.set(3, 4, 5.0);
+        sparse.set(3, 2, 1.0);
+        sparse.set(3, 4, 0.0);
+        assert_eq!(sparse.to_csr().row(3), (&[2][..], &[1.0][..]));
+        assert_eq!(sparse.nnz(), 1);
+    }
+}
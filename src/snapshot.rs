@@ -0,0 +1,120 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use num::Float;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+
+fn parse_error(line: usize, reason: String) -> MatrixError {
+    MatrixError::Parse { line, reason }
+}
+
+impl<T: Float + Display> Matrix<T> {
+    /// Renders the matrix in a stable text format for golden-file tests.
+    ///
+    /// The first line is a `matrix <rows>x<cols>` header, followed by one line per
+    /// row with every element printed to `precision` decimal places and each column
+    /// right-aligned, so a changed element shows up as a one-line diff. Negative
+    /// zero is written as zero, so results that differ only in the sign of zero
+    /// produce identical snapshots.
+    pub fn to_snapshot_string(&self, precision: usize) -> String {
+        let cells: Vec<String> = self.data.iter()
+            .map(|&val| {
+                let val = if val == T::zero() { T::zero() } else { val };
+                format!("{:.*}", precision, val)
+            })
+            .collect();
+        let widths: Vec<usize> = (0..self.cols)
+            .map(|j| (0..self.rows).map(|i| cells[i * self.cols + j].len()).max().unwrap_or(0))
+            .collect();
+
+        let mut output = format!("matrix {}x{}\n", self.rows, self.cols);
+        for i in 0..self.rows {
+            let row: Vec<String> = (0..self.cols)
+                .map(|j| format!("{:>width$}", cells[i * self.cols + j], width = widths[j]))
+                .collect();
+            output.push_str(&row.join(" "));
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl<T: Float + FromStr> Matrix<T> {
+    /// Reads a matrix written by [`Matrix::to_snapshot_string`].
+    ///
+    /// Any amount of whitespace may separate elements, so snapshots can be edited by hand.
+    pub fn from_snapshot_string(snapshot: &str) -> Result<Matrix<T>, MatrixError> {
+        let mut lines = snapshot.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+
+        let (rows, cols) = lines.next()
+            .and_then(|(_, header)| header.trim().strip_prefix("matrix "))
+            .and_then(|shape| {
+                let mut dims = shape.trim().split('x').map(|dim| dim.parse::<usize>());
+                match (dims.next(), dims.next(), dims.next()) {
+                    (Some(Ok(rows)), Some(Ok(cols)), None) => Some((rows, cols)),
+                    _ => None
+                }
+            })
+            .ok_or_else(|| parse_error(1, "expected a `matrix <rows>x<cols>` header".to_string()))?;
+
+        let mut data = Vec::with_capacity(rows * cols);
+        let mut found_rows = 0;
+        for (index, line) in lines {
+            let line_number = index + 1;
+            let row = line.split_whitespace()
+                .map(|cell| cell.parse::<T>().map_err(|_| parse_error(line_number, format!("invalid element `{}`", cell))))
+                .collect::<Result<Vec<T>, MatrixError>>()?;
+            if row.len() != cols {
+                return Err(MatrixError::RaggedRows { row: found_rows, expected: cols, found: row.len() });
+            }
+            data.extend(row);
+            found_rows += 1;
+        }
+        if found_rows != rows {
+            return Err(parse_error(snapshot.lines().count(), format!("expected {} rows but found {}", rows, found_rows)));
+        }
+
+        Ok(Matrix {
+            rows,
+            cols,
+            data
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_round_trip() {
+        let matrix = Matrix::from(vec![
+            vec![1.0, -0.0, 12.5],
+            vec![-3.25, 100.0, f64::INFINITY]
+        ]);
+
+        let snapshot = matrix.to_snapshot_string(2);
+
+        assert_eq!(snapshot, "matrix 2x3\n 1.00   0.00 12.50\n-3.25 100.00   inf\n");
+        assert_eq!(Matrix::<f64>::from_snapshot_string(&snapshot).unwrap(), matrix);
+    }
+
+    #[test]
+    fn snapshot_parse_errors() {
+        assert_eq!(
+            Matrix::<f64>::from_snapshot_string("2x2\n1 2\n3 4\n"),
+            Err(MatrixError::Parse { line: 1, reason: "expected a `matrix <rows>x<cols>` header".to_string() })
+        );
+        assert_eq!(
+            Matrix::<f64>::from_snapshot_string("matrix 2x2\n1 2\n3 four\n"),
+            Err(MatrixError::Parse { line: 3, reason: "invalid element `four`".to_string() })
+        );
+        assert_eq!(
+            Matrix::<f64>::from_snapshot_string("matrix 2x2\n1 2\n3\n"),
+            Err(MatrixError::RaggedRows { row: 1, expected: 2, found: 1 })
+        );
+        assert!(Matrix::<f64>::from_snapshot_string("matrix 3x2\n1 2\n3 4\n").is_err());
+    }
+}
@@ -0,0 +1,157 @@
+use std::cmp::Ordering;
+
+use crate::Matrix;
+
+/// A total order for sorting partially ordered values: values that aren't
+/// comparable to themselves, such as NaN, sort after all others and equal to
+/// each other.
+///
+/// `sort_by` may panic when its comparison isn't a total order, as treating
+/// NaN as equal to everything would be.
+pub(crate) fn nan_last_cmp<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    let is_nan = |val: &T| val.partial_cmp(val).is_none();
+    match (is_nan(a), is_nan(b)) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(b).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Like [`nan_last_cmp`] but with NaN before all other values, so that the
+/// maximum of a pivot search is never NaN while any other candidate remains.
+pub(crate) fn nan_first_cmp<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    let is_nan = |val: &T| val.partial_cmp(val).is_none();
+    match (is_nan(a), is_nan(b)) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => a.partial_cmp(b).unwrap_or(Ordering::Equal)
+    }
+}
+
+fn argsort<T: PartialOrd>(values: &[T]) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..values.len()).collect();
+    indices.sort_by(|&a, &b| nan_last_cmp(&values[a], &values[b]));
+    indices
+}
+
+impl<T> Matrix<T> {
+    /// Row indices in the order that sorts the matrix ascending by column `column`.
+    ///
+    /// The sort is stable and puts NaN last, and the result can be passed
+    /// straight to [`Matrix::permute_rows`].
+    pub fn argsort_column(&self, column: usize) -> Vec<usize>
+        where T: PartialOrd + Clone
+    {
+        argsort(&self.get_column(column))
+    }
+
+    /// Column indices in the order that sorts the matrix ascending by row `row`.
+    ///
+    /// The sort is stable and puts NaN last, and the result can be passed
+    /// straight to [`Matrix::permute_columns`].
+    pub fn argsort_row(&self, row: usize) -> Vec<usize>
+        where T: PartialOrd
+    {
+        argsort(&self.data[row * self.cols..(row + 1) * self.cols])
+    }
+
+    /// Builds a matrix whose `i`th row is row `permutation[i]` of `self`.
+    ///
+    /// Indices may repeat or be omitted, so this also selects rows (e.g. the
+    /// first `k` entries of an argsort for top-k selection).
+    pub fn permute_rows(&self, permutation: &[usize]) -> Matrix<T>
+        where T: Clone
+    {
+        let mut data = vec![];
+        for &row in permutation {
+            data.extend(self.get_row(row));
+        }
+
+        Matrix {
+            rows: permutation.len(),
+            cols: self.cols,
+            data
+        }
+    }
+
+    /// Builds a matrix whose `j`th column is column `permutation[j]` of `self`.
+    pub fn permute_columns(&self, permutation: &[usize]) -> Matrix<T>
+        where T: Clone
+    {
+        let mut data = vec![];
+        for i in 0..self.rows {
+            for &col in permutation {
+                data.push(self.get(i, col).clone());
+            }
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: permutation.len(),
+            data
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn argsort_column_and_permute() {
+        let matrix = Matrix::from(vec![
+            vec![3, 30],
+            vec![1, 10],
+            vec![2, 20]
+        ]);
+
+        let order = matrix.argsort_column(0);
+        assert_eq!(order, vec![1, 2, 0]);
+
+        let expected_matrix = Matrix::from(vec![
+            vec![1, 10],
+            vec![2, 20],
+            vec![3, 30]
+        ]);
+        assert_eq!(matrix.permute_rows(&order), expected_matrix);
+    }
+
+    #[test]
+    fn argsort_row_is_stable() {
+        let matrix = Matrix::from(vec![
+            vec![2.0, 1.0, 2.0, 0.5]
+        ]);
+
+        let order = matrix.argsort_row(0);
+        assert_eq!(order, vec![3, 1, 0, 2]);
+        assert_eq!(matrix.permute_columns(&order), Matrix::from(vec![vec![0.5, 1.0, 2.0, 2.0]]));
+    }
+
+    #[test]
+    fn argsort_puts_nan_last() {
+        let matrix = Matrix::from(vec![
+            vec![f64::NAN, 3.0, f64::NAN, -1.0, 2.0, f64::NAN, 0.0]
+        ]);
+
+        assert_eq!(matrix.argsort_row(0), vec![3, 6, 4, 1, 0, 2, 5]);
+        assert_eq!(matrix.transpose().argsort_column(0), vec![3, 6, 4, 1, 0, 2, 5]);
+    }
+
+    #[test]
+    fn top_k_rows() {
+        let matrix = Matrix::from(vec![
+            vec![5],
+            vec![9],
+            vec![7]
+        ]);
+
+        let mut order = matrix.argsort_column(0);
+        order.reverse();
+        let top = matrix.permute_rows(&order[..2]);
+
+        assert_eq!(top.rows, 2);
+        assert_eq!(top.data, vec![9, 7]);
+    }
+}
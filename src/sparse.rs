@@ -0,0 +1,485 @@
+use std::ops::Range;
+
+use crate::Matrix;
+use crate::element::MatrixElement;
+use crate::error::MatrixError;
+use crate::shape::Shape;
+use crate::traits::MatrixOps;
+
+/// A sparse matrix in compressed sparse row (CSR) format.
+///
+/// Row `i` stores its nonzero entries at positions
+/// `row_offsets[i]..row_offsets[i + 1]` of `col_indices` and `values`, with the
+/// columns strictly increasing. Memory and the cost of a matrix-vector product
+/// grow with the number of stored entries instead of `rows * cols`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T> {
+    pub(crate) rows: usize,
+    pub(crate) cols: usize,
+    pub(crate) row_offsets: Vec<usize>,
+    pub(crate) col_indices: Vec<usize>,
+    pub(crate) values: Vec<T>
+}
+
+impl<T: MatrixElement> CsrMatrix<T> {
+    /// Assembles a matrix from `(row, col, value)` triplets in any order.
+    ///
+    /// Values given for the same position are summed, as in finite element
+    /// assembly, and positions that sum to zero are not stored.
+    pub fn from_triplets(rows: usize, cols: usize, triplets: &[(usize, usize, T)]) -> Result<CsrMatrix<T>, MatrixError> {
+        if let Some(&(row, col, _)) = triplets.iter().find(|&&(row, col, _)| row >= rows || col >= cols) {
+            return Err(MatrixError::IndexOutOfBounds { row, col, rows, cols });
+        }
+
+        let mut sorted: Vec<&(usize, usize, T)> = triplets.iter().collect();
+        sorted.sort_by_key(|&&(row, col, _)| (row, col));
+
+        let mut row_offsets = vec![0; rows + 1];
+        let mut col_indices = vec![];
+        let mut values: Vec<T> = vec![];
+        let mut last = None;
+        for &(row, col, ref val) in sorted {
+            if last == Some((row, col)) {
+                let total = values.pop().unwrap() + val.clone();
+                values.push(total);
+            } else {
+                col_indices.push(col);
+                values.push(val.clone());
+                row_offsets[row + 1] += 1;
+                last = Some((row, col));
+            }
+        }
+        for i in 0..rows {
+            row_offsets[i + 1] += row_offsets[i];
+        }
+
+        Ok(CsrMatrix {
+            rows,
+            cols,
+            row_offsets,
+            col_indices,
+            values
+        }.without_zeros())
+    }
+
+    /// The nonzero entries of a dense matrix.
+    pub fn from_dense(matrix: &Matrix<T>) -> CsrMatrix<T> {
+        let mut row_offsets = Vec::with_capacity(matrix.rows + 1);
+        let mut col_indices = vec![];
+        let mut values = vec![];
+        row_offsets.push(0);
+        for row in matrix.data.chunks(matrix.cols.max(1)).take(matrix.rows) {
+            for (j, val) in row.iter().enumerate().filter(|(_, val)| !val.is_zero()) {
+                col_indices.push(j);
+                values.push(val.clone());
+            }
+            row_offsets.push(col_indices.len());
+        }
+
+        CsrMatrix {
+            rows: matrix.rows,
+            cols: matrix.cols,
+            row_offsets,
+            col_indices,
+            values
+        }
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut matrix = Matrix::new(self.rows, self.cols, T::zero());
+        for i in 0..self.rows {
+            for (&j, val) in self.row(i).0.iter().zip(self.row(i).1.iter()) {
+                matrix.set(i, j, val.clone());
+            }
+        }
+        matrix
+    }
+
+    /// The matrix formed by rows `indices` of `self`, in that order; an index
+    /// may repeat. Panics if an index is out of bounds.
+    pub fn select_rows(&self, indices: &[usize]) -> CsrMatrix<T> {
+        let mut row_offsets = Vec::with_capacity(indices.len() + 1);
+        row_offsets.push(0);
+        let mut col_indices = vec![];
+        let mut values = vec![];
+        for &i in indices {
+            assert!(i < self.rows, "row {} is out of bounds for a matrix with {} rows", i, self.rows);
+            let (cols, row_values) = self.row(i);
+            col_indices.extend_from_slice(cols);
+            values.extend_from_slice(row_values);
+            row_offsets.push(col_indices.len());
+        }
+
+        CsrMatrix {
+            rows: indices.len(),
+            cols: self.cols,
+            row_offsets,
+            col_indices,
+            values
+        }
+    }
+
+    /// The matrix formed by columns `indices` of `self`, in that order; an
+    /// index may repeat. Panics if an index is out of bounds.
+    ///
+    /// Runs in time proportional to the stored entries, not the dense size.
+    pub fn select_cols(&self, indices: &[usize]) -> CsrMatrix<T> {
+        // Where each original column lands in the result.
+        let mut targets: Vec<Vec<usize>> = vec![vec![]; self.cols];
+        for (new, &old) in indices.iter().enumerate() {
+            assert!(old < self.cols, "column {} is out of bounds for a matrix with {} columns", old, self.cols);
+            targets[old].push(new);
+        }
+
+        let mut row_offsets = Vec::with_capacity(self.rows + 1);
+        row_offsets.push(0);
+        let mut col_indices = vec![];
+        let mut values = vec![];
+        let mut row = vec![];
+        for i in 0..self.rows {
+            let (cols, row_values) = self.row(i);
+            row.clear();
+            for (&j, val) in cols.iter().zip(row_values.iter()) {
+                row.extend(targets[j].iter().map(|&new| (new, val.clone())));
+            }
+            row.sort_by_key(|&(new, _)| new);
+            for (new, val) in row.drain(..) {
+                col_indices.push(new);
+                values.push(val);
+            }
+            row_offsets.push(col_indices.len());
+        }
+
+        CsrMatrix {
+            rows: self.rows,
+            cols: indices.len(),
+            row_offsets,
+            col_indices,
+            values
+        }
+    }
+
+    /// The contiguous block of rows `rows` and columns `cols`, as a new sparse
+    /// matrix. Panics if either range reaches past the matrix.
+    pub fn block(&self, rows: Range<usize>, cols: Range<usize>) -> CsrMatrix<T> {
+        assert!(
+            rows.start <= rows.end && rows.end <= self.rows && cols.start <= cols.end && cols.end <= self.cols,
+            "block {:?} x {:?} is out of bounds for a {} matrix", rows, cols, self.shape()
+        );
+
+        let mut row_offsets = Vec::with_capacity(rows.len() + 1);
+        row_offsets.push(0);
+        let mut col_indices = vec![];
+        let mut values = vec![];
+        for i in rows.clone() {
+            let (row_cols, row_values) = self.row(i);
+            // The stored columns are sorted, so the block is one contiguous run.
+            let start = row_cols.partition_point(|&j| j < cols.start);
+            let end = row_cols.partition_point(|&j| j < cols.end);
+            col_indices.extend(row_cols[start..end].iter().map(|&j| j - cols.start));
+            values.extend_from_slice(&row_values[start..end]);
+            row_offsets.push(col_indices.len());
+        }
+
+        CsrMatrix {
+            rows: rows.len(),
+            cols: cols.len(),
+            row_offsets,
+            col_indices,
+            values
+        }
+    }
+
+    /// The transpose, built by counting the entries of each column in one pass
+    /// and scattering them in a second.
+    pub fn transpose(&self) -> CsrMatrix<T> {
+        let mut counts = vec![0; self.cols + 1];
+        for &j in self.col_indices.iter() {
+            counts[j + 1] += 1;
+        }
+        for j in 0..self.cols {
+            counts[j + 1] += counts[j];
+        }
+
+        let row_offsets = counts.clone();
+        let mut next = counts;
+        let mut col_indices = vec![0; self.nnz()];
+        let mut values = self.values.clone();
+        // Walking the rows in order leaves each row of the transpose sorted.
+        for i in 0..self.rows {
+            let (cols, row_values) = self.row(i);
+            for (&j, val) in cols.iter().zip(row_values.iter()) {
+                col_indices[next[j]] = i;
+                values[next[j]] = val.clone();
+                next[j] += 1;
+            }
+        }
+
+        CsrMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            row_offsets,
+            col_indices,
+            values
+        }
+    }
+
+    /// The sum `self + rhs`, or an error if the shapes differ.
+    pub fn checked_add(&self, rhs: &CsrMatrix<T>) -> Result<CsrMatrix<T>, MatrixError> {
+        let shape = self.shape().elementwise_with(rhs.shape())?;
+        let mut row_offsets = Vec::with_capacity(shape.rows + 1);
+        row_offsets.push(0);
+        let mut col_indices = vec![];
+        let mut values = vec![];
+        for i in 0..shape.rows {
+            let (a_cols, a_values) = self.row(i);
+            let (b_cols, b_values) = rhs.row(i);
+            let (mut p, mut q) = (0, 0);
+            while p < a_cols.len() || q < b_cols.len() {
+                if q == b_cols.len() || (p < a_cols.len() && a_cols[p] < b_cols[q]) {
+                    col_indices.push(a_cols[p]);
+                    values.push(a_values[p].clone());
+                    p += 1;
+                } else if p == a_cols.len() || b_cols[q] < a_cols[p] {
+                    col_indices.push(b_cols[q]);
+                    values.push(b_values[q].clone());
+                    q += 1;
+                } else {
+                    col_indices.push(a_cols[p]);
+                    values.push(a_values[p].clone() + b_values[q].clone());
+                    p += 1;
+                    q += 1;
+                }
+            }
+            row_offsets.push(col_indices.len());
+        }
+
+        Ok(CsrMatrix {
+            rows: shape.rows,
+            cols: shape.cols,
+            row_offsets,
+            col_indices,
+            values
+        }.without_zeros())
+    }
+
+    /// The product `self * rhs`, or an error if the inner dimensions differ.
+    ///
+    /// Each row of the result combines the rows of `rhs` selected by the
+    /// entries of the matching row of `self` (Gustavson's algorithm), so the
+    /// work is proportional to the multiplications actually needed.
+    pub fn checked_mul(&self, rhs: &CsrMatrix<T>) -> Result<CsrMatrix<T>, MatrixError> {
+        let shape = self.shape().matmul_with(rhs.shape())?;
+        let mut row_offsets = Vec::with_capacity(shape.rows + 1);
+        row_offsets.push(0);
+        let mut col_indices = vec![];
+        let mut values = vec![];
+
+        let mut accumulator = vec![T::zero(); shape.cols];
+        // The row of the result each column was last touched by.
+        let mut touched = vec![usize::MAX; shape.cols];
+        let mut pattern = vec![];
+        for i in 0..shape.rows {
+            pattern.clear();
+            let (cols, row_values) = self.row(i);
+            for (&k, a) in cols.iter().zip(row_values.iter()) {
+                let (b_cols, b_values) = rhs.row(k);
+                for (&j, b) in b_cols.iter().zip(b_values.iter()) {
+                    if touched[j] != i {
+                        touched[j] = i;
+                        pattern.push(j);
+                        accumulator[j] = T::zero();
+                    }
+                    accumulator[j] = accumulator[j].clone() + a.clone() * b.clone();
+                }
+            }
+            pattern.sort_unstable();
+            for &j in pattern.iter() {
+                col_indices.push(j);
+                values.push(accumulator[j].clone());
+            }
+            row_offsets.push(col_indices.len());
+        }
+
+        Ok(CsrMatrix {
+            rows: shape.rows,
+            cols: shape.cols,
+            row_offsets,
+            col_indices,
+            values
+        }.without_zeros())
+    }
+
+    /// Drops stored entries that are zero.
+    pub(crate) fn without_zeros(mut self) -> CsrMatrix<T> {
+        let mut kept = 0;
+        let mut start = 0;
+        for i in 0..self.rows {
+            let end = self.row_offsets[i + 1];
+            for k in start..end {
+                if !self.values[k].is_zero() {
+                    self.col_indices[kept] = self.col_indices[k];
+                    self.values.swap(kept, k);
+                    kept += 1;
+                }
+            }
+            start = end;
+            self.row_offsets[i + 1] = kept;
+        }
+        self.col_indices.truncate(kept);
+        self.values.truncate(kept);
+        self
+    }
+}
+
+impl<T> CsrMatrix<T> {
+    pub fn shape(&self) -> Shape {
+        Shape::new(self.rows, self.cols)
+    }
+
+    /// The number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// The column indices and values stored in row `i`.
+    pub fn row(&self, i: usize) -> (&[usize], &[T]) {
+        let range = self.row_offsets[i]..self.row_offsets[i + 1];
+        (&self.col_indices[range.clone()], &self.values[range])
+    }
+}
+
+impl<T: MatrixElement> MatrixOps<T> for CsrMatrix<T> {
+    fn shape(&self) -> Shape {
+        Shape::new(self.rows, self.cols)
+    }
+
+    fn element(&self, row: usize, col: usize) -> T {
+        let (cols, values) = self.row(row);
+        match cols.binary_search(&col) {
+            Ok(k) => values[k].clone(),
+            Err(_) => T::zero()
+        }
+    }
+
+    fn row_entries(&self, row: usize) -> Box<dyn Iterator<Item = (usize, T)> + '_> {
+        let (cols, values) = self.row(row);
+        Box::new(cols.iter().copied().zip(values.iter().cloned()))
+    }
+
+    /// Visits only the stored entries, in `O(nnz)` operations.
+    fn matvec(&self, x: &[T]) -> Vec<T> {
+        assert_eq!(x.len(), self.cols);
+
+        (0..self.rows)
+            .map(|i| {
+                let (cols, values) = self.row(i);
+                cols.iter().zip(values.iter()).fold(T::zero(), |total, (&j, val)| total + val.clone() * x[j].clone())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csr_from_triplets_and_dense() {
+        let dense = Matrix::from(vec![
+            vec![4, 0, 0, -1],
+            vec![0, 0, 0, 0],
+            vec![2, 3, 0, 5]
+        ]);
+
+        let sparse = CsrMatrix::from_dense(&dense);
+        assert_eq!(sparse.nnz(), 5);
+        assert_eq!(sparse.row(2), (&[0, 1, 3][..], &[2, 3, 5][..]));
+        assert_eq!(sparse.to_dense(), dense);
+
+        // Out of order, with duplicates summed and a cancelling pair dropped.
+        let assembled = CsrMatrix::from_triplets(3, 4, &[
+            (2, 3, 5), (0, 0, 3), (2, 1, 3), (0, 3, -1), (0, 0, 1), (2, 0, 2), (1, 2, 7), (1, 2, -7)
+        ]).unwrap();
+        assert_eq!(assembled, sparse);
+
+        assert_eq!(
+            CsrMatrix::from_triplets(2, 2, &[(0, 2, 1)]),
+            Err(MatrixError::IndexOutOfBounds { row: 0, col: 2, rows: 2, cols: 2 })
+        );
+    }
+
+    #[test]
+    fn csr_slicing() {
+        let dense = Matrix::from(vec![
+            vec![1, 0, 2, 0],
+            vec![0, 3, 0, 4],
+            vec![5, 0, 0, 6]
+        ]);
+        let sparse = CsrMatrix::from_dense(&dense);
+
+        let rows = sparse.select_rows(&[2, 0, 2]);
+        assert_eq!(rows.shape(), Shape::new(3, 4));
+        assert_eq!(rows.to_dense(), Matrix::from(vec![vec![5, 0, 0, 6], vec![1, 0, 2, 0], vec![5, 0, 0, 6]]));
+
+        let cols = sparse.select_cols(&[3, 0, 3]);
+        assert_eq!(cols.shape(), Shape::new(3, 3));
+        assert_eq!(cols.to_dense(), Matrix::from(vec![vec![0, 1, 0], vec![4, 0, 4], vec![6, 5, 6]]));
+        assert_eq!(cols.nnz(), 6);
+
+        let block = sparse.block(1..3, 1..4);
+        assert_eq!(block.to_dense(), dense.view(1..3, 1..4).to_owned());
+        assert_eq!(block.row(1), (&[2][..], &[6][..]));
+        assert_eq!(sparse.block(0..0, 0..4).nnz(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn csr_select_out_of_bounds_panics() {
+        CsrMatrix::from_dense(&Matrix::new(2, 2, 1)).select_cols(&[2]);
+    }
+
+    #[test]
+    fn csr_arithmetic_matches_dense() {
+        let a = Matrix::from(vec![
+            vec![1, 0, 2, 0],
+            vec![0, 0, 0, 0],
+            vec![3, -4, 0, 5]
+        ]);
+        let b = Matrix::from(vec![
+            vec![0, 1, 0],
+            vec![2, 0, 0],
+            vec![0, 0, 0],
+            vec![-1, 0, 6]
+        ]);
+        let (sparse_a, sparse_b) = (CsrMatrix::from_dense(&a), CsrMatrix::from_dense(&b));
+
+        assert_eq!(sparse_a.transpose().to_dense(), a.transpose());
+        assert_eq!(sparse_b.transpose().transpose(), sparse_b);
+
+        let product = sparse_a.checked_mul(&sparse_b).unwrap();
+        assert_eq!(product.to_dense(), &a * &b);
+        assert_eq!(product.row(1).0.len(), 0);
+
+        let sum = sparse_a.checked_add(&CsrMatrix::from_dense(&(&a * -1))).unwrap();
+        assert_eq!(sum.nnz(), 0);
+        assert_eq!(sparse_a.checked_add(&sparse_a).unwrap().to_dense(), &a + &a);
+        assert!(sparse_a.checked_add(&sparse_b).is_err());
+        assert!(sparse_a.checked_mul(&sparse_a).is_err());
+    }
+
+    #[test]
+    fn csr_matrix_ops() {
+        let dense = Matrix::from(vec![
+            vec![1.0, 0.0, 2.0],
+            vec![0.0, 3.0, 0.0]
+        ]);
+        let sparse = CsrMatrix::from_dense(&dense);
+
+        assert_eq!(MatrixOps::shape(&sparse), Shape::new(2, 3));
+        assert_eq!(sparse.element(0, 2), 2.0);
+        assert_eq!(sparse.element(1, 0), 0.0);
+        assert_eq!(sparse.row_entries(0).collect::<Vec<_>>(), vec![(0, 1.0), (2, 2.0)]);
+        assert_eq!(sparse.matvec(&[1.0, 2.0, 3.0]), dense.matvec(&[1.0, 2.0, 3.0]));
+    }
+}
@@ -0,0 +1,116 @@
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use num::BigInt;
+use num::rational::Ratio;
+
+use crate::Matrix;
+use crate::element::MatrixElement;
+use crate::error::MatrixError;
+
+/// A [`Matrix`] known to have as many rows as columns.
+///
+/// Operations that only make sense for square matrices are available here without
+/// a shape check, since the check happened once when the value was constructed.
+/// All read-only `Matrix` methods are available through `Deref`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SquareMatrix<T> {
+    inner: Matrix<T>
+}
+
+impl<T> Matrix<T> {
+    pub fn try_into_square(self) -> Result<SquareMatrix<T>, MatrixError> {
+        SquareMatrix::try_from(self)
+    }
+}
+
+impl<T> TryFrom<Matrix<T>> for SquareMatrix<T> {
+    type Error = MatrixError;
+
+    fn try_from(matrix: Matrix<T>) -> Result<SquareMatrix<T>, MatrixError> {
+        if matrix.rows != matrix.cols {
+            return Err(MatrixError::NotSquare { rows: matrix.rows, cols: matrix.cols });
+        }
+        Ok(SquareMatrix {
+            inner: matrix
+        })
+    }
+}
+
+impl<T> Deref for SquareMatrix<T> {
+    type Target = Matrix<T>;
+
+    fn deref(&self) -> &Matrix<T> {
+        &self.inner
+    }
+}
+
+impl<T> SquareMatrix<T> {
+    /// The number of rows, which is also the number of columns.
+    pub fn size(&self) -> usize {
+        self.inner.rows
+    }
+
+    pub fn into_inner(self) -> Matrix<T> {
+        self.inner
+    }
+
+    /// The sum of the diagonal elements.
+    pub fn trace(&self) -> T
+        where T: MatrixElement
+    {
+        (0..self.size()).fold(T::zero(), |total, i| total + self.inner.get(i, i).clone())
+    }
+}
+
+impl SquareMatrix<i64> {
+    pub fn determinant(&self) -> i64 {
+        self.inner.determinant()
+    }
+
+    pub fn inverse(&self) -> Option<SquareMatrix<Ratio<i64>>> {
+        self.inner.inverse().map(|inner| SquareMatrix { inner })
+    }
+}
+
+impl SquareMatrix<BigInt> {
+    pub fn determinant(&self) -> BigInt {
+        self.inner.determinant()
+    }
+
+    pub fn inverse(&self) -> Option<SquareMatrix<Ratio<BigInt>>> {
+        self.inner.inverse().map(|inner| SquareMatrix { inner })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn square_matrix_validation() {
+        let rectangular = Matrix::new(2, 3, 0);
+        assert_eq!(rectangular.try_into_square(), Err(MatrixError::NotSquare { rows: 2, cols: 3 }));
+
+        let square = Matrix::new(2, 2, 1).try_into_square().unwrap();
+        assert_eq!(square.size(), 2);
+        assert_eq!(square.into_inner(), Matrix::new(2, 2, 1));
+    }
+
+    #[test]
+    fn square_matrix_operations() {
+        let matrix: Matrix<i64> = Matrix::from(vec![
+            vec![4, 7],
+            vec![2, 6]
+        ]);
+        let square = SquareMatrix::try_from(matrix).unwrap();
+
+        assert_eq!(square.trace(), 10);
+        assert_eq!(square.determinant(), 10);
+        assert_eq!(*square.get(1, 0), 2);
+
+        let inverse = square.inverse().unwrap();
+        assert_eq!(inverse.size(), 2);
+        assert_eq!(*inverse.get(0, 0), Ratio::new(3, 5));
+    }
+}
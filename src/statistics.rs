@@ -0,0 +1,268 @@
+use num::Float;
+
+use crate::{Axis, Matrix};
+use crate::error::MatrixError;
+use crate::sort::nan_last_cmp;
+
+/// Equal-width bins over a range of values and how many values fell in each.
+///
+/// `edges` has one more entry than `counts`; bin `i` covers `edges[i]..edges[i + 1]`,
+/// and the last bin also includes its upper edge.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram<T> {
+    pub edges: Vec<T>,
+    pub counts: Vec<usize>
+}
+
+impl<T: Float> Histogram<T> {
+    /// Bins the finite values, ignoring NaN and infinities.
+    fn of(values: impl Iterator<Item = T> + Clone, bins: usize) -> Histogram<T> {
+        assert!(bins > 0, "a histogram needs at least one bin");
+
+        let finite = values.filter(|val| val.is_finite());
+        let (mut min, mut max) = finite.clone()
+            .fold((T::infinity(), T::neg_infinity()), |(min, max), val| (min.min(val), max.max(val)));
+        if min > max {
+            min = T::zero();
+            max = T::one();
+        } else if min == max {
+            // Matches NumPy: a single repeated value is centred in a unit-wide range.
+            let half = T::from(0.5).unwrap();
+            min = min - half;
+            max = max + half;
+        }
+
+        let width = (max - min) / T::from(bins).unwrap();
+        let edges = (0..=bins).map(|i| min + width * T::from(i).unwrap()).collect();
+        let mut counts = vec![0; bins];
+        for val in finite {
+            let bin = ((val - min) / width).to_usize().unwrap_or(0).min(bins - 1);
+            counts[bin] += 1;
+        }
+
+        Histogram {
+            edges,
+            counts
+        }
+    }
+}
+
+/// The `q` quantile of `values` by linear interpolation between order
+/// statistics, or NaN if any value is NaN, as with NumPy's `quantile`.
+///
+/// Returns [`MatrixError::EmptyInput`] if `values` is empty.
+fn quantile<T: Float>(values: &mut [T], q: T) -> Result<T, MatrixError> {
+    assert!(q >= T::zero() && q <= T::one(), "quantile must lie in [0, 1]");
+    if values.is_empty() {
+        return Err(MatrixError::EmptyInput);
+    }
+    values.sort_by(nan_last_cmp);
+    if values.last().is_some_and(|val| val.is_nan()) {
+        return Ok(T::nan());
+    }
+
+    let position = q * T::from(values.len() - 1).unwrap();
+    let low = position.floor();
+    let fraction = position - low;
+    let low = low.to_usize().unwrap();
+    let high = (low + 1).min(values.len() - 1);
+    Ok(values[low] + (values[high] - values[low]) * fraction)
+}
+
+impl<T: Float> Matrix<T> {
+    /// The mean of each row (`Axis::Rows`) or column (`Axis::Cols`).
+    pub fn mean_axis(&self, axis: Axis) -> Vec<T> {
+        (0..self.lane_count(axis))
+            .map(|index| {
+                let lane = self.lane(axis, index);
+                lane.iter().fold(T::zero(), |total, &val| total + val) / T::from(lane.len()).unwrap()
+            })
+            .collect()
+    }
+
+    /// The population standard deviation of each row or column.
+    pub fn std_axis(&self, axis: Axis) -> Vec<T> {
+        self.mean_axis(axis)
+            .into_iter()
+            .enumerate()
+            .map(|(index, mean)| {
+                let lane = self.lane(axis, index);
+                let squares = lane.iter().fold(T::zero(), |total, &val| total + (val - mean) * (val - mean));
+                (squares / T::from(lane.len()).unwrap()).sqrt()
+            })
+            .collect()
+    }
+
+    /// The `q` quantile of each row or column, interpolating linearly between
+    /// elements. Lanes containing NaN give NaN.
+    ///
+    /// Returns [`MatrixError::EmptyInput`] if the lanes are empty, e.g. for the
+    /// columns of a matrix with no rows.
+    pub fn quantile_axis(&self, axis: Axis, q: T) -> Result<Vec<T>, MatrixError> {
+        (0..self.lane_count(axis)).map(|index| quantile(&mut self.lane(axis, index), q)).collect()
+    }
+
+    /// Standardises every column to zero mean and unit population standard deviation.
+    ///
+    /// Constant columns have no spread to scale by and become all zeros.
+    pub fn zscore_columns(&self) -> Matrix<T> {
+        let means = self.mean_axis(Axis::Cols);
+        let std = self.std_axis(Axis::Cols);
+
+        let mut output = self.clone();
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let val = if std[j] == T::zero() {
+                    T::zero()
+                } else {
+                    (*self.get(i, j) - means[j]) / std[j]
+                };
+                output.set(i, j, val);
+            }
+        }
+        output
+    }
+
+    /// Clamps each column to its `lower_q` and `upper_q` quantiles.
+    pub fn winsorize_columns(&self, lower_q: T, upper_q: T) -> Matrix<T> {
+        assert!(lower_q <= upper_q, "lower quantile must not exceed upper quantile");
+        // Only empty columns have no quantiles, and then there is nothing to clamp.
+        let lower = self.quantile_axis(Axis::Cols, lower_q).unwrap_or_default();
+        let upper = self.quantile_axis(Axis::Cols, upper_q).unwrap_or_default();
+
+        let mut output = self.clone();
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                output.set(i, j, self.get(i, j).max(lower[j]).min(upper[j]));
+            }
+        }
+        output
+    }
+
+    /// Marks elements lying more than `k` interquartile ranges outside the
+    /// first or third quartile of their column (Tukey's fences; `k = 1.5` is usual).
+    pub fn iqr_outlier_mask(&self, k: T) -> Matrix<bool> {
+        // Only empty columns have no quartiles, and then there is nothing to mark.
+        let q1 = self.quantile_axis(Axis::Cols, T::from(0.25).unwrap()).unwrap_or_default();
+        let q3 = self.quantile_axis(Axis::Cols, T::from(0.75).unwrap()).unwrap_or_default();
+
+        let mut data = Vec::with_capacity(self.data.len());
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let iqr = q3[j] - q1[j];
+                let val = *self.get(i, j);
+                data.push(val < q1[j] - k * iqr || val > q3[j] + k * iqr);
+            }
+        }
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data
+        }
+    }
+
+    /// A histogram of every element with `bins` equal-width bins spanning the data.
+    pub fn histogram(&self, bins: usize) -> Histogram<T> {
+        Histogram::of(self.data.iter().cloned(), bins)
+    }
+
+    /// A separate [`Matrix::histogram`] for each column.
+    pub fn histogram_columns(&self, bins: usize) -> Vec<Histogram<T>> {
+        (0..self.cols)
+            .map(|j| Histogram::of((0..self.rows).map(|i| *self.get(i, j)), bins))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Matrix<f64> {
+        Matrix::from(vec![
+            vec![1.0, 5.0],
+            vec![2.0, 5.0],
+            vec![3.0, 5.0],
+            vec![4.0, 5.0],
+            vec![100.0, 5.0]
+        ])
+    }
+
+    #[test]
+    fn zscore_standardises_columns() {
+        let matrix = Matrix::from(vec![
+            vec![1.0, 7.0],
+            vec![3.0, 7.0]
+        ]);
+
+        let expected_matrix = Matrix::from(vec![
+            vec![-1.0, 0.0],
+            vec![1.0, 0.0]
+        ]);
+        assert_eq!(matrix.zscore_columns(), expected_matrix);
+    }
+
+    #[test]
+    fn winsorize_clamps_to_quantiles() {
+        let winsorized = example().winsorize_columns(0.25, 0.75);
+
+        assert_eq!(winsorized.get_column(0), vec![2.0, 2.0, 3.0, 4.0, 4.0]);
+        assert_eq!(winsorized.get_column(1), vec![5.0; 5]);
+    }
+
+    #[test]
+    fn iqr_mask_flags_outliers() {
+        let mask = example().iqr_outlier_mask(1.5);
+
+        assert_eq!(mask.get_column(0), vec![false, false, false, false, true]);
+    }
+
+    #[test]
+    fn histogram_of_values() {
+        let histogram = example().histogram(4);
+
+        assert_eq!(histogram.edges, vec![1.0, 25.75, 50.5, 75.25, 100.0]);
+        assert_eq!(histogram.counts, vec![9, 0, 0, 1]);
+        assert_eq!(histogram.counts.iter().sum::<usize>(), example().data.len());
+
+        let columns = example().histogram_columns(2);
+        assert_eq!(columns[0].counts, vec![4, 1]);
+        assert_eq!(columns[1].edges, vec![4.5, 5.0, 5.5]);
+        assert_eq!(columns[1].counts, vec![0, 5]);
+    }
+
+    #[test]
+    fn statistics_along_axis() {
+        let matrix = Matrix::from(vec![
+            vec![1.0, 3.0],
+            vec![5.0, 7.0]
+        ]);
+
+        assert_eq!(matrix.mean_axis(Axis::Rows), vec![2.0, 6.0]);
+        assert_eq!(matrix.mean_axis(Axis::Cols), vec![3.0, 5.0]);
+        assert_eq!(matrix.std_axis(Axis::Rows), vec![1.0, 1.0]);
+        assert_eq!(matrix.quantile_axis(Axis::Cols, 0.5), Ok(vec![3.0, 5.0]));
+    }
+
+    #[test]
+    fn quantile_with_nan() {
+        let matrix = Matrix::from(vec![
+            vec![3.0, f64::NAN, 1.0, 2.0],
+            vec![4.0, 1.0, 3.0, 2.0]
+        ]);
+
+        let medians = matrix.quantile_axis(Axis::Rows, 0.5).unwrap();
+        assert!(medians[0].is_nan());
+        assert_eq!(medians[1], 2.5);
+        assert!(matrix.quantile_axis(Axis::Rows, 0.0).unwrap()[0].is_nan());
+    }
+
+    #[test]
+    fn quantile_of_empty_lanes() {
+        let empty = Matrix::new(0, 3, 0.0);
+
+        assert_eq!(empty.quantile_axis(Axis::Cols, 0.5), Err(MatrixError::EmptyInput));
+        assert_eq!(empty.quantile_axis(Axis::Rows, 0.5), Ok(vec![]));
+        assert_eq!(empty.winsorize_columns(0.1, 0.9), empty);
+    }
+}
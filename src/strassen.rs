@@ -0,0 +1,161 @@
+use crate::Matrix;
+use crate::element::MatrixElement;
+use crate::error::MatrixError;
+use crate::matmul::blocked_product;
+
+/// Blocks at most this large are multiplied by the ordinary kernel, where
+/// Strassen's extra additions cost more than the multiplication they save.
+const CUTOFF: usize = 128;
+
+fn add<T: MatrixElement>(a: &[T], b: &[T]) -> Vec<T> {
+    a.iter().zip(b.iter()).map(|(x, y)| x.clone() + y.clone()).collect()
+}
+
+fn sub<T: MatrixElement>(a: &[T], b: &[T]) -> Vec<T> {
+    a.iter().zip(b.iter()).map(|(x, y)| x.clone() - y.clone()).collect()
+}
+
+/// The four `n/2 x n/2` quadrants of a row-major `n x n` matrix, in the order
+/// top left, top right, bottom left, bottom right.
+fn quadrants<T: Clone>(data: &[T], n: usize) -> [Vec<T>; 4] {
+    let half = n / 2;
+    let quadrant = |row_offset: usize, col_offset: usize| {
+        let mut block = Vec::with_capacity(half * half);
+        for i in row_offset..row_offset + half {
+            block.extend_from_slice(&data[i * n + col_offset..i * n + col_offset + half]);
+        }
+        block
+    };
+    [quadrant(0, 0), quadrant(0, half), quadrant(half, 0), quadrant(half, half)]
+}
+
+/// The product of two row-major `n x n` matrices.
+fn strassen<T: MatrixElement>(a: &[T], b: &[T], n: usize) -> Vec<T> {
+    if n <= CUTOFF || n % 2 == 1 {
+        let square = |data: &[T]| Matrix { rows: n, cols: n, data: data.to_vec() };
+        return blocked_product(&square(a), &square(b));
+    }
+
+    let half = n / 2;
+    let [a11, a12, a21, a22] = quadrants(a, n);
+    let [b11, b12, b21, b22] = quadrants(b, n);
+
+    let m1 = strassen(&add(&a11, &a22), &add(&b11, &b22), half);
+    let m2 = strassen(&add(&a21, &a22), &b11, half);
+    let m3 = strassen(&a11, &sub(&b12, &b22), half);
+    let m4 = strassen(&a22, &sub(&b21, &b11), half);
+    let m5 = strassen(&add(&a11, &a12), &b22, half);
+    let m6 = strassen(&sub(&a21, &a11), &add(&b11, &b12), half);
+    let m7 = strassen(&sub(&a12, &a22), &add(&b21, &b22), half);
+
+    let c11 = add(&sub(&add(&m1, &m4), &m5), &m7);
+    let c12 = add(&m3, &m5);
+    let c21 = add(&m2, &m4);
+    let c22 = add(&add(&sub(&m1, &m2), &m3), &m6);
+
+    let mut c = Vec::with_capacity(n * n);
+    for i in 0..half {
+        c.extend_from_slice(&c11[i * half..(i + 1) * half]);
+        c.extend_from_slice(&c12[i * half..(i + 1) * half]);
+    }
+    for i in 0..half {
+        c.extend_from_slice(&c21[i * half..(i + 1) * half]);
+        c.extend_from_slice(&c22[i * half..(i + 1) * half]);
+    }
+    c
+}
+
+/// The smallest size of the form `m * 2^k` with `m <= CUTOFF` that is at
+/// least `n`, so the recursion halves evenly down to the cutoff with far less
+/// padding than rounding up to a power of two.
+fn padded_size(n: usize) -> usize {
+    let mut halvings = 0;
+    while (n >> halvings) > CUTOFF {
+        halvings += 1;
+    }
+    let base = (n + (1 << halvings) - 1) >> halvings;
+    base << halvings
+}
+
+impl<T: MatrixElement> Matrix<T> {
+    /// The product `self * rhs` by Strassen's algorithm, or an error if the inner
+    /// dimensions differ.
+    ///
+    /// Each level of recursion trades one of eight block multiplications for a
+    /// handful of additions, for `O(n^2.81)` work instead of `O(n³)`. The operands
+    /// are zero-padded to a common square size that halves evenly, and blocks of
+    /// up to 128 rows use the ordinary kernel, so this only pays off for large,
+    /// roughly square matrices. With exact element types the result equals
+    /// `self * rhs`; with floats it carries somewhat more rounding error.
+    pub fn mul_strassen(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let shape = self.shape().matmul_with(rhs.shape())?;
+        let largest = self.rows.max(self.cols).max(rhs.cols);
+        if largest <= CUTOFF {
+            return self.checked_mul(rhs);
+        }
+
+        let n = padded_size(largest);
+        let pad = |matrix: &Matrix<T>| {
+            let mut data = vec![T::zero(); n * n];
+            for i in 0..matrix.rows {
+                data[i * n..i * n + matrix.cols].clone_from_slice(&matrix.data[i * matrix.cols..(i + 1) * matrix.cols]);
+            }
+            data
+        };
+        let product = strassen(&pad(self), &pad(rhs), n);
+
+        let mut data = Vec::with_capacity(shape.len());
+        for i in 0..shape.rows {
+            data.extend_from_slice(&product[i * n..i * n + shape.cols]);
+        }
+        Ok(Matrix {
+            rows: shape.rows,
+            cols: shape.cols,
+            data
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn integer_matrix(rows: usize, cols: usize, seed: i64) -> Matrix<i64> {
+        Matrix {
+            rows,
+            cols,
+            data: (0..(rows * cols) as i64).map(|val| (val * seed + 7) % 19 - 9).collect()
+        }
+    }
+
+    #[test]
+    fn strassen_matches_ordinary_product() {
+        // 300 = 75 * 4 needs no padding and recurses twice before reaching the cutoff.
+        let a = integer_matrix(300, 300, 5);
+        let b = integer_matrix(300, 300, 11);
+        assert_eq!(a.mul_strassen(&b).unwrap(), &a * &b);
+
+        let tall = integer_matrix(260, 140, 3);
+        let wide = integer_matrix(140, 200, 13);
+        let product = tall.mul_strassen(&wide).unwrap();
+        assert_eq!(product.shape(), (260, 200).into());
+        assert_eq!(product, &tall * &wide);
+    }
+
+    #[test]
+    fn padding_sizes() {
+        assert_eq!(padded_size(128), 128);
+        assert_eq!(padded_size(129), 130);
+        assert_eq!(padded_size(2000), 2000);
+        assert_eq!(padded_size(2001), 2016);
+        assert_eq!(padded_size(1024), 1024);
+    }
+
+    #[test]
+    fn strassen_small_and_mismatched() {
+        let a = integer_matrix(3, 4, 2);
+        let b = integer_matrix(4, 2, 5);
+        assert_eq!(a.mul_strassen(&b).unwrap(), &a * &b);
+        assert_eq!(a.mul_strassen(&a), Err(MatrixError::DimensionMismatch { left: a.shape(), right: a.shape() }));
+    }
+}
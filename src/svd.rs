@@ -0,0 +1,326 @@
+use std::fmt::Debug;
+
+use num::Float;
+use rand::Rng;
+
+use crate::Matrix;
+use crate::eigen::jacobi_options;
+use crate::error::MatrixError;
+use crate::progress::{Solution, SolverOptions};
+use crate::random::Normal;
+use crate::sort::nan_first_cmp;
+
+/// The sweep limit of [`Matrix::svd`].
+const MAX_SWEEPS: usize = 60;
+
+/// A (possibly truncated) singular value decomposition `a ≈ u * diag(singular_values) * vᵀ`.
+///
+/// The columns of `u` and `v` are orthonormal and the singular values are in
+/// decreasing order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svd<T> {
+    pub u: Matrix<T>,
+    pub singular_values: Vec<T>,
+    pub v: Matrix<T>
+}
+
+impl<T: Float + Debug> Svd<T> {
+    /// Multiplies the factors back together.
+    pub fn reconstruct(&self) -> Matrix<T> {
+        let mut scaled = self.u.clone();
+        for i in 0..scaled.rows {
+            for (j, sigma) in self.singular_values.iter().enumerate() {
+                let val = *scaled.get(i, j) * *sigma;
+                scaled.set(i, j, val);
+            }
+        }
+        scaled * self.v.transpose()
+    }
+}
+
+/// One-sided Jacobi SVD of a matrix with at least as many rows as columns.
+///
+/// Rotates pairs of columns until they are mutually orthogonal; the column
+/// norms are then the singular values. Accurate to working precision even for
+/// tiny singular values.
+///
+/// One sweep over the column pairs is one iteration, and its residual is the
+/// largest cosine of the angle between two columns seen during the sweep.
+pub(crate) fn jacobi_svd<T: Float>(matrix: &Matrix<T>, options: &mut SolverOptions) -> Result<Solution<Svd<T>>, MatrixError> {
+    let (m, n) = (matrix.rows, matrix.cols);
+    let mut work = matrix.clone();
+    let mut v = Matrix::identity(n);
+
+    let rotate = |target: &mut Matrix<T>, p: usize, q: usize, c: T, s: T| {
+        for i in 0..target.rows {
+            let (x, y) = (*target.get(i, p), *target.get(i, q));
+            target.set(i, p, c * x - s * y);
+            target.set(i, q, s * x + c * y);
+        }
+    };
+
+    let tolerance = T::from(options.tolerance).unwrap();
+    let mut residual = f64::INFINITY;
+    let mut sweeps = 0;
+    while residual > options.tolerance && sweeps < options.max_iterations {
+        let mut largest = T::zero();
+        for p in 0..n {
+            for q in p + 1..n {
+                let (mut alpha, mut beta, mut gamma) = (T::zero(), T::zero(), T::zero());
+                for i in 0..m {
+                    let (x, y) = (*work.get(i, p), *work.get(i, q));
+                    alpha = alpha + x * x;
+                    beta = beta + y * y;
+                    gamma = gamma + x * y;
+                }
+                if gamma.is_zero() {
+                    continue;
+                }
+                let cosine = gamma.abs() / (alpha * beta).sqrt();
+                largest = largest.max(cosine);
+                if cosine <= tolerance {
+                    continue;
+                }
+
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let t = zeta.signum() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                let c = T::one() / (T::one() + t * t).sqrt();
+                rotate(&mut work, p, q, c, c * t);
+                rotate(&mut v, p, q, c, c * t);
+            }
+        }
+
+        residual = largest.to_f64().unwrap();
+        if options.report(sweeps, residual).is_break() {
+            return Err(MatrixError::Cancelled { iterations: sweeps + 1 });
+        }
+        sweeps += 1;
+    }
+
+    let norms: Vec<T> = (0..n)
+        .map(|j| (0..m).fold(T::zero(), |total, i| total + *work.get(i, j) * *work.get(i, j)).sqrt())
+        .collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    // Decreasing, with any NaN norms last.
+    order.sort_by(|&a, &b| nan_first_cmp(&norms[b], &norms[a]));
+
+    let mut u = Matrix::new(m, n, T::zero());
+    let mut sorted_v = Matrix::new(n, n, T::zero());
+    for (new, &old) in order.iter().enumerate() {
+        for i in 0..m {
+            let val = if norms[old] == T::zero() { T::zero() } else { *work.get(i, old) / norms[old] };
+            u.set(i, new, val);
+        }
+        for i in 0..n {
+            sorted_v.set(i, new, *v.get(i, old));
+        }
+    }
+
+    Ok(Solution {
+        value: Svd {
+            u,
+            singular_values: order.iter().map(|&j| norms[j]).collect(),
+            v: sorted_v
+        },
+        converged: residual <= options.tolerance,
+        iterations: sweeps,
+        residual
+    })
+}
+
+impl<T: Float + Debug> Matrix<T> {
+    /// The thin singular value decomposition, with `min(rows, cols)` singular values.
+    ///
+    /// One-sided Jacobi converges for every matrix in practice, so this returns
+    /// the decomposition directly; use [`Matrix::svd_with`] to check convergence
+    /// or to trade accuracy for speed.
+    pub fn svd(&self) -> Svd<T> {
+        self.svd_with(&mut jacobi_options::<T>(MAX_SWEEPS))
+            .expect("an SVD without a progress callback cannot be cancelled")
+            .value
+    }
+
+    /// [`Matrix::svd`] with a custom tolerance, sweep limit and progress reporting.
+    ///
+    /// Each Jacobi sweep is one iteration, and the residual is the largest
+    /// cosine between two columns being orthogonalized. Running out of sweeps is
+    /// not an error: the returned [`Solution`] says whether the tolerance was met
+    /// and carries the best effort either way.
+    pub fn svd_with(&self, options: &mut SolverOptions) -> Result<Solution<Svd<T>>, MatrixError> {
+        if self.rows >= self.cols {
+            return jacobi_svd(self, options);
+        }
+        let solution = jacobi_svd(&self.transpose(), options)?;
+        let Svd { u, singular_values, v } = solution.value;
+        Ok(Solution {
+            value: Svd {
+                u: v,
+                singular_values,
+                v: u
+            },
+            ..solution
+        })
+    }
+
+    /// An approximate rank-`rank` SVD by the randomized range finder of Halko,
+    /// Martinsson and Tropp.
+    ///
+    /// The column space is sampled with `rank + oversampling` Gaussian test
+    /// vectors, refined by `n_iter` power iterations (each one sharpens the
+    /// separation between kept and discarded singular values), and the small
+    /// projected matrix is decomposed exactly. The cost is dominated by a few
+    /// products with `self`, far cheaper than a full SVD when `rank` is small.
+    pub fn randomized_svd(&self, rank: usize, oversampling: usize, n_iter: usize, rng: &mut impl Rng) -> Svd<T> {
+        let samples = (rank + oversampling).min(self.rows).min(self.cols);
+        let rank = rank.min(samples);
+        let transpose = self.transpose();
+
+        let omega = Matrix::random(self.cols, samples, rng, Normal::standard());
+        let mut q = (self * &omega).qr().q;
+        for _ in 0..n_iter {
+            let z = (&transpose * &q).qr().q;
+            q = (self * &z).qr().q;
+        }
+
+        // b = qᵀ * self is small; decomposing bᵀ = u_b * s * v_bᵀ gives b = v_b * s * u_bᵀ.
+        let b_transpose = transpose * q.clone();
+        let small = b_transpose.svd();
+
+        let keep = |matrix: &Matrix<T>| {
+            let mut data = vec![];
+            for i in 0..matrix.rows {
+                data.extend_from_slice(&matrix.data[i * matrix.cols..i * matrix.cols + rank]);
+            }
+            Matrix {
+                rows: matrix.rows,
+                cols: rank,
+                data
+            }
+        };
+
+        Svd {
+            u: keep(&(q * small.v)),
+            singular_values: small.singular_values[..rank].to_vec(),
+            v: keep(&small.u)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::*;
+
+    fn max_abs_difference(a: &Matrix<f64>, b: &Matrix<f64>) -> f64 {
+        a.data.iter().zip(b.data.iter()).fold(0.0, |max, (x, y)| max.max((x - y).abs()))
+    }
+
+    fn assert_orthonormal_columns(matrix: &Matrix<f64>) {
+        let gram = matrix.transpose() * matrix.clone();
+        for i in 0..gram.rows {
+            for j in 0..gram.cols {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((gram.get(i, j) - expected).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn jacobi_svd_of_small_matrix() {
+        let matrix = Matrix::from(vec![
+            vec![3.0, 2.0, 2.0],
+            vec![2.0, 3.0, -2.0]
+        ]);
+
+        let svd = jacobi_svd(&matrix.transpose(), &mut jacobi_options::<f64>(MAX_SWEEPS)).unwrap().value;
+
+        assert!((svd.singular_values[0] - 5.0).abs() < 1e-12);
+        assert!((svd.singular_values[1] - 3.0).abs() < 1e-12);
+        assert_orthonormal_columns(&svd.u);
+        assert_orthonormal_columns(&svd.v);
+        assert!(max_abs_difference(&svd.reconstruct(), &matrix.transpose()) < 1e-12);
+    }
+
+    #[test]
+    fn svd_of_wide_matrix() {
+        let matrix = Matrix::from(vec![
+            vec![3.0, 2.0, 2.0],
+            vec![2.0, 3.0, -2.0]
+        ]);
+
+        let svd = matrix.svd();
+
+        assert_eq!((svd.u.rows, svd.u.cols, svd.v.rows, svd.v.cols), (2, 2, 3, 2));
+        assert!((svd.singular_values[0] - 5.0).abs() < 1e-12);
+        assert!(max_abs_difference(&svd.reconstruct(), &matrix) < 1e-12);
+    }
+
+    #[test]
+    fn svd_with_loose_tolerance_stops_early() {
+        let matrix = Matrix::from((0..8).map(|i| (0..5).map(|j| ((i * 5 + j) as f64).sin()).collect()).collect());
+
+        let precise = matrix.svd_with(&mut SolverOptions::default().tolerance(1e-15)).unwrap();
+        let loose = matrix.svd_with(&mut SolverOptions::default().tolerance(1e-3)).unwrap();
+        assert!(precise.converged && loose.converged);
+        assert!(loose.iterations < precise.iterations);
+        assert!(loose.residual <= 1e-3 && loose.residual > precise.residual);
+        assert!(max_abs_difference(&precise.value.reconstruct(), &matrix) < 1e-12);
+
+        let capped = matrix.transpose().svd_with(&mut SolverOptions::default().max_iterations(1)).unwrap();
+        assert!(!capped.converged);
+        assert_eq!(capped.iterations, 1);
+        assert_eq!(capped.value.u.shape(), (5, 5).into());
+    }
+
+    #[test]
+    fn randomized_svd_recovers_low_rank_matrix() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let left = Matrix { rows: 40, cols: 3, data: (0..120).map(|_| rng.gen_range(-1.0..1.0)).collect() };
+        let right = Matrix { rows: 3, cols: 25, data: (0..75).map(|_| rng.gen_range(-1.0..1.0)).collect() };
+        let matrix = left * right;
+
+        let svd = matrix.randomized_svd(3, 5, 1, &mut rng);
+
+        assert_eq!((svd.u.rows, svd.u.cols, svd.v.rows, svd.v.cols), (40, 3, 25, 3));
+        assert_orthonormal_columns(&svd.u);
+        assert_orthonormal_columns(&svd.v);
+        assert!(svd.singular_values.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert!(max_abs_difference(&svd.reconstruct(), &matrix) < 1e-10);
+    }
+
+    #[test]
+    fn randomized_svd_finds_leading_singular_values() {
+        // A matrix with singular values 10, 5, 1, 0.5, 0.1, ... and mixed rows.
+        let mut matrix = Matrix::new(30, 20, 0.0);
+        let values = [10.0, 5.0, 1.0, 0.5, 0.1];
+        for (k, sigma) in values.iter().enumerate() {
+            matrix.set(k, k, *sigma);
+        }
+        let mixer = Matrix {
+            rows: 30,
+            cols: 30,
+            data: (0..900).map(|i| ((i * 37 % 101) as f64).sin()).collect()
+        }.qr().q;
+        let matrix = mixer * matrix;
+
+        let svd = matrix.randomized_svd(2, 4, 3, &mut StdRng::seed_from_u64(1));
+
+        assert!((svd.singular_values[0] - 10.0).abs() < 1e-8);
+        assert!((svd.singular_values[1] - 5.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn svd_with_nan_does_not_panic() {
+        let matrix = Matrix::from(vec![
+            vec![f64::NAN, 0.0, 0.0],
+            vec![0.0, 3.0, 0.0],
+            vec![0.0, 0.0, 0.0]
+        ]);
+
+        let values = matrix.svd().singular_values;
+        assert_eq!(values.len(), 3);
+        assert!(values.last().unwrap().is_nan());
+    }
+}
@@ -0,0 +1,175 @@
+use std::fmt::Debug;
+
+use num::Float;
+
+use crate::Matrix;
+use crate::error::MatrixError;
+use crate::shape::Shape;
+
+/// Copies the `rows x cols` block of `matrix` starting at `(row, col)`.
+fn block<T: Float>(matrix: &Matrix<T>, (row, rows): (usize, usize), (col, cols): (usize, usize)) -> Matrix<T> {
+    let mut output = Matrix::new(rows, cols, T::zero());
+    for i in 0..rows {
+        for j in 0..cols {
+            output.set(i, j, *matrix.get(row + i, col + j));
+        }
+    }
+    output
+}
+
+/// Solves the small Sylvester equation `s * z + z * r = g` for blocks of size
+/// at most 2 through its Kronecker form.
+fn solve_small<T: Float>(s: &Matrix<T>, r: &Matrix<T>, g: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+    let (p, q) = (s.rows, r.rows);
+    // vec(z) stacks the columns of z, so z[(a, b)] is unknown a + b * p.
+    let mut system = Matrix::new(p * q, p * q, T::zero());
+    let mut rhs = Matrix::new(p * q, 1, T::zero());
+    for b in 0..q {
+        for a in 0..p {
+            let row = a + b * p;
+            rhs.set(row, 0, *g.get(a, b));
+            for c in 0..p {
+                let val = *system.get(row, c + b * p) + *s.get(a, c);
+                system.set(row, c + b * p, val);
+            }
+            for d in 0..q {
+                let val = *system.get(row, a + d * p) + *r.get(d, b);
+                system.set(row, a + d * p, val);
+            }
+        }
+    }
+    let solution = system.solve(&rhs)?;
+
+    let mut z = Matrix::new(p, q, T::zero());
+    for b in 0..q {
+        for a in 0..p {
+            z.set(a, b, *solution.get(a + b * p, 0));
+        }
+    }
+    Ok(z)
+}
+
+impl<T: Float + Debug> Matrix<T> {
+    /// Solves the Sylvester equation `a * x + x * b = c` by the Bartels-Stewart algorithm.
+    ///
+    /// With the real Schur forms `a = u * s * uᵀ` and `b = v * r * vᵀ` the
+    /// equation becomes `s * y + y * r = uᵀ * c * v`, which is solved one
+    /// diagonal block pair at a time by substitution, and `x = u * y * vᵀ`. A
+    /// unique solution exists exactly when `a` and `-b` share no eigenvalue;
+    /// otherwise [`MatrixError::Singular`] is returned.
+    pub fn solve_sylvester(a: &Matrix<T>, b: &Matrix<T>, c: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let a_schur = a.real_schur()?;
+        let b_schur = b.real_schur()?;
+        if c.rows != a.rows || c.cols != b.rows {
+            return Err(MatrixError::DimensionMismatch {
+                left: c.shape(),
+                right: Shape::new(a.rows, b.rows)
+            });
+        }
+
+        let (s, r) = (&a_schur.t, &b_schur.t);
+        let f = &(a_schur.q.transpose() * c) * &b_schur.q;
+        let mut y = Matrix::new(c.rows, c.cols, T::zero());
+
+        // s is upper quasi-triangular, so the rows of y are found from the bottom
+        // up; r is too, so the columns are found from the left.
+        for &(col, cols) in b_schur.blocks().iter() {
+            for &(row, rows) in a_schur.blocks().iter().rev() {
+                let mut g = block(&f, (row, rows), (col, cols));
+                for i in 0..rows {
+                    for j in 0..cols {
+                        let from_s = (row + rows..s.cols).fold(T::zero(), |total, k| total + *s.get(row + i, k) * *y.get(k, col + j));
+                        let from_r = (0..col).fold(T::zero(), |total, k| total + *y.get(row + i, k) * *r.get(k, col + j));
+                        let val = *g.get(i, j) - from_s - from_r;
+                        g.set(i, j, val);
+                    }
+                }
+
+                let z = solve_small(&block(s, (row, rows), (row, rows)), &block(r, (col, cols), (col, cols)), &g)?;
+                for i in 0..rows {
+                    for j in 0..cols {
+                        y.set(row + i, col + j, *z.get(i, j));
+                    }
+                }
+            }
+        }
+
+        Ok(&(&a_schur.q * &y) * &b_schur.q.transpose())
+    }
+
+    /// Solves the continuous Lyapunov equation `a * x + x * aᵀ = q`.
+    ///
+    /// This is the Sylvester equation with `b = aᵀ`. For a symmetric `q` the
+    /// solution is symmetric, and when `a` is stable (every eigenvalue has a
+    /// negative real part) and `q` is negative definite it is positive definite.
+    pub fn solve_lyapunov(a: &Matrix<T>, q: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        Matrix::solve_sylvester(a, &a.transpose(), q)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>) {
+        assert!(a.iter().zip(b.iter()).all(|(x, y)| (x - y).abs() < 1e-9), "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn sylvester_equation() {
+        let a = Matrix::from(vec![
+            vec![1.0, -2.0, 0.0],
+            vec![2.0, 1.0, 1.0],
+            vec![0.5, 0.0, 3.0]
+        ]);
+        let b = Matrix::from(vec![
+            vec![4.0, 1.0],
+            vec![0.0, 5.0]
+        ]);
+        let x = Matrix::from(vec![
+            vec![1.0, 2.0],
+            vec![-1.0, 0.0],
+            vec![3.0, 1.0]
+        ]);
+        let c = &(&a * &x) + &(&x * &b);
+
+        let solution = Matrix::solve_sylvester(&a, &b, &c).unwrap();
+
+        assert_close(&solution, &x);
+    }
+
+    #[test]
+    fn sylvester_with_shared_eigenvalue_is_singular() {
+        let a = Matrix::from(vec![vec![1.0, 0.0], vec![0.0, 2.0]]);
+        let b = Matrix::from(vec![vec![-2.0]]);
+        let c = Matrix::new(2, 1, 1.0);
+        assert_eq!(Matrix::solve_sylvester(&a, &b, &c), Err(MatrixError::Singular));
+        assert!(matches!(
+            Matrix::solve_sylvester(&a, &b, &Matrix::new(1, 1, 1.0)),
+            Err(MatrixError::DimensionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn lyapunov_equation_for_stable_system() {
+        // A damped oscillator; the solution is the controllability Gramian.
+        let a = Matrix::from(vec![
+            vec![0.0, 1.0],
+            vec![-2.0, -3.0]
+        ]);
+        let q = Matrix::from(vec![
+            vec![0.0, 0.0],
+            vec![0.0, -1.0]
+        ]);
+
+        let x = Matrix::solve_lyapunov(&a, &q).unwrap();
+
+        assert_close(&(&(&a * &x) + &(&x * &a.transpose())), &q);
+        assert_close(&x, &x.transpose());
+        assert!(x.cholesky().is_ok());
+        assert_close(&x, &Matrix::from(vec![
+            vec![1.0 / 12.0, 0.0],
+            vec![0.0, 1.0 / 6.0]
+        ]));
+    }
+}
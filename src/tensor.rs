@@ -0,0 +1,245 @@
+use crate::Matrix;
+use crate::element::MatrixElement;
+use crate::error::MatrixError;
+use crate::shape::Shape;
+use crate::traits::MatrixOps;
+
+/// A dimension of a [`Tensor3`].
+///
+/// This is separate from [`Axis`](crate::Axis) because a tensor has a third,
+/// depth dimension that a matrix lacks; reusing `Axis` would leave no way to
+/// reduce across the stacked matrices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TensorAxis {
+    /// Across the stacked matrices.
+    Depth,
+    Rows,
+    Cols
+}
+
+/// A stack of `depth` matrices, each `rows x cols`, stored contiguously.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tensor3<T> {
+    depth: usize,
+    rows: usize,
+    cols: usize,
+    data: Vec<T>
+}
+
+/// A borrowed matrix from a [`Tensor3`], returned by [`Tensor3::slice_matrix`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatrixSlice<'a, T> {
+    rows: usize,
+    cols: usize,
+    data: &'a [T]
+}
+
+impl<T> MatrixSlice<'_, T> {
+    pub fn get(&self, row: usize, col: usize) -> &T {
+        &self.data[row * self.cols + col]
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T>
+        where T: Clone
+    {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.to_vec()
+        }
+    }
+}
+
+impl<T: MatrixElement> MatrixOps<T> for MatrixSlice<'_, T> {
+    fn shape(&self) -> Shape {
+        Shape::new(self.rows, self.cols)
+    }
+
+    fn element(&self, row: usize, col: usize) -> T {
+        self.get(row, col).clone()
+    }
+
+    fn row_entries(&self, row: usize) -> Box<dyn Iterator<Item = (usize, T)> + '_> {
+        Box::new(self.data[row * self.cols..(row + 1) * self.cols].iter().cloned().enumerate())
+    }
+}
+
+impl<T> Tensor3<T> {
+    pub fn new(depth: usize, rows: usize, cols: usize, value: T) -> Tensor3<T>
+        where T: Clone
+    {
+        Tensor3 {
+            depth,
+            rows,
+            cols,
+            data: vec![value; depth * rows * cols]
+        }
+    }
+
+    /// Stacks equally shaped matrices, the first becoming slice 0.
+    pub fn from_matrices(matrices: Vec<Matrix<T>>) -> Result<Tensor3<T>, MatrixError> {
+        let (rows, cols) = matrices.first().map_or((0, 0), |first| (first.rows, first.cols));
+        let depth = matrices.len();
+
+        let mut data = Vec::with_capacity(depth * rows * cols);
+        for matrix in matrices {
+            if (matrix.rows, matrix.cols) != (rows, cols) {
+                return Err(MatrixError::DimensionMismatch { left: Shape::new(rows, cols), right: matrix.shape() });
+            }
+            data.extend(matrix.data);
+        }
+        Ok(Tensor3 {
+            depth,
+            rows,
+            cols,
+            data
+        })
+    }
+
+    pub fn shape(&self) -> (usize, usize, usize) {
+        (self.depth, self.rows, self.cols)
+    }
+
+    pub fn get(&self, k: usize, row: usize, col: usize) -> &T {
+        &self.data[(k * self.rows + row) * self.cols + col]
+    }
+
+    pub fn set(&mut self, k: usize, row: usize, col: usize, value: T) {
+        self.data[(k * self.rows + row) * self.cols + col] = value;
+    }
+
+    /// Borrows matrix `k` of the stack without copying.
+    pub fn slice_matrix(&self, k: usize) -> MatrixSlice<'_, T> {
+        assert!(k < self.depth, "slice {} out of bounds for depth {}", k, self.depth);
+        let len = self.rows * self.cols;
+
+        MatrixSlice {
+            rows: self.rows,
+            cols: self.cols,
+            data: &self.data[k * len..(k + 1) * len]
+        }
+    }
+}
+
+impl<T: MatrixElement> Tensor3<T> {
+    /// Multiplies matching slices, so slice `k` of the result is `self[k] * other[k]`.
+    ///
+    /// Returns [`MatrixError::DepthMismatch`] if the tensors hold different
+    /// numbers of slices, and [`MatrixError::DimensionMismatch`] between the
+    /// slice shapes if those can't be multiplied.
+    pub fn batch_matmul(&self, other: &Tensor3<T>) -> Result<Tensor3<T>, MatrixError> {
+        if self.depth != other.depth {
+            return Err(MatrixError::DepthMismatch { left: self.depth, right: other.depth });
+        }
+        if self.cols != other.rows {
+            return Err(MatrixError::DimensionMismatch {
+                left: Shape::new(self.rows, self.cols),
+                right: Shape::new(other.rows, other.cols)
+            });
+        }
+
+        let mut output = Tensor3::new(self.depth, self.rows, other.cols, T::zero());
+        for k in 0..self.depth {
+            for i in 0..self.rows {
+                for j in 0..other.cols {
+                    let mut total = T::zero();
+                    for l in 0..self.cols {
+                        total = total + self.get(k, i, l).clone() * other.get(k, l, j).clone();
+                    }
+                    output.set(k, i, j, total);
+                }
+            }
+        }
+        Ok(output)
+    }
+
+    /// Sums along `axis`, leaving that dimension with length one.
+    pub fn sum_axis(&self, axis: TensorAxis) -> Tensor3<T> {
+        let (depth, rows, cols) = match axis {
+            TensorAxis::Depth => (1, self.rows, self.cols),
+            TensorAxis::Rows => (self.depth, 1, self.cols),
+            TensorAxis::Cols => (self.depth, self.rows, 1)
+        };
+
+        let mut output = Tensor3::new(depth, rows, cols, T::zero());
+        for k in 0..self.depth {
+            for i in 0..self.rows {
+                for j in 0..self.cols {
+                    let (k_out, i_out, j_out) = (k.min(depth - 1), i.min(rows - 1), j.min(cols - 1));
+                    let total = output.get(k_out, i_out, j_out).clone() + self.get(k, i, j).clone();
+                    output.set(k_out, i_out, j_out, total);
+                }
+            }
+        }
+        output
+    }
+
+    /// The element-wise sum of all the stacked matrices.
+    pub fn sum_depth(&self) -> Matrix<T> {
+        let summed = self.sum_axis(TensorAxis::Depth);
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: summed.data
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Tensor3<i32> {
+        Tensor3::from_matrices(vec![
+            Matrix::from(vec![vec![1, 2], vec![3, 4]]),
+            Matrix::from(vec![vec![5, 6], vec![7, 8]]),
+            Matrix::from(vec![vec![0, 1], vec![1, 0]])
+        ]).unwrap()
+    }
+
+    #[test]
+    fn tensor_slices() {
+        let tensor = example();
+
+        assert_eq!(tensor.shape(), (3, 2, 2));
+        assert_eq!(*tensor.get(1, 1, 0), 7);
+        let slice = tensor.slice_matrix(1);
+        assert_eq!(*slice.get(0, 1), 6);
+        assert_eq!(slice.matvec(&[1, 1]), vec![11, 15]);
+        assert_eq!(slice.to_matrix(), Matrix::from(vec![vec![5, 6], vec![7, 8]]));
+
+        let ragged = Tensor3::from_matrices(vec![Matrix::new(2, 2, 0), Matrix::new(2, 3, 0)]);
+        assert_eq!(ragged, Err(MatrixError::DimensionMismatch { left: Shape::new(2, 2), right: Shape::new(2, 3) }));
+    }
+
+    #[test]
+    fn batched_matmul() {
+        let tensor = example();
+        let product = tensor.batch_matmul(&tensor).unwrap();
+
+        for k in 0..3 {
+            let matrix = tensor.slice_matrix(k).to_matrix();
+            assert_eq!(product.slice_matrix(k).to_matrix(), matrix.clone() * matrix);
+        }
+        assert_eq!(tensor.batch_matmul(&Tensor3::new(2, 2, 2, 1)), Err(MatrixError::DepthMismatch { left: 3, right: 2 }));
+        assert_eq!(
+            tensor.batch_matmul(&Tensor3::new(3, 3, 2, 1)),
+            Err(MatrixError::DimensionMismatch { left: Shape::new(2, 2), right: Shape::new(3, 2) })
+        );
+    }
+
+    #[test]
+    fn tensor_reductions() {
+        let tensor = example();
+
+        assert_eq!(tensor.sum_depth(), Matrix::from(vec![vec![6, 9], vec![11, 12]]));
+
+        let row_sums = tensor.sum_axis(TensorAxis::Cols);
+        assert_eq!(row_sums.shape(), (3, 2, 1));
+        assert_eq!(row_sums.data, vec![3, 7, 11, 15, 1, 1]);
+
+        let column_sums = tensor.sum_axis(TensorAxis::Rows);
+        assert_eq!(column_sums.shape(), (3, 1, 2));
+        assert_eq!(column_sums.data, vec![4, 6, 12, 14, 1, 1]);
+    }
+}
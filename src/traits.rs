@@ -0,0 +1,75 @@
+use crate::Matrix;
+use crate::element::MatrixElement;
+use crate::shape::Shape;
+
+/// Read-only operations shared by every matrix representation in the crate.
+///
+/// Algorithms written against this trait work unchanged on dense matrices and on
+/// any other storage that implements it. Only `shape`, `element`, and `row_entries`
+/// are required; `matvec` has a default built on row iteration that
+/// implementations may override with something faster.
+pub trait MatrixOps<T: MatrixElement> {
+    fn shape(&self) -> Shape;
+
+    /// The element at `(row, col)`, which is zero for entries a representation doesn't store.
+    fn element(&self, row: usize, col: usize) -> T;
+
+    /// The `(column, value)` pairs stored in `row`, in increasing column order.
+    ///
+    /// Dense representations yield every column; sparse ones may skip zeros.
+    fn row_entries(&self, row: usize) -> Box<dyn Iterator<Item = (usize, T)> + '_>;
+
+    fn nrows(&self) -> usize {
+        self.shape().rows
+    }
+
+    fn ncols(&self) -> usize {
+        self.shape().cols
+    }
+
+    /// The matrix-vector product `A * x`.
+    fn matvec(&self, x: &[T]) -> Vec<T> {
+        assert_eq!(x.len(), self.ncols());
+
+        (0..self.nrows())
+            .map(|i| self.row_entries(i).fold(T::zero(), |total, (j, val)| total + val * x[j].clone()))
+            .collect()
+    }
+}
+
+impl<T: MatrixElement> MatrixOps<T> for Matrix<T> {
+    fn shape(&self) -> Shape {
+        Shape::new(self.rows, self.cols)
+    }
+
+    fn element(&self, row: usize, col: usize) -> T {
+        self.get(row, col).clone()
+    }
+
+    fn row_entries(&self, row: usize) -> Box<dyn Iterator<Item = (usize, T)> + '_> {
+        Box::new(self.data[row * self.cols..(row + 1) * self.cols].iter().cloned().enumerate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row_sums<T: MatrixElement, M: MatrixOps<T>>(matrix: &M) -> Vec<T> {
+        matrix.matvec(&vec![T::one(); matrix.ncols()])
+    }
+
+    #[test]
+    fn matrix_ops_for_dense() {
+        let matrix = Matrix::from(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6]
+        ]);
+
+        assert_eq!(MatrixOps::shape(&matrix), Shape::new(2, 3));
+        assert_eq!(matrix.element(1, 2), 6);
+        assert_eq!(matrix.row_entries(1).collect::<Vec<_>>(), vec![(0, 4), (1, 5), (2, 6)]);
+        assert_eq!(matrix.matvec(&[1, 0, -1]), vec![-2, -2]);
+        assert_eq!(row_sums(&matrix), vec![6, 15]);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::Matrix;
+use crate::element::MatrixElement;
+
+impl<T: MatrixElement> Matrix<T> {
+    /// In-place rank-one update `A += alpha * x * y^T`.
+    ///
+    /// `x` must have one entry per row and `y` one entry per column.
+    pub fn rank_one_update(&mut self, alpha: T, x: &[T], y: &[T]) {
+        assert_eq!(x.len(), self.rows);
+        assert_eq!(y.len(), self.cols);
+
+        for (i, x_val) in x.iter().enumerate() {
+            let scaled = alpha.clone() * x_val.clone();
+            for (j, y_val) in y.iter().enumerate() {
+                let val = self.get(i, j).clone();
+                self.set(i, j, val + scaled.clone() * y_val.clone());
+            }
+        }
+    }
+
+    /// In-place symmetric rank-k update `C += alpha * A * A^T` (BLAS `syrk`).
+    ///
+    /// `self` must be square with as many rows as `a`. Since `A * A^T` is
+    /// symmetric only its upper triangle is computed and mirrored.
+    pub fn rank_k_update(&mut self, alpha: T, a: &Matrix<T>) {
+        assert_eq!(self.rows, self.cols);
+        assert_eq!(self.rows, a.rows);
+
+        for i in 0..self.rows {
+            for j in i..self.cols {
+                let mut total = T::zero();
+                for k in 0..a.cols {
+                    total = total + a.get(i, k).clone() * a.get(j, k).clone();
+                }
+                let total = alpha.clone() * total;
+
+                let val = self.get(i, j).clone();
+                self.set(i, j, val + total.clone());
+                if i != j {
+                    let val = self.get(j, i).clone();
+                    self.set(j, i, val + total);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rank_one_update_outer_product() {
+        let mut matrix = Matrix::from(vec![
+            vec![1, 0, 0],
+            vec![0, 1, 0]
+        ]);
+
+        matrix.rank_one_update(2, &[1, 2], &[1, 0, 3]);
+
+        let expected_matrix = Matrix::from(vec![
+            vec![3, 0, 6],
+            vec![4, 1, 12]
+        ]);
+        assert_eq!(matrix, expected_matrix);
+    }
+
+    #[test]
+    fn rank_k_update_matches_product() {
+        let a = Matrix::from(vec![
+            vec![1, 2],
+            vec![3, 4],
+            vec![5, 6]
+        ]);
+        let mut matrix = Matrix::new(3, 3, 1);
+
+        matrix.rank_k_update(1, &a);
+
+        let expected_matrix = Matrix::from(vec![
+            vec![6, 12, 18],
+            vec![12, 26, 40],
+            vec![18, 40, 62]
+        ]);
+        assert_eq!(matrix, expected_matrix);
+    }
+}
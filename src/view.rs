@@ -0,0 +1,430 @@
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Range, Sub, SubAssign};
+
+use crate::Matrix;
+use crate::element::MatrixElement;
+use crate::error::MatrixError;
+use crate::shape::Shape;
+use crate::traits::MatrixOps;
+
+/// A borrowed rectangular block of a [`Matrix`], read in place.
+///
+/// Element `(i, j)` of the view is `data[i * stride + j]`, where `stride` is
+/// the column count of the matrix the view was taken from, so views of rows,
+/// columns and blocks all avoid copying. Views support the same arithmetic as
+/// matrices, producing owned results.
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, T> {
+    rows: usize,
+    cols: usize,
+    stride: usize,
+    data: &'a [T]
+}
+
+/// A mutably borrowed rectangular block of a [`Matrix`]; writes go straight to
+/// the matrix. See [`MatrixView`].
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    rows: usize,
+    cols: usize,
+    stride: usize,
+    data: &'a mut [T]
+}
+
+/// The extent of the storage a `rows x cols` view with the given stride
+/// spans, starting from its first element.
+fn span(rows: usize, cols: usize, stride: usize) -> usize {
+    if rows == 0 || cols == 0 {
+        0
+    } else {
+        (rows - 1) * stride + cols
+    }
+}
+
+/// Checks that `rows x cols` ranges lie within a matrix, returning the offset
+/// of the first element and the extent of the block.
+fn block(shape: Shape, rows: &Range<usize>, cols: &Range<usize>) -> Range<usize> {
+    assert!(
+        rows.start <= rows.end && rows.end <= shape.rows && cols.start <= cols.end && cols.end <= shape.cols,
+        "view {:?} x {:?} is out of bounds for a {} matrix", rows, cols, shape
+    );
+    let start = rows.start * shape.cols + cols.start;
+    start..start + span(rows.len(), cols.len(), shape.cols)
+}
+
+fn check_index(shape: Shape, row: usize, col: usize) {
+    if row >= shape.rows || col >= shape.cols {
+        panic!("{}", MatrixError::IndexOutOfBounds { row, col, rows: shape.rows, cols: shape.cols });
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Borrows the block of rows `rows` and columns `cols` without copying.
+    ///
+    /// Panics if either range reaches past the matrix.
+    pub fn view(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixView<'_, T> {
+        let range = block(self.shape(), &rows, &cols);
+        MatrixView {
+            rows: rows.len(),
+            cols: cols.len(),
+            stride: self.cols,
+            data: &self.data[range]
+        }
+    }
+
+    /// The mutable counterpart of [`Matrix::view`].
+    pub fn view_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatrixViewMut<'_, T> {
+        let range = block(self.shape(), &rows, &cols);
+        MatrixViewMut {
+            rows: rows.len(),
+            cols: cols.len(),
+            stride: self.cols,
+            data: &mut self.data[range]
+        }
+    }
+
+    /// The whole matrix as a view.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        self.view(0..self.rows, 0..self.cols)
+    }
+
+    /// Row `i` as a `1 x cols` view.
+    pub fn row_view(&self, i: usize) -> MatrixView<'_, T> {
+        self.view(i..i + 1, 0..self.cols)
+    }
+
+    /// Column `j` as a `rows x 1` view.
+    pub fn column_view(&self, j: usize) -> MatrixView<'_, T> {
+        self.view(0..self.rows, j..j + 1)
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    pub fn shape(&self) -> Shape {
+        Shape::new(self.rows, self.cols)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &'a T {
+        check_index(self.shape(), row, col);
+        &self.data[row * self.stride + col]
+    }
+
+    /// The block of rows `rows` and columns `cols` of this view, as a view of the same matrix.
+    pub fn view(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixView<'a, T> {
+        assert!(
+            rows.start <= rows.end && rows.end <= self.rows && cols.start <= cols.end && cols.end <= self.cols,
+            "view {:?} x {:?} is out of bounds for a {} view", rows, cols, self.shape()
+        );
+        let start = rows.start * self.stride + cols.start;
+        MatrixView {
+            rows: rows.len(),
+            cols: cols.len(),
+            stride: self.stride,
+            data: &self.data[start..start + span(rows.len(), cols.len(), self.stride)]
+        }
+    }
+
+    /// Row `i` of the view as a `1 x cols` view.
+    pub fn row(&self, i: usize) -> MatrixView<'a, T> {
+        self.view(i..i + 1, 0..self.cols)
+    }
+
+    /// Column `j` of the view as a `rows x 1` view.
+    pub fn column(&self, j: usize) -> MatrixView<'a, T> {
+        self.view(0..self.rows, j..j + 1)
+    }
+
+    /// The elements in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let (data, stride, cols) = (self.data, self.stride, self.cols);
+        (0..self.rows).flat_map(move |i| data[i * stride..i * stride + cols].iter())
+    }
+
+    /// Copies the viewed elements into a new matrix.
+    pub fn to_owned(&self) -> Matrix<T>
+        where T: Clone
+    {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.iter().cloned().collect()
+        }
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    pub fn shape(&self) -> Shape {
+        Shape::new(self.rows, self.cols)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &T {
+        check_index(self.shape(), row, col);
+        &self.data[row * self.stride + col]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        check_index(self.shape(), row, col);
+        self.data[row * self.stride + col] = value;
+    }
+
+    /// Reborrows the block as a read-only view.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            rows: self.rows,
+            cols: self.cols,
+            stride: self.stride,
+            data: self.data
+        }
+    }
+
+    /// The elements in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        let (stride, cols) = (self.stride, self.cols);
+        self.data.chunks_mut(stride.max(1)).take(self.rows).flat_map(move |row| row[..cols].iter_mut())
+    }
+
+    /// Overwrites the block with the elements of `source`, which must have the same shape.
+    pub fn copy_from(&mut self, source: MatrixView<'_, T>)
+        where T: Clone
+    {
+        if let Err(error) = self.shape().elementwise_with(source.shape()) {
+            panic!("{}", error);
+        }
+        for (val, other) in self.iter_mut().zip(source.iter()) {
+            *val = other.clone();
+        }
+    }
+
+    pub fn to_owned(&self) -> Matrix<T>
+        where T: Clone
+    {
+        self.as_view().to_owned()
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col)
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col)
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        check_index(self.shape(), row, col);
+        &mut self.data[row * self.stride + col]
+    }
+}
+
+impl<T: MatrixElement> MatrixOps<T> for MatrixView<'_, T> {
+    fn shape(&self) -> Shape {
+        Shape::new(self.rows, self.cols)
+    }
+
+    fn element(&self, row: usize, col: usize) -> T {
+        self.get(row, col).clone()
+    }
+
+    fn row_entries(&self, row: usize) -> Box<dyn Iterator<Item = (usize, T)> + '_> {
+        let start = row * self.stride;
+        Box::new(self.data[start..start + self.cols].iter().cloned().enumerate())
+    }
+}
+
+fn zip_views<T: MatrixElement>(lhs: MatrixView<'_, T>, rhs: MatrixView<'_, T>, f: impl Fn(T, T) -> T) -> Matrix<T> {
+    let shape = lhs.shape().elementwise_with(rhs.shape()).unwrap_or_else(|error| panic!("{}", error));
+    Matrix {
+        rows: shape.rows,
+        cols: shape.cols,
+        data: lhs.iter().zip(rhs.iter()).map(|(a, b)| f(a.clone(), b.clone())).collect()
+    }
+}
+
+fn multiply_views<T: MatrixElement>(lhs: MatrixView<'_, T>, rhs: MatrixView<'_, T>) -> Matrix<T> {
+    let shape = lhs.shape().matmul_with(rhs.shape()).unwrap_or_else(|error| panic!("{}", error));
+    let mut output = Matrix::new(shape.rows, shape.cols, T::zero());
+    for i in 0..shape.rows {
+        for k in 0..lhs.cols {
+            let a = lhs.get(i, k);
+            for j in 0..shape.cols {
+                let val = output.data[i * shape.cols + j].clone() + a.clone() * rhs.get(k, j).clone();
+                output.data[i * shape.cols + j] = val;
+            }
+        }
+    }
+    output
+}
+
+/// Implements a binary operator between views, and between a view and a
+/// borrowed matrix in either order, all producing an owned matrix.
+/// Mismatched shapes panic, as for the matrix operators.
+macro_rules! impl_view_op {
+    ($op:ident, $method:ident, $body:expr) => {
+        impl<T: MatrixElement> $op<MatrixView<'_, T>> for MatrixView<'_, T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: MatrixView<'_, T>) -> Matrix<T> {
+                $body(self, rhs)
+            }
+        }
+
+        impl<T: MatrixElement> $op<&Matrix<T>> for MatrixView<'_, T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: &Matrix<T>) -> Matrix<T> {
+                $body(self, rhs.as_view())
+            }
+        }
+
+        impl<T: MatrixElement> $op<MatrixView<'_, T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: MatrixView<'_, T>) -> Matrix<T> {
+                $body(self.as_view(), rhs)
+            }
+        }
+    };
+}
+
+impl_view_op!(Add, add, |lhs, rhs| zip_views(lhs, rhs, |a, b| a + b));
+impl_view_op!(Sub, sub, |lhs, rhs| zip_views(lhs, rhs, |a, b| a - b));
+impl_view_op!(Mul, mul, multiply_views);
+
+impl<T: MatrixElement> Mul<T> for MatrixView<'_, T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: T) -> Matrix<T> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.iter().map(|val| val.clone() * rhs.clone()).collect()
+        }
+    }
+}
+
+impl<T: MatrixElement> AddAssign<MatrixView<'_, T>> for MatrixViewMut<'_, T> {
+    fn add_assign(&mut self, rhs: MatrixView<'_, T>) {
+        if let Err(error) = self.shape().elementwise_with(rhs.shape()) {
+            panic!("{}", error);
+        }
+        for (val, other) in self.iter_mut().zip(rhs.iter()) {
+            *val = val.clone() + other.clone();
+        }
+    }
+}
+
+impl<T: MatrixElement> SubAssign<MatrixView<'_, T>> for MatrixViewMut<'_, T> {
+    fn sub_assign(&mut self, rhs: MatrixView<'_, T>) {
+        if let Err(error) = self.shape().elementwise_with(rhs.shape()) {
+            panic!("{}", error);
+        }
+        for (val, other) in self.iter_mut().zip(rhs.iter()) {
+            *val = val.clone() - other.clone();
+        }
+    }
+}
+
+impl<T: MatrixElement> AddAssign<&Matrix<T>> for MatrixViewMut<'_, T> {
+    fn add_assign(&mut self, rhs: &Matrix<T>) {
+        *self += rhs.as_view();
+    }
+}
+
+impl<T: MatrixElement> SubAssign<&Matrix<T>> for MatrixViewMut<'_, T> {
+    fn sub_assign(&mut self, rhs: &Matrix<T>) {
+        *self -= rhs.as_view();
+    }
+}
+
+impl<T: MatrixElement> MulAssign<T> for MatrixViewMut<'_, T> {
+    fn mul_assign(&mut self, rhs: T) {
+        for val in self.iter_mut() {
+            *val = val.clone() * rhs.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(rows: usize, cols: usize) -> Matrix<i32> {
+        Matrix::from_fn(rows, cols, |i, j| (i * 10 + j) as i32)
+    }
+
+    #[test]
+    fn views_borrow_blocks_rows_and_columns() {
+        let matrix = numbered(4, 5);
+
+        let block = matrix.view(1..3, 2..5);
+        assert_eq!(block.shape(), Shape::new(2, 3));
+        assert_eq!(block[(1, 0)], 22);
+        assert_eq!(block.to_owned(), Matrix::from(vec![vec![12, 13, 14], vec![22, 23, 24]]));
+
+        assert_eq!(block.row(1).to_owned(), Matrix::from(vec![vec![22, 23, 24]]));
+        assert_eq!(block.column(2).iter().copied().collect::<Vec<_>>(), vec![14, 24]);
+        assert_eq!(matrix.column_view(4).to_owned().shape(), Shape::new(4, 1));
+        assert_eq!(matrix.row_view(3).iter().copied().collect::<Vec<_>>(), matrix.get_row(3));
+        assert_eq!(block.matvec(&[1, 0, -1]), vec![-2, -2]);
+
+        let empty = matrix.view(2..2, 0..5);
+        assert_eq!(empty.shape(), Shape::new(0, 5));
+        assert_eq!(empty.iter().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn view_out_of_bounds_panics() {
+        numbered(3, 3).view(1..4, 0..1);
+    }
+
+    #[test]
+    fn view_arithmetic() {
+        let matrix = numbered(4, 4);
+        let top_left = matrix.view(0..2, 0..2);
+        let bottom_right = matrix.view(2..4, 2..4);
+
+        let top_left_owned = top_left.to_owned();
+        let bottom_right_owned = bottom_right.to_owned();
+        assert_eq!(top_left + bottom_right, &top_left_owned + &bottom_right_owned);
+        assert_eq!(bottom_right - &top_left_owned, &bottom_right_owned - &top_left_owned);
+        assert_eq!(&top_left_owned * bottom_right, &top_left_owned * &bottom_right_owned);
+        assert_eq!(top_left * 3, top_left_owned * 3);
+
+        // A 2x4 strip times a 4x1 column, both borrowed from the same matrix.
+        let product = matrix.view(1..3, 0..4) * matrix.column_view(1);
+        assert_eq!(product, matrix.view(1..3, 0..4).to_owned() * matrix.column_view(1).to_owned());
+    }
+
+    #[test]
+    fn mutable_views_write_through() {
+        let mut matrix = numbered(3, 4);
+        let addend = Matrix::new(2, 2, 100);
+        {
+            let mut block = matrix.view_mut(1..3, 1..3);
+            block += &addend;
+            block.set(0, 0, -1);
+            block[(1, 1)] *= 2;
+        }
+        assert_eq!(matrix, Matrix::from(vec![
+            vec![0, 1, 2, 3],
+            vec![10, -1, 112, 13],
+            vec![20, 121, 244, 23]
+        ]));
+
+        let source = numbered(2, 2);
+        let mut corner = matrix.view_mut(0..2, 2..4);
+        corner.copy_from(source.as_view());
+        corner *= -1;
+        assert_eq!(corner.to_owned(), Matrix::from(vec![vec![0, -1], vec![-10, -11]]));
+        assert_eq!(*matrix.get(2, 3), 23);
+    }
+}